@@ -1,5 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use sea_query::*;
+use std::fmt::Write;
 
 #[derive(Debug, Iden)]
 pub enum Char {
@@ -8,6 +9,38 @@ pub enum Char {
     Character,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum Font {
+    Table,
+    Id,
+    Name,
+    Variant,
+    Language,
+}
+
+// Implemented by hand, rendered through the default `Iden::prepare`
+impl Iden for Font {
+    fn unquoted(&self, s: &mut dyn Write) {
+        let name = match self {
+            Self::Table => "font",
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::Variant => "variant",
+            Self::Language => "language",
+        };
+        write!(s, "{name}").unwrap();
+    }
+}
+
+#[derive(Debug, Clone, Copy, IdenStatic)]
+pub enum Glyph {
+    Table,
+    Id,
+    Aspect,
+    Image,
+    FontId,
+}
+
 fn vanilla() -> String {
     format!(
         "SELECT `{}` from `{}` where `character` = {}",
@@ -33,11 +66,39 @@ fn select_and_to_string() {
     select().to_string(MysqlQueryBuilder);
 }
 
+fn select_many_columns_and_build() {
+    let mut query = Query::select();
+    for _ in 0..100 {
+        query.columns([Font::Id, Font::Name, Font::Variant, Font::Language]);
+    }
+    query
+        .from(Font::Table)
+        .and_where(Expr::col(Font::Language).eq("en"))
+        .build(PostgresQueryBuilder);
+}
+
+fn select_many_static_columns_and_build() {
+    let mut query = Query::select();
+    for _ in 0..100 {
+        query.columns([Glyph::Id, Glyph::Aspect, Glyph::Image, Glyph::FontId]);
+    }
+    query
+        .from(Glyph::Table)
+        .and_where(Expr::col(Glyph::FontId).eq(1))
+        .build(PostgresQueryBuilder);
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("vanilla", |b| b.iter(vanilla));
     c.bench_function("select", |b| b.iter(select));
     c.bench_function("select_and_build", |b| b.iter(select_and_build));
     c.bench_function("select_and_to_string", |b| b.iter(select_and_to_string));
+    c.bench_function("select_many_columns_and_build", |b| {
+        b.iter(select_many_columns_and_build)
+    });
+    c.bench_function("select_many_static_columns_and_build", |b| {
+        b.iter(select_many_static_columns_and_build)
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -101,11 +101,15 @@ fn main() -> Result<()> {
             .into(),
             Some(date!(2020 - 1 - 1).with_time(time!(2:2:2))).into(),
         ])
+        .returning_col(Character::Id)
         .build_rusqlite(SqliteQueryBuilder);
 
-    let result = conn.execute(sql.as_str(), &*values.as_params());
-    println!("Insert into character: {result:?}\n");
-    let id = conn.last_insert_rowid();
+    let mut stmt = conn.prepare(sql.as_str())?;
+    let ids = stmt
+        .query_map(&*values.as_params(), |row| row.get::<_, i32>(0))?
+        .collect::<Result<Vec<_>>>()?;
+    println!("Insert into character returning id: {ids:?}\n");
+    let id = *ids.last().unwrap();
 
     // Read
 
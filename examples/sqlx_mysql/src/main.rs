@@ -114,7 +114,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(MysqlQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
@@ -163,7 +163,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(MysqlQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
@@ -218,7 +218,7 @@ async fn main() {
         .order_by(Character::Id, Order::Desc)
         .build_sqlx(MysqlQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
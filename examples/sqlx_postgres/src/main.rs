@@ -137,7 +137,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(PostgresQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
@@ -188,7 +188,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(PostgresQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
@@ -260,7 +260,7 @@ async fn main() {
         .order_by(Character::Id, Order::Desc)
         .build_sqlx(PostgresQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&mut *pool)
         .await
         .unwrap();
@@ -91,7 +91,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(SqliteQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
@@ -101,7 +101,7 @@ async fn main() {
     }
     println!();
 
-    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
@@ -136,7 +136,7 @@ async fn main() {
         .limit(1)
         .build_sqlx(SqliteQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
@@ -144,7 +144,7 @@ async fn main() {
     for row in rows.iter() {
         println!("{row:?}\n");
     }
-    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
@@ -198,7 +198,7 @@ async fn main() {
         .order_by(Character::Id, Order::Desc)
         .build_sqlx(SqliteQueryBuilder);
 
-    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructChrono, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
@@ -207,7 +207,7 @@ async fn main() {
         println!("{row:?}");
     }
 
-    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, values.clone())
+    let rows = sqlx::query_as_with::<_, CharacterStructTime, _>(&sql, &values)
         .fetch_all(&pool)
         .await
         .unwrap();
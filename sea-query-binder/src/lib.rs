@@ -4,6 +4,20 @@
 //! For instance, using the [`SqlxBinder`] trait adds a [`SqlxBinder::build_sqlx`] method that
 //! returns the query and a [`Values`] object, which can be directly passed to `sqlx`'s
 //! [`sqlx::query_with`] method.
+//!
+//! ## `sqlx::Any`
+//!
+//! With the `sqlx-any` feature, [`SqlxBinder::build_any_sqlx`] renders a statement with a query
+//! builder chosen at runtime, so the placeholders (`?` or `$1`) match the connected database.
+//! The resulting [`SqlxValues`] implement `IntoArguments` for `sqlx::Any`, which accepts fewer
+//! types than the concrete drivers:
+//!
+//! - Integers are widened to `i32` or `i64`, and `u64` values must fit in an `i64`
+//! - `char` is bound as a string
+//! - `chrono` and `time` values are bound as strings in naive UTC
+//! - UUID, decimal, bigdecimal and JSON values are bound as their string representation
+//! - Network address, bit vector, interval, array and vector values are not supported,
+//!   and binding them panics
 
 #[cfg(feature = "sqlx-any")]
 mod sqlx_any;
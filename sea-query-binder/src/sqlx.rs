@@ -2,7 +2,13 @@ use crate::SqlxValues;
 use sea_query::{query::*, QueryBuilder};
 
 pub trait SqlxBinder {
+    /// Build the SQL statement and the values to bind with a statically known query builder.
     fn build_sqlx<T: QueryBuilder>(&self, query_builder: T) -> (String, SqlxValues);
+
+    /// Build the SQL statement and the values to bind with a query builder selected at runtime,
+    /// e.g. to run the query on a `sqlx::AnyPool`.
+    ///
+    /// See the [crate documentation](crate) for the value types that `sqlx::Any` cannot bind.
     fn build_any_sqlx(&self, query_builder: &dyn QueryBuilder) -> (String, SqlxValues);
 }
 
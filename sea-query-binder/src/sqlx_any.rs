@@ -6,126 +6,218 @@ impl<'q> sqlx::IntoArguments<'q, sqlx::any::Any> for SqlxValues {
         let mut args = sqlx::any::AnyArguments::default();
         for arg in self.0.into_iter() {
             use sqlx::Arguments;
+            // Move the heap allocated values into the arguments
             match arg {
-                Value::Bool(b) => {
-                    let _ = args.add(b);
-                }
-                Value::TinyInt(i) => {
-                    let _ = args.add(i.map(Into::<i32>::into));
-                }
-                Value::SmallInt(i) => {
-                    let _ = args.add(i.map(Into::<i32>::into));
-                }
-                Value::Int(i) => {
-                    let _ = args.add(i);
-                }
-                Value::BigInt(i) => {
-                    let _ = args.add(i);
-                }
-                Value::TinyUnsigned(i) => {
-                    let _ = args.add(i.map(Into::<i32>::into));
-                }
-                Value::SmallUnsigned(i) => {
-                    let _ = args.add(i.map(Into::<i32>::into));
-                }
-                Value::Unsigned(i) => {
-                    let _ = args.add(i.map(Into::<i64>::into));
-                }
-                Value::BigUnsigned(i) => {
-                    let _ = args
-                        .add(i.map(|i| <i64 as std::convert::TryFrom<u64>>::try_from(i).unwrap()));
-                }
-                Value::Float(f) => {
-                    let _ = args.add(f);
-                }
-                Value::Double(d) => {
-                    let _ = args.add(d);
-                }
                 Value::String(s) => {
                     let _ = args.add(s.map(|s| *s));
                 }
-                Value::Char(c) => {
-                    let _ = args.add(c.map(|c| c.to_string()));
-                }
                 Value::Bytes(b) => {
                     let _ = args.add(b.map(|b| *b));
                 }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDate(t) => {
-                    let _ = args.add(Value::ChronoDate(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoTime(t) => {
-                    let _ = args.add(Value::ChronoTime(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTime(t) => {
-                    let _ = args.add(Value::ChronoDateTime(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeUtc(t) => {
-                    let _ = args.add(Value::ChronoDateTimeUtc(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeLocal(t) => {
-                    let _ = args.add(Value::ChronoDateTimeLocal(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeWithTimeZone(t) => {
-                    let _ = args
-                        .add(Value::ChronoDateTimeWithTimeZone(t).chrono_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDate(t) => {
-                    let _ = args.add(Value::TimeDate(t).time_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeTime(t) => {
-                    let _ = args.add(Value::TimeTime(t).time_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDateTime(t) => {
-                    let _ = args.add(Value::TimeDateTime(t).time_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDateTimeWithTimeZone(t) => {
-                    let _ =
-                        args.add(Value::TimeDateTimeWithTimeZone(t).time_as_naive_utc_in_string());
-                }
-                #[cfg(feature = "with-uuid")]
-                Value::Uuid(_) => {
-                    panic!("UUID support not implemented for Any");
-                }
-                #[cfg(feature = "with-rust_decimal")]
-                Value::Decimal(_) => {
-                    panic!("Sqlite doesn't support decimal arguments");
-                }
-                #[cfg(feature = "with-bigdecimal")]
-                Value::BigDecimal(_) => {
-                    panic!("Sqlite doesn't support bigdecimal arguments");
-                }
-                #[cfg(feature = "with-json")]
-                Value::Json(_) => {
-                    panic!("Json support not implemented for Any");
-                }
-                #[cfg(feature = "with-ipnetwork")]
-                Value::IpNetwork(_) => {
-                    panic!("SQLx doesn't support IpNetwork arguments for Any");
-                }
-                #[cfg(feature = "with-mac_address")]
-                Value::MacAddress(_) => {
-                    panic!("SQLx doesn't support MacAddress arguments for Any");
-                }
-                #[cfg(feature = "postgres-array")]
-                Value::Array(_, _) => {
-                    panic!("SQLx doesn't support array arguments for Any");
+                arg => bind_value(&mut args, &arg),
+            }
+        }
+        args
+    }
+}
+
+impl<'q> sqlx::IntoArguments<'q, sqlx::any::Any> for &'q SqlxValues {
+    fn into_arguments(self) -> sqlx::any::AnyArguments<'q> {
+        let mut args = sqlx::any::AnyArguments::default();
+        for arg in self.0.iter() {
+            use sqlx::Arguments;
+            // Borrow the heap allocated values instead of copying them
+            match arg {
+                Value::String(s) => {
+                    let _ = args.add(s.as_deref().map(String::as_str));
                 }
-                #[cfg(feature = "postgres-vector")]
-                Value::Vector(_) => {
-                    panic!("SQLx doesn't support vector arguments for Any");
+                Value::Bytes(b) => {
+                    let _ = args.add(b.as_deref().map(Vec::as_slice));
                 }
+                arg => bind_value(&mut args, arg),
             }
         }
         args
     }
 }
+
+fn bind_value(args: &mut sqlx::any::AnyArguments<'_>, arg: &Value) {
+    use sqlx::Arguments;
+    match arg {
+        Value::Bool(b) => {
+            let _ = args.add(*b);
+        }
+        Value::TinyInt(i) => {
+            let _ = args.add(i.map(Into::<i32>::into));
+        }
+        Value::SmallInt(i) => {
+            let _ = args.add(i.map(Into::<i32>::into));
+        }
+        Value::Int(i) => {
+            let _ = args.add(*i);
+        }
+        Value::BigInt(i) => {
+            let _ = args.add(*i);
+        }
+        Value::TinyUnsigned(i) => {
+            let _ = args.add(i.map(Into::<i32>::into));
+        }
+        Value::SmallUnsigned(i) => {
+            let _ = args.add(i.map(Into::<i32>::into));
+        }
+        Value::Unsigned(i) => {
+            let _ = args.add(i.map(Into::<i64>::into));
+        }
+        Value::BigUnsigned(i) => {
+            let _ = args.add(i.map(|i| <i64 as std::convert::TryFrom<u64>>::try_from(i).unwrap()));
+        }
+        Value::Float(f) => {
+            let _ = args.add(*f);
+        }
+        Value::Double(d) => {
+            let _ = args.add(*d);
+        }
+        Value::Char(c) => {
+            let _ = args.add(c.map(|c| c.to_string()));
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(_) => {
+            let _ = args.add(arg.chrono_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(_) => {
+            let _ = args.add(arg.time_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(_) => {
+            let _ = args.add(arg.time_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(_) => {
+            let _ = args.add(arg.time_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(_) => {
+            let _ = args.add(arg.time_as_naive_utc_in_string());
+        }
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(uuid) => {
+            let _ = args.add(uuid.as_ref().map(|uuid| uuid.to_string()));
+        }
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(decimal) => {
+            let _ = args.add(decimal.as_ref().map(|d| d.to_string()));
+        }
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(big_decimal) => {
+            let _ = args.add(big_decimal.as_ref().map(|d| d.to_string()));
+        }
+        #[cfg(feature = "with-json")]
+        Value::Json(j) => {
+            let _ = args.add(j.as_ref().map(|j| j.to_string()));
+        }
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(_) => {
+            panic!("SQLx doesn't support IpNetwork arguments for Any");
+        }
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(_) => {
+            panic!("SQLx doesn't support MacAddress arguments for Any");
+        }
+        #[cfg(feature = "with-bit-vec")]
+        Value::BitVec(_) => {
+            panic!("SQLx doesn't support BitVec arguments for Any");
+        }
+        #[cfg(feature = "postgres-interval")]
+        Value::Interval(_) => {
+            panic!("SQLx doesn't support Interval arguments for Any");
+        }
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, _) => {
+            panic!("SQLx doesn't support array arguments for Any");
+        }
+        #[cfg(feature = "postgres-vector")]
+        Value::Vector(_) => {
+            panic!("SQLx doesn't support vector arguments for Any");
+        }
+        Value::String(_) | Value::Bytes(_) => {
+            unreachable!("Moved or borrowed by `into_arguments`");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::{any::Any, Arguments, IntoArguments};
+
+    #[test]
+    fn into_arguments_borrowed() {
+        let values = SqlxValues(sea_query::Values(vec![
+            1i32.into(),
+            "hello".into(),
+            vec![0u8; 1024].into(),
+        ]));
+
+        let mut expected = sqlx::any::AnyArguments::default();
+        expected.add(Some(1i32)).unwrap();
+        expected.add(Some("hello")).unwrap();
+        expected.add(Some(vec![0u8; 1024])).unwrap();
+
+        let args = <&SqlxValues as IntoArguments<'_, Any>>::into_arguments(&values);
+        assert_eq!(
+            format!("{:?}", args.values.0),
+            format!("{:?}", expected.values.0)
+        );
+
+        // The values are only borrowed, so they can be bound again
+        let args = <SqlxValues as IntoArguments<'_, Any>>::into_arguments(values);
+        assert_eq!(
+            format!("{:?}", args.values.0),
+            format!("{:?}", expected.values.0)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-uuid", feature = "with-json"))]
+    fn into_arguments_as_string() {
+        let values = SqlxValues(sea_query::Values(vec![
+            uuid::Uuid::nil().into(),
+            serde_json::json!({ "a": 1 }).into(),
+            Value::Uuid(None),
+        ]));
+
+        let mut expected = sqlx::any::AnyArguments::default();
+        expected
+            .add(Some("00000000-0000-0000-0000-000000000000"))
+            .unwrap();
+        expected.add(Some(r#"{"a":1}"#)).unwrap();
+        expected.add(None::<String>).unwrap();
+
+        let args = <&SqlxValues as IntoArguments<'_, Any>>::into_arguments(&values);
+        assert_eq!(
+            format!("{:?}", args.values.0),
+            format!("{:?}", expected.values.0)
+        );
+    }
+}
@@ -2,43 +2,49 @@ use crate::SqlxValues;
 use sea_query::Value;
 
 impl sqlx::IntoArguments<'_, sqlx::mysql::MySql> for SqlxValues {
+    fn into_arguments(self) -> sqlx::mysql::MySqlArguments {
+        <&SqlxValues as sqlx::IntoArguments<'_, sqlx::mysql::MySql>>::into_arguments(&self)
+    }
+}
+
+impl<'q> sqlx::IntoArguments<'q, sqlx::mysql::MySql> for &'q SqlxValues {
     fn into_arguments(self) -> sqlx::mysql::MySqlArguments {
         let mut args = sqlx::mysql::MySqlArguments::default();
-        for arg in self.0.into_iter() {
+        for arg in self.0.iter() {
             use sqlx::Arguments;
             match arg {
                 Value::Bool(b) => {
-                    let _ = args.add(b);
+                    let _ = args.add(*b);
                 }
                 Value::TinyInt(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::SmallInt(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::Int(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::BigInt(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::TinyUnsigned(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::SmallUnsigned(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::Unsigned(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::BigUnsigned(i) => {
-                    let _ = args.add(i);
+                    let _ = args.add(*i);
                 }
                 Value::Float(f) => {
-                    let _ = args.add(f);
+                    let _ = args.add(*f);
                 }
                 Value::Double(d) => {
-                    let _ = args.add(d);
+                    let _ = args.add(*d);
                 }
                 Value::String(s) => {
                     let _ = args.add(s.as_deref());
@@ -70,9 +76,8 @@ impl sqlx::IntoArguments<'_, sqlx::mysql::MySql> for SqlxValues {
                     let _ = args.add(t.as_deref());
                 }
                 #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeWithTimeZone(t) => {
-                    let _ = args
-                        .add(Value::ChronoDateTimeWithTimeZone(t).chrono_as_naive_utc_in_string());
+                Value::ChronoDateTimeWithTimeZone(_) => {
+                    let _ = args.add(arg.chrono_as_naive_utc_in_string());
                 }
                 #[cfg(feature = "with-time")]
                 Value::TimeDate(t) => {
@@ -122,6 +127,14 @@ impl sqlx::IntoArguments<'_, sqlx::mysql::MySql> for SqlxValues {
                 Value::MacAddress(_) => {
                     panic!("Mysql doesn't support MacAddress arguments");
                 }
+                #[cfg(feature = "with-bit-vec")]
+                Value::BitVec(_) => {
+                    panic!("Mysql doesn't support BitVec arguments");
+                }
+                #[cfg(feature = "postgres-interval")]
+                Value::Interval(_) => {
+                    panic!("Mysql doesn't support Interval arguments");
+                }
             }
         }
         args
@@ -1,5 +1,7 @@
 #[cfg(feature = "with-bigdecimal")]
 use bigdecimal::BigDecimal;
+#[cfg(feature = "with-bit-vec")]
+use bit_vec::BitVec;
 #[cfg(feature = "with-chrono")]
 use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 #[cfg(feature = "with-ipnetwork")]
@@ -13,7 +15,13 @@ use serde_json::Value as Json;
 #[cfg(feature = "with-uuid")]
 use uuid::Uuid;
 
-use sea_query::{ArrayType, Value};
+#[cfg(feature = "postgres-array")]
+use sea_query::ArrayType;
+#[cfg(feature = "postgres-interval")]
+use sea_query::Interval;
+use sea_query::Value;
+#[cfg(feature = "postgres-interval")]
+use sqlx::postgres::types::PgInterval;
 
 use crate::SqlxValues;
 
@@ -21,305 +29,422 @@ impl sqlx::IntoArguments<'_, sqlx::postgres::Postgres> for SqlxValues {
     fn into_arguments(self) -> sqlx::postgres::PgArguments {
         let mut args = sqlx::postgres::PgArguments::default();
         for arg in self.0.into_iter() {
-            use sqlx::Arguments;
             match arg {
-                Value::Bool(b) => {
-                    let _ = args.add(b);
-                }
-                Value::TinyInt(i) => {
-                    let _ = args.add(i);
-                }
-                Value::SmallInt(i) => {
-                    let _ = args.add(i);
-                }
-                Value::Int(i) => {
-                    let _ = args.add(i);
-                }
-                Value::BigInt(i) => {
-                    let _ = args.add(i);
-                }
-                Value::TinyUnsigned(i) => {
-                    let _ = args.add(i.map(|i| i as i16));
-                }
-                Value::SmallUnsigned(i) => {
-                    let _ = args.add(i.map(|i| i as i32));
-                }
-                Value::Unsigned(i) => {
-                    let _ = args.add(i.map(|i| i as i64));
-                }
-                Value::BigUnsigned(i) => {
-                    let _ = args.add(i.map(|i| <i64 as TryFrom<u64>>::try_from(i).unwrap()));
-                }
-                Value::Float(f) => {
-                    let _ = args.add(f);
-                }
-                Value::Double(d) => {
-                    let _ = args.add(d);
-                }
-                Value::String(s) => {
-                    let _ = args.add(s.as_deref());
-                }
-                Value::Char(c) => {
-                    let _ = args.add(c.map(|c| c.to_string()));
-                }
-                Value::Bytes(b) => {
-                    let _ = args.add(b.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDate(d) => {
-                    let _ = args.add(d.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoTime(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTime(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeUtc(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeLocal(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-chrono")]
-                Value::ChronoDateTimeWithTimeZone(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDate(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeTime(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDateTime(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-time")]
-                Value::TimeDateTimeWithTimeZone(t) => {
-                    let _ = args.add(t.as_deref());
-                }
-                #[cfg(feature = "with-uuid")]
-                Value::Uuid(uuid) => {
-                    let _ = args.add(uuid.as_deref());
-                }
-                #[cfg(feature = "with-rust_decimal")]
-                Value::Decimal(d) => {
-                    let _ = args.add(d.as_deref());
-                }
-                #[cfg(feature = "with-bigdecimal")]
-                Value::BigDecimal(d) => {
-                    let _ = args.add(d.as_deref());
-                }
-                #[cfg(feature = "with-json")]
-                Value::Json(j) => {
-                    let _ = args.add(j.as_deref());
-                }
-                #[cfg(feature = "with-ipnetwork")]
-                Value::IpNetwork(ip) => {
-                    let _ = args.add(ip.as_deref());
-                }
-                #[cfg(feature = "with-mac_address")]
-                Value::MacAddress(mac) => {
-                    let _ = args.add(mac.as_deref());
-                }
+                // Move the arrays into the arguments
                 #[cfg(feature = "postgres-array")]
-                Value::Array(ty, v) => match ty {
-                    ArrayType::Bool => {
-                        let value: Option<Vec<bool>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Bool");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::TinyInt => {
-                        let value: Option<Vec<i8>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::TinyInt");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::SmallInt => {
-                        let value: Option<Vec<i16>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::SmallInt");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Int => {
-                        let value: Option<Vec<i32>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Int");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::BigInt => {
-                        let value: Option<Vec<i64>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::BigInt");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::TinyUnsigned => {
-                        let value: Option<Vec<u8>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::TinyUnsigned");
-                        let value: Option<Vec<i16>> =
-                            value.map(|vec| vec.into_iter().map(|i| i as i16).collect());
-                        let _ = args.add(value);
-                    }
-                    ArrayType::SmallUnsigned => {
-                        let value: Option<Vec<u16>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::SmallUnsigned");
-                        let value: Option<Vec<i32>> =
-                            value.map(|vec| vec.into_iter().map(|i| i as i32).collect());
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Unsigned => {
-                        let value: Option<Vec<u32>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Unsigned");
-                        let value: Option<Vec<i64>> =
-                            value.map(|vec| vec.into_iter().map(|i| i as i64).collect());
-                        let _ = args.add(value);
-                    }
-                    ArrayType::BigUnsigned => {
-                        let value: Option<Vec<u64>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::BigUnsigned");
-                        let value: Option<Vec<i64>> = value.map(|vec| {
-                            vec.into_iter()
-                                .map(|i| <i64 as TryFrom<u64>>::try_from(i).unwrap())
-                                .collect()
-                        });
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Float => {
-                        let value: Option<Vec<f32>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Float");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Double => {
-                        let value: Option<Vec<f64>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Double");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::String => {
-                        let value: Option<Vec<String>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::String");
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Char => {
-                        let value: Option<Vec<char>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Char");
-                        let value: Option<Vec<String>> =
-                            value.map(|vec| vec.into_iter().map(|c| c.to_string()).collect());
-                        let _ = args.add(value);
-                    }
-                    ArrayType::Bytes => {
-                        let value: Option<Vec<Vec<u8>>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Bytes");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoDate => {
-                        let value: Option<Vec<NaiveDate>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::ChronoDate");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoTime => {
-                        let value: Option<Vec<NaiveTime>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::ChronoTime");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoDateTime => {
-                        let value: Option<Vec<NaiveDateTime>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::ChronoDateTime");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoDateTimeUtc => {
-                        let value: Option<Vec<DateTime<Utc>>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::ChronoDateTimeUtc");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoDateTimeLocal => {
-                        let value: Option<Vec<DateTime<Local>>> = Value::Array(ty, v).expect(
-                            "This Value::Array should consist of Value::ChronoDateTimeLocal",
-                        );
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-chrono")]
-                    ArrayType::ChronoDateTimeWithTimeZone => {
-                        let value: Option<Vec<DateTime<Local>>> = Value::Array(ty, v).expect(
-                            "This Value::Array should consist of Value::ChronoDateTimeWithTimeZone",
-                        );
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-time")]
-                    ArrayType::TimeDate => {
-                        let value: Option<Vec<time::Date>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::TimeDate");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-time")]
-                    ArrayType::TimeTime => {
-                        let value: Option<Vec<time::Time>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::TimeTime");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-time")]
-                    ArrayType::TimeDateTime => {
-                        let value: Option<Vec<time::PrimitiveDateTime>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::TimeDateTime");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-time")]
-                    ArrayType::TimeDateTimeWithTimeZone => {
-                        let value: Option<Vec<time::OffsetDateTime>> = Value::Array(ty, v).expect(
-                            "This Value::Array should consist of Value::TimeDateTimeWithTimeZone",
-                        );
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-uuid")]
-                    ArrayType::Uuid => {
-                        let value: Option<Vec<Uuid>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Uuid");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-rust_decimal")]
-                    ArrayType::Decimal => {
-                        let value: Option<Vec<Decimal>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Decimal");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-bigdecimal")]
-                    ArrayType::BigDecimal => {
-                        let value: Option<Vec<BigDecimal>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::BigDecimal");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-json")]
-                    ArrayType::Json => {
-                        let value: Option<Vec<Json>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::Json");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-ipnetwork")]
-                    ArrayType::IpNetwork => {
-                        let value: Option<Vec<IpNetwork>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::IpNetwork");
-                        let _ = args.add(value);
-                    }
-                    #[cfg(feature = "with-mac_address")]
-                    ArrayType::MacAddress => {
-                        let value: Option<Vec<MacAddress>> = Value::Array(ty, v)
-                            .expect("This Value::Array should consist of Value::MacAddress");
-                        let _ = args.add(value);
-                    }
-                },
-                #[cfg(feature = "postgres-vector")]
-                Value::Vector(v) => {
-                    let _ = args.add(v.as_deref());
-                }
+                Value::Array(_, _) => bind_array(&mut args, arg),
+                arg => bind_value(&mut args, &arg),
             }
         }
         args
     }
 }
+
+impl<'q> sqlx::IntoArguments<'q, sqlx::postgres::Postgres> for &'q SqlxValues {
+    fn into_arguments(self) -> sqlx::postgres::PgArguments {
+        let mut args = sqlx::postgres::PgArguments::default();
+        for arg in self.0.iter() {
+            match arg {
+                // The elements of an array are converted by value, so a borrowed array is copied
+                #[cfg(feature = "postgres-array")]
+                Value::Array(_, _) => bind_array(&mut args, arg.clone()),
+                arg => bind_value(&mut args, arg),
+            }
+        }
+        args
+    }
+}
+
+fn bind_value(args: &mut sqlx::postgres::PgArguments, arg: &Value) {
+    use sqlx::Arguments;
+    match arg {
+        Value::Bool(b) => {
+            let _ = args.add(*b);
+        }
+        Value::TinyInt(i) => {
+            let _ = args.add(*i);
+        }
+        Value::SmallInt(i) => {
+            let _ = args.add(*i);
+        }
+        Value::Int(i) => {
+            let _ = args.add(*i);
+        }
+        Value::BigInt(i) => {
+            let _ = args.add(*i);
+        }
+        Value::TinyUnsigned(i) => {
+            let _ = args.add(i.map(|i| i as i16));
+        }
+        Value::SmallUnsigned(i) => {
+            let _ = args.add(i.map(|i| i as i32));
+        }
+        Value::Unsigned(i) => {
+            let _ = args.add(i.map(|i| i as i64));
+        }
+        Value::BigUnsigned(i) => {
+            let _ = args.add(i.map(|i| <i64 as TryFrom<u64>>::try_from(i).unwrap()));
+        }
+        Value::Float(f) => {
+            let _ = args.add(*f);
+        }
+        Value::Double(d) => {
+            let _ = args.add(*d);
+        }
+        Value::String(s) => {
+            let _ = args.add(s.as_deref());
+        }
+        Value::Char(c) => {
+            let _ = args.add(c.map(|c| c.to_string()));
+        }
+        Value::Bytes(b) => {
+            let _ = args.add(b.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(d) => {
+            let _ = args.add(d.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(t) => {
+            let _ = args.add(t.as_deref());
+        }
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(uuid) => {
+            let _ = args.add(uuid.as_deref());
+        }
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(d) => {
+            let _ = args.add(d.as_deref());
+        }
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(d) => {
+            let _ = args.add(d.as_deref());
+        }
+        #[cfg(feature = "with-json")]
+        Value::Json(j) => {
+            let _ = args.add(j.as_deref());
+        }
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(ip) => {
+            let _ = args.add(ip.as_deref());
+        }
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(mac) => {
+            let _ = args.add(mac.as_deref());
+        }
+        #[cfg(feature = "with-bit-vec")]
+        Value::BitVec(bits) => {
+            let _ = args.add(bits.as_deref());
+        }
+        #[cfg(feature = "postgres-interval")]
+        Value::Interval(interval) => {
+            let _ = args.add(interval.as_deref().map(|v| PgInterval {
+                months: v.months,
+                days: v.days,
+                microseconds: v.microseconds,
+            }));
+        }
+        #[cfg(feature = "postgres-vector")]
+        Value::Vector(v) => {
+            let _ = args.add(v.as_deref());
+        }
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, _) => {
+            unreachable!("Bound by `bind_array`");
+        }
+    }
+}
+
+#[cfg(feature = "postgres-array")]
+fn bind_array(args: &mut sqlx::postgres::PgArguments, arg: Value) {
+    use sqlx::Arguments;
+    let ty = match &arg {
+        Value::Array(ty, _) => ty.clone(),
+        _ => unreachable!(),
+    };
+    match ty {
+        ArrayType::Bool => {
+            let value: Option<Vec<Option<bool>>> =
+                arg.expect("This Value::Array should consist of Value::Bool");
+            let _ = args.add(value);
+        }
+        ArrayType::TinyInt => {
+            let value: Option<Vec<Option<i8>>> =
+                arg.expect("This Value::Array should consist of Value::TinyInt");
+            let _ = args.add(value);
+        }
+        ArrayType::SmallInt => {
+            let value: Option<Vec<Option<i16>>> =
+                arg.expect("This Value::Array should consist of Value::SmallInt");
+            let _ = args.add(value);
+        }
+        ArrayType::Int => {
+            let value: Option<Vec<Option<i32>>> =
+                arg.expect("This Value::Array should consist of Value::Int");
+            let _ = args.add(value);
+        }
+        ArrayType::BigInt => {
+            let value: Option<Vec<Option<i64>>> =
+                arg.expect("This Value::Array should consist of Value::BigInt");
+            let _ = args.add(value);
+        }
+        ArrayType::TinyUnsigned => {
+            // `Vec<u8>` converts from `Value::Bytes`, so unwrap the elements one by one
+            let value: Option<Vec<Option<i16>>> = match arg {
+                Value::Array(_, v) => v.map(|vec| {
+                    vec.into_iter()
+                        .map(|v| {
+                            let v: Option<u8> =
+                                v.expect("This Value::Array should consist of Value::TinyUnsigned");
+                            v.map(|i| i as i16)
+                        })
+                        .collect()
+                }),
+                _ => unreachable!(),
+            };
+            let _ = args.add(value);
+        }
+        ArrayType::SmallUnsigned => {
+            let value: Option<Vec<Option<u16>>> =
+                arg.expect("This Value::Array should consist of Value::SmallUnsigned");
+            let value: Option<Vec<Option<i32>>> =
+                value.map(|vec| vec.into_iter().map(|i| i.map(|i| i as i32)).collect());
+            let _ = args.add(value);
+        }
+        ArrayType::Unsigned => {
+            let value: Option<Vec<Option<u32>>> =
+                arg.expect("This Value::Array should consist of Value::Unsigned");
+            let value: Option<Vec<Option<i64>>> =
+                value.map(|vec| vec.into_iter().map(|i| i.map(|i| i as i64)).collect());
+            let _ = args.add(value);
+        }
+        ArrayType::BigUnsigned => {
+            let value: Option<Vec<Option<u64>>> =
+                arg.expect("This Value::Array should consist of Value::BigUnsigned");
+            let value: Option<Vec<Option<i64>>> = value.map(|vec| {
+                vec.into_iter()
+                    .map(|i| i.map(|i| <i64 as TryFrom<u64>>::try_from(i).unwrap()))
+                    .collect()
+            });
+            let _ = args.add(value);
+        }
+        ArrayType::Float => {
+            let value: Option<Vec<Option<f32>>> =
+                arg.expect("This Value::Array should consist of Value::Float");
+            let _ = args.add(value);
+        }
+        ArrayType::Double => {
+            let value: Option<Vec<Option<f64>>> =
+                arg.expect("This Value::Array should consist of Value::Double");
+            let _ = args.add(value);
+        }
+        ArrayType::String => {
+            let value: Option<Vec<Option<String>>> =
+                arg.expect("This Value::Array should consist of Value::String");
+            let _ = args.add(value);
+        }
+        ArrayType::Char => {
+            let value: Option<Vec<Option<char>>> =
+                arg.expect("This Value::Array should consist of Value::Char");
+            let value: Option<Vec<Option<String>>> =
+                value.map(|vec| vec.into_iter().map(|c| c.map(|c| c.to_string())).collect());
+            let _ = args.add(value);
+        }
+        ArrayType::Bytes => {
+            let value: Option<Vec<Option<Vec<u8>>>> =
+                arg.expect("This Value::Array should consist of Value::Bytes");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDate => {
+            let value: Option<Vec<Option<NaiveDate>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoDate");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoTime => {
+            let value: Option<Vec<Option<NaiveTime>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoTime");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTime => {
+            let value: Option<Vec<Option<NaiveDateTime>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoDateTime");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeUtc => {
+            let value: Option<Vec<Option<DateTime<Utc>>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoDateTimeUtc");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeLocal => {
+            let value: Option<Vec<Option<DateTime<Local>>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoDateTimeLocal");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-chrono")]
+        ArrayType::ChronoDateTimeWithTimeZone => {
+            let value: Option<Vec<Option<DateTime<Local>>>> =
+                arg.expect("This Value::Array should consist of Value::ChronoDateTimeWithTimeZone");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDate => {
+            let value: Option<Vec<Option<time::Date>>> =
+                arg.expect("This Value::Array should consist of Value::TimeDate");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeTime => {
+            let value: Option<Vec<Option<time::Time>>> =
+                arg.expect("This Value::Array should consist of Value::TimeTime");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTime => {
+            let value: Option<Vec<Option<time::PrimitiveDateTime>>> =
+                arg.expect("This Value::Array should consist of Value::TimeDateTime");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-time")]
+        ArrayType::TimeDateTimeWithTimeZone => {
+            let value: Option<Vec<Option<time::OffsetDateTime>>> =
+                arg.expect("This Value::Array should consist of Value::TimeDateTimeWithTimeZone");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-uuid")]
+        ArrayType::Uuid => {
+            let value: Option<Vec<Option<Uuid>>> =
+                arg.expect("This Value::Array should consist of Value::Uuid");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-rust_decimal")]
+        ArrayType::Decimal => {
+            let value: Option<Vec<Option<Decimal>>> =
+                arg.expect("This Value::Array should consist of Value::Decimal");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-bigdecimal")]
+        ArrayType::BigDecimal => {
+            let value: Option<Vec<Option<BigDecimal>>> =
+                arg.expect("This Value::Array should consist of Value::BigDecimal");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-json")]
+        ArrayType::Json => {
+            let value: Option<Vec<Option<Json>>> =
+                arg.expect("This Value::Array should consist of Value::Json");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-ipnetwork")]
+        ArrayType::IpNetwork => {
+            let value: Option<Vec<Option<IpNetwork>>> =
+                arg.expect("This Value::Array should consist of Value::IpNetwork");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-mac_address")]
+        ArrayType::MacAddress => {
+            let value: Option<Vec<Option<MacAddress>>> =
+                arg.expect("This Value::Array should consist of Value::MacAddress");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "with-bit-vec")]
+        ArrayType::BitVec => {
+            let value: Option<Vec<Option<BitVec>>> =
+                arg.expect("This Value::Array should consist of Value::BitVec");
+            let _ = args.add(value);
+        }
+        #[cfg(feature = "postgres-interval")]
+        ArrayType::Interval => {
+            let value: Option<Vec<Option<Interval>>> =
+                arg.expect("This Value::Array should consist of Value::Interval");
+            let _ = args.add(value.map(|v| {
+                v.into_iter()
+                    .map(|v| {
+                        v.map(|v| PgInterval {
+                            months: v.months,
+                            days: v.days,
+                            microseconds: v.microseconds,
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            }));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "postgres-array"))]
+mod tests {
+    use super::*;
+    use sqlx::{postgres::Postgres, Arguments, IntoArguments};
+
+    #[test]
+    fn into_arguments_array_with_null() {
+        let values = SqlxValues(sea_query::Values(vec![
+            vec![Some(1i32), None, Some(3)].into()
+        ]));
+        let args = <SqlxValues as IntoArguments<'_, Postgres>>::into_arguments(values);
+
+        let mut expected = sqlx::postgres::PgArguments::default();
+        expected.add(Some(vec![Some(1i32), None, Some(3)])).unwrap();
+
+        assert_eq!(args.len(), 1);
+        assert_eq!(format!("{args:?}"), format!("{expected:?}"));
+    }
+
+    #[test]
+    fn into_arguments_array_borrowed() {
+        let values = SqlxValues(sea_query::Values(vec![vec![
+            "a".to_owned(),
+            "b".to_owned(),
+        ]
+        .into()]));
+
+        let mut expected = sqlx::postgres::PgArguments::default();
+        expected
+            .add(Some(vec![Some("a".to_owned()), Some("b".to_owned())]))
+            .unwrap();
+
+        let args = <&SqlxValues as IntoArguments<'_, Postgres>>::into_arguments(&values);
+        assert_eq!(format!("{args:?}"), format!("{expected:?}"));
+
+        // The array is only copied, so it can be moved into the arguments afterwards
+        let args = <SqlxValues as IntoArguments<'_, Postgres>>::into_arguments(values);
+        assert_eq!(format!("{args:?}"), format!("{expected:?}"));
+    }
+}
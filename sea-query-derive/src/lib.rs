@@ -33,7 +33,7 @@ pub fn derive_iden(input: TokenStream) -> TokenStream {
             syn::Data::Struct(DataStruct {
                 fields: Fields::Unit,
                 ..
-            }) => return impl_iden_for_unit_struct(&ident, &table_name).into(),
+            }) => return impl_iden_for_unit_struct(&ident, &table_name, quote! {}).into(),
             _ => return quote_spanned! {
                 ident.span() => compile_error!("you can only derive Iden on enums or unit structs");
             }
@@ -44,7 +44,7 @@ pub fn derive_iden(input: TokenStream) -> TokenStream {
         return TokenStream::new();
     }
 
-    let output = impl_iden_for_enum(&ident, &table_name, variants.iter());
+    let output = impl_iden_for_enum(&ident, &table_name, variants.iter(), quote! {});
 
     output.into()
 }
@@ -70,7 +70,12 @@ pub fn derive_iden_static(input: TokenStream) -> TokenStream {
                 fields: Fields::Unit,
                 ..
             }) => {
-                let impl_iden = impl_iden_for_unit_struct(&ident, &table_name);
+                let static_iden = quote! {
+                    fn static_iden(&self) -> Option<&'static dyn #sea_query_path::Iden> {
+                        Some(&#ident)
+                    }
+                };
+                let impl_iden = impl_iden_for_unit_struct(&ident, &table_name, static_iden);
 
                 return quote! {
                     #impl_iden
@@ -99,7 +104,21 @@ pub fn derive_iden_static(input: TokenStream) -> TokenStream {
         return TokenStream::new();
     }
 
-    let impl_iden = impl_iden_for_enum(&ident, &table_name, variants.iter());
+    // A unit variant is a constant, which can be borrowed for `'static`
+    let static_iden = if variants.iter().all(|v| matches!(v.fields, Fields::Unit)) {
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        quote! {
+            fn static_iden(&self) -> Option<&'static dyn #sea_query_path::Iden> {
+                Some(match self {
+                    #(Self::#variant_idents => &Self::#variant_idents),*
+                })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let impl_iden = impl_iden_for_enum(&ident, &table_name, variants.iter(), static_iden);
 
     let match_arms = match variants
         .iter()
@@ -160,6 +179,7 @@ fn must_be_valid_iden(name: &str) -> bool {
 fn impl_iden_for_unit_struct(
     ident: &proc_macro2::Ident,
     table_name: &str,
+    static_iden: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let sea_query_path = sea_query_path();
 
@@ -182,6 +202,8 @@ fn impl_iden_for_unit_struct(
             fn unquoted(&self, s: &mut dyn ::std::fmt::Write) {
                 write!(s, #table_name).unwrap();
             }
+
+            #static_iden
         }
     }
 }
@@ -190,6 +212,7 @@ fn impl_iden_for_enum<'a, T>(
     ident: &proc_macro2::Ident,
     table_name: &str,
     variants: T,
+    static_iden: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream
 where
     T: Iterator<Item = &'a Variant>,
@@ -232,6 +255,8 @@ where
                     #match_arms
                 };
             }
+
+            #static_iden
         }
     }
 }
@@ -331,6 +356,7 @@ pub fn enum_def(args: TokenStream, input: TokenStream) -> TokenStream {
     );
     let pascal_def_names = field_names.iter().map(|field| &field.pascal);
     let pascal_def_names2 = pascal_def_names.clone();
+    let pascal_def_names3 = pascal_def_names.clone();
     let default_names = field_names.iter().map(|field| &field.default);
     let default_names2 = default_names.clone();
     let import_name = Ident::new(
@@ -362,6 +388,13 @@ pub fn enum_def(args: TokenStream, input: TokenStream) -> TokenStream {
             fn unquoted(&self, s: &mut dyn sea_query::Write) {
                 write!(s, "{}", <Self as #import_name::IdenStatic>::as_str(&self)).unwrap();
             }
+
+            fn static_iden(&self) -> Option<&'static dyn #import_name::Iden> {
+                Some(match self {
+                    #enum_name::Table => &#enum_name::Table,
+                    #(#enum_name::#pascal_def_names3 => &#enum_name::#pascal_def_names3),*
+                })
+            }
         }
 
         impl ::std::convert::AsRef<str> for #enum_name {
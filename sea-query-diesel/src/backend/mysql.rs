@@ -71,6 +71,10 @@ impl TransformValue for Mysql {
             Value::IpNetwork(_) => bail!("Mysql doesn't support IpNetwork arguments"),
             #[cfg(feature = "with-mac_address")]
             Value::MacAddress(_) => bail!("Mysql doesn't support MacAddress arguments"),
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(_) => bail!("Mysql doesn't support BitVec arguments"),
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(_) => bail!("Mysql doesn't support Interval arguments"),
             #[cfg(feature = "postgres-array")]
             Value::Array(_, _) => bail!("Mysql doesn't support array arguments"),
             #[cfg(feature = "postgres-vector")]
@@ -81,6 +81,10 @@ impl TransformValue for Pg {
             Value::IpNetwork(v) => build!(Inet, v.map(|v| *v)),
             #[cfg(feature = "with-mac_address")]
             Value::MacAddress(v) => build!(MacAddr, v.map(|v| v.bytes())),
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(_) => bail!("Diesel doesn't support BitVec arguments"),
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(_) => bail!("Diesel doesn't support Interval arguments"),
             #[cfg(feature = "postgres-array")]
             Value::Array(ty, v) => match ty {
                 ArrayType::Bool => build!(Array<Bool>, refine!(bool, ty, v)),
@@ -204,6 +208,10 @@ impl TransformValue for Pg {
                             .map(|v| v.into_iter().map(|v| v.bytes()).collect::<Vec<_>>())
                     )
                 }
+                #[cfg(feature = "with-bit-vec")]
+                ArrayType::BitVec => bail!("Diesel doesn't support BitVec arguments"),
+                #[cfg(feature = "postgres-interval")]
+                ArrayType::Interval => bail!("Diesel doesn't support Interval arguments"),
             },
             #[cfg(feature = "postgres-vector")]
             Value::Vector(v) => build!(pgvector::sql_types::Vector, v.map(|v| *v)),
@@ -96,6 +96,10 @@ impl TransformValue for Sqlite {
             Value::IpNetwork(_) => bail!("Sqlite doesn't support IpNetwork arguments"),
             #[cfg(feature = "with-mac_address")]
             Value::MacAddress(_) => bail!("Sqlite doesn't support MacAddress arguments"),
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(_) => bail!("Sqlite doesn't support BitVec arguments"),
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(_) => bail!("Sqlite doesn't support Interval arguments"),
             #[cfg(feature = "postgres-array")]
             Value::Array(_, _) => bail!("Sqlite doesn't support array arguments"),
             #[cfg(feature = "postgres-vector")]
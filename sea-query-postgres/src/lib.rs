@@ -1,9 +1,12 @@
 use std::error::Error;
 
+#[cfg(feature = "postgres-interval")]
+use bytes::BufMut;
 use bytes::BytesMut;
 use postgres_types::{to_sql_checked, IsNull, ToSql, Type};
 
-use sea_query::{query::*, QueryBuilder, Value};
+use sea_query::{foreign_key::*, index::*, query::*, table::*};
+use sea_query::{QueryBuilder, SchemaBuilder, Value};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct PostgresValue(pub Value);
@@ -49,6 +52,35 @@ impl_postgres_binder!(InsertStatement);
 impl_postgres_binder!(DeleteStatement);
 impl_postgres_binder!(WithQuery);
 
+/// Schema statements have no parameters, the values are always empty.
+/// It lets DDL go through the same call sites as queries.
+pub trait PostgresSchemaBinder {
+    fn build_postgres<T: SchemaBuilder>(&self, schema_builder: T) -> (String, PostgresValues);
+}
+
+macro_rules! impl_postgres_schema_binder {
+    ($l:ident) => {
+        impl PostgresSchemaBinder for $l {
+            fn build_postgres<T: SchemaBuilder>(
+                &self,
+                schema_builder: T,
+            ) -> (String, PostgresValues) {
+                (self.build(schema_builder), PostgresValues(Vec::new()))
+            }
+        }
+    };
+}
+
+impl_postgres_schema_binder!(TableCreateStatement);
+impl_postgres_schema_binder!(TableAlterStatement);
+impl_postgres_schema_binder!(TableDropStatement);
+impl_postgres_schema_binder!(TableRenameStatement);
+impl_postgres_schema_binder!(TableTruncateStatement);
+impl_postgres_schema_binder!(IndexCreateStatement);
+impl_postgres_schema_binder!(IndexDropStatement);
+impl_postgres_schema_binder!(ForeignKeyCreateStatement);
+impl_postgres_schema_binder!(ForeignKeyDropStatement);
+
 impl ToSql for PostgresValue {
     fn to_sql(
         &self,
@@ -137,6 +169,17 @@ impl ToSql for PostgresValue {
                     .map(|v| MacAddress::new(v.bytes()))
                     .to_sql(ty, out)
             }
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(v) => v.as_deref().to_sql(ty, out),
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(Some(v)) => {
+                out.put_i64(v.microseconds);
+                out.put_i32(v.days);
+                out.put_i32(v.months);
+                Ok(IsNull::No)
+            }
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(None) => Ok(IsNull::Yes),
         }
     }
 
@@ -146,3 +189,20 @@ impl ToSql for PostgresValue {
 
     to_sql_checked!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_query::{Alias, ColumnDef, PostgresQueryBuilder, Table};
+
+    #[test]
+    fn build_table_create() {
+        let (sql, values) = Table::create()
+            .table(Alias::new("font"))
+            .col(ColumnDef::new(Alias::new("id")).integer().not_null())
+            .build_postgres(PostgresQueryBuilder);
+
+        assert_eq!(sql, r#"CREATE TABLE "font" ( "id" integer NOT NULL )"#);
+        assert!(values.0.is_empty());
+    }
+}
@@ -44,100 +44,134 @@ where
 }
 
 fn to_rb_values(values: Values) -> Vec<rbs::Value> {
-    let mut args: Vec<rbs::Value> = vec![];
-    for arg in values {
-        match arg {
-            Value::Bool(v) => args.push(v.to()),
-            Value::TinyInt(v) => args.push(v.to()),
-            Value::SmallInt(v) => args.push(v.to()),
-            Value::Int(v) => args.push(v.to()),
-            Value::BigInt(v) => args.push(v.to()),
-            Value::TinyUnsigned(v) => args.push(v.to()),
-            Value::SmallUnsigned(v) => args.push(v.to()),
-            Value::Unsigned(v) => args.push(v.to()),
-            Value::BigUnsigned(v) => args.push(v.to()),
-            Value::Float(v) => args.push(v.to()),
-            Value::Double(v) => args.push(v.to()),
-            Value::String(v) => {
-                let d = match v {
-                    Some(v) => v.to_string().into(),
-                    None => RbValue::Null,
-                };
-                args.push(d)
-            }
-            Value::Char(v) => args.push(to_value!(v)),
-            Value::Bytes(v) => args.push(to_value!(v)),
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoDate(v) => args.push(to_value!(
-                Value::ChronoDate(v).chrono_as_naive_utc_in_string()
-            )),
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoTime(v) => {
-                args.push(to_value!(
-                    Value::ChronoTime(v).chrono_as_naive_utc_in_string()
-                ));
-            }
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoDateTime(t) => {
-                args.push(Value::ChronoDateTime(t).chrono_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoDateTimeUtc(t) => {
-                args.push(Value::ChronoDateTimeUtc(t).chrono_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoDateTimeLocal(t) => {
-                args.push(Value::ChronoDateTimeLocal(t).chrono_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-chrono")]
-            Value::ChronoDateTimeWithTimeZone(t) => {
-                args.push(Value::ChronoDateTimeWithTimeZone(t).chrono_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-time")]
-            Value::TimeDate(t) => {
-                args.push(Value::TimeDate(t).time_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-time")]
-            Value::TimeTime(t) => {
-                args.push(Value::TimeTime(t).time_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-time")]
-            Value::TimeDateTime(t) => {
-                args.push(Value::TimeDateTime(t).time_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-time")]
-            Value::TimeDateTimeWithTimeZone(t) => {
-                args.push(Value::TimeDateTimeWithTimeZone(t).time_as_naive_utc_in_string().to());
-            }
-            #[cfg(feature = "with-uuid")]
-            Value::Uuid(uuid) => {
-                args.push(Value::Uuid(uuid).to_string().into());
-            }
-            #[cfg(feature = "with-rust_decimal")]
-            Value::Decimal(_) => {
-                panic!("rbs doesn't support Decimal arguments");
-            }
-            #[cfg(feature = "with-bigdecimal")]
-            Value::BigDecimal(d) => {
-                args.push(to_value!(Value::BigDecimal(d).to_string()));
-            }
-            #[cfg(feature = "with-json")]
-            Value::Json(j) => {
-                args.push(to_value!(j));
-            }
-            #[cfg(feature = "postgres-array")]
-            Value::Array(_, _) => {
-                panic!("Mysql doesn't support array arguments");
-            }
-            #[cfg(feature = "with-ipnetwork")]
-            Value::IpNetwork(_) => {
-                panic!("Mysql doesn't support IpNetwork arguments");
-            }
-            #[cfg(feature = "with-mac_address")]
-            Value::MacAddress(_) => {
-                panic!("Mysql doesn't support MacAddress arguments");
-            }
+    values.into_iter().map(to_rb_value).collect()
+}
+
+fn to_rb_value(value: Value) -> RbValue {
+    match value {
+        Value::Bool(v) => v.to(),
+        Value::TinyInt(v) => v.to(),
+        Value::SmallInt(v) => v.to(),
+        Value::Int(v) => v.to(),
+        Value::BigInt(v) => v.to(),
+        Value::TinyUnsigned(v) => v.to(),
+        Value::SmallUnsigned(v) => v.to(),
+        Value::Unsigned(v) => v.to(),
+        Value::BigUnsigned(v) => v.to(),
+        Value::Float(v) => v.to(),
+        Value::Double(v) => v.to(),
+        Value::String(v) => match v {
+            Some(v) => v.to_string().into(),
+            None => RbValue::Null,
+        },
+        Value::Char(v) => to_value!(v),
+        Value::Bytes(v) => to_value!(v),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(v) => to_value!(Value::ChronoDate(v).chrono_as_naive_utc_in_string()),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoTime(v) => to_value!(Value::ChronoTime(v).chrono_as_naive_utc_in_string()),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(t) => Value::ChronoDateTime(t)
+            .chrono_as_naive_utc_in_string()
+            .to(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeUtc(t) => Value::ChronoDateTimeUtc(t)
+            .chrono_as_naive_utc_in_string()
+            .to(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeLocal(t) => Value::ChronoDateTimeLocal(t)
+            .chrono_as_naive_utc_in_string()
+            .to(),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTimeWithTimeZone(t) => Value::ChronoDateTimeWithTimeZone(t)
+            .chrono_as_naive_utc_in_string()
+            .to(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(t) => Value::TimeDate(t).time_as_naive_utc_in_string().to(),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(t) => Value::TimeTime(t).time_as_naive_utc_in_string().to(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(t) => Value::TimeDateTime(t).time_as_naive_utc_in_string().to(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(t) => Value::TimeDateTimeWithTimeZone(t)
+            .time_as_naive_utc_in_string()
+            .to(),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(uuid) => Value::Uuid(uuid).to_string().into(),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(d) => match d {
+            Some(d) => d.to_string().into(),
+            None => RbValue::Null,
+        },
+        #[cfg(feature = "with-bigdecimal")]
+        Value::BigDecimal(d) => match d {
+            Some(d) => d.to_string().into(),
+            None => RbValue::Null,
+        },
+        #[cfg(feature = "with-json")]
+        Value::Json(j) => to_value!(j),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, Some(v)) => RbValue::Array(
+            v.into_iter()
+                .map(|v| match v {
+                    Value::Array(_, _) => panic!("rbs doesn't support nested array arguments"),
+                    v => to_rb_value(v),
+                })
+                .collect(),
+        ),
+        #[cfg(feature = "postgres-array")]
+        Value::Array(_, None) => RbValue::Null,
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(_) => {
+            panic!("Mysql doesn't support IpNetwork arguments");
         }
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(_) => {
+            panic!("Mysql doesn't support MacAddress arguments");
+        }
+        #[cfg(feature = "with-bit-vec")]
+        Value::BitVec(_) => {
+            panic!("Mysql doesn't support BitVec arguments");
+        }
+        #[cfg(feature = "postgres-interval")]
+        Value::Interval(_) => {
+            panic!("Mysql doesn't support Interval arguments");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "with-rust_decimal")]
+    fn bind_decimal() {
+        use rust_decimal::Decimal;
+
+        let values = to_rb_values(Values(vec![
+            Decimal::new(1050, 2).into(),
+            Option::<Decimal>::None.into(),
+        ]));
+
+        assert_eq!(
+            values,
+            vec![RbValue::String("10.50".to_owned()), RbValue::Null]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "postgres-array")]
+    fn bind_array() {
+        let values = to_rb_values(Values(vec![vec![1, 2, 3].into()]));
+
+        assert_eq!(
+            values,
+            vec![RbValue::Array(vec![
+                RbValue::I32(1),
+                RbValue::I32(2),
+                RbValue::I32(3)
+            ])]
+        );
     }
-    args
 }
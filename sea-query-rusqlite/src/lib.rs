@@ -23,6 +23,54 @@ impl RusqliteValues {
 }
 
 pub trait RusqliteBinder {
+    /// Build the SQL statement and values to be bound with rusqlite.
+    ///
+    /// A `RETURNING` clause (SQLite 3.35+) is kept in the SQL. Run such statements with
+    /// `Statement::query` instead of `Connection::execute` to read the returned rows.
+    ///
+    /// ```
+    /// use rusqlite::Connection;
+    /// use sea_query::{Alias, ColumnDef, Query, SqliteQueryBuilder, Table};
+    /// use sea_query_rusqlite::RusqliteBinder;
+    ///
+    /// # fn main() -> rusqlite::Result<()> {
+    /// let conn = Connection::open_in_memory()?;
+    /// conn.execute_batch(
+    ///     &Table::create()
+    ///         .table(Alias::new("character"))
+    ///         .col(
+    ///             ColumnDef::new(Alias::new("id"))
+    ///                 .integer()
+    ///                 .not_null()
+    ///                 .auto_increment()
+    ///                 .primary_key(),
+    ///         )
+    ///         .col(ColumnDef::new(Alias::new("character")).string())
+    ///         .build(SqliteQueryBuilder),
+    /// )?;
+    ///
+    /// let (sql, values) = Query::insert()
+    ///     .into_table(Alias::new("character"))
+    ///     .columns([Alias::new("character")])
+    ///     .values_panic(["A".into()])
+    ///     .values_panic(["B".into()])
+    ///     .returning_col(Alias::new("id"))
+    ///     .build_rusqlite(SqliteQueryBuilder);
+    ///
+    /// assert_eq!(
+    ///     sql,
+    ///     r#"INSERT INTO "character" ("character") VALUES (?), (?) RETURNING "id""#
+    /// );
+    ///
+    /// let mut stmt = conn.prepare(&sql)?;
+    /// let ids = stmt
+    ///     .query_map(&*values.as_params(), |row| row.get::<_, i32>(0))?
+    ///     .collect::<rusqlite::Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(ids, [1, 2]);
+    /// # Ok(())
+    /// # }
+    /// ```
     fn build_rusqlite<T: QueryBuilder>(&self, query_builder: T) -> (String, RusqliteValues);
 }
 
@@ -126,6 +174,14 @@ impl ToSql for RusqliteValue {
             Value::MacAddress(_) => {
                 panic!("Rusqlite doesn't support MacAddress arguments");
             }
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(_) => {
+                panic!("Rusqlite doesn't support BitVec arguments");
+            }
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(_) => {
+                panic!("Rusqlite doesn't support Interval arguments");
+            }
             #[cfg(feature = "postgres-array")]
             Value::Array(_, _) => {
                 panic!("Rusqlite doesn't support Array arguments");
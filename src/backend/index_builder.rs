@@ -10,9 +10,10 @@ pub trait IndexBuilder: QuotedBuilder + TableRefBuilder {
         sql: &mut dyn SqlWriter,
     ) {
         if let Some(name) = &create.index.name {
+            write!(sql, "CONSTRAINT ").unwrap();
             write!(
-                sql,
-                "CONSTRAINT {}{}{} ",
+                sql.as_iden_writer(),
+                "{}{}{} ",
                 self.quote().left(),
                 name,
                 self.quote().right()
@@ -23,6 +24,7 @@ pub trait IndexBuilder: QuotedBuilder + TableRefBuilder {
         self.prepare_index_prefix(create, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
 
         self.prepare_filter(&create.r#where, sql);
     }
@@ -64,8 +66,14 @@ pub trait IndexBuilder: QuotedBuilder + TableRefBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.name.prepare(sql.as_writer(), self.quote());
-            self.write_column_index_prefix(&col.prefix, sql);
+            if let Some(expr) = &col.expr {
+                write!(sql, "(").unwrap();
+                self.prepare_index_expr(expr, sql);
+                write!(sql, ")").unwrap();
+            } else if let Some(name) = &col.name {
+                name.prepare(sql.as_iden_writer(), self.quote());
+                self.write_column_index_prefix(&col.prefix, sql);
+            }
             if let Some(order) = &col.order {
                 match order {
                     IndexOrder::Asc => write!(sql, " ASC").unwrap(),
@@ -77,6 +85,20 @@ pub trait IndexBuilder: QuotedBuilder + TableRefBuilder {
         write!(sql, ")").unwrap();
     }
 
+    #[doc(hidden)]
+    /// Write the INCLUDE columns of a covering index.
+    fn prepare_index_include(&self, columns: &[DynIden], _sql: &mut dyn SqlWriter) {
+        if !columns.is_empty() {
+            panic!("INCLUDE columns are only supported by Postgres");
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the expression of a functional index column.
+    fn prepare_index_expr(&self, _expr: &SimpleExpr, _sql: &mut dyn SqlWriter) {
+        panic!("Index expressions are not supported by this backend");
+    }
+
     #[doc(hidden)]
     // Write WHERE clause for partial index. This function is not available in MySQL.
     fn prepare_filter(&self, _condition: &ConditionHolder, _sql: &mut dyn SqlWriter) {}
@@ -140,7 +140,12 @@ impl Oper {
     pub(crate) fn is_like(&self) -> bool {
         matches!(
             self,
-            Oper::BinOper(BinOper::Like) | Oper::BinOper(BinOper::NotLike)
+            Oper::BinOper(BinOper::Like)
+                | Oper::BinOper(BinOper::NotLike)
+                | Oper::BinOper(BinOper::ILike)
+                | Oper::BinOper(BinOper::NotILike)
+                | Oper::BinOper(BinOper::SimilarTo)
+                | Oper::BinOper(BinOper::NotSimilarTo)
         )
     }
 
@@ -188,6 +193,7 @@ impl Oper {
                         | BinOper::GreaterThanOrEqual
                         | BinOper::GreaterThan
                         | BinOper::NotEqual
+                        | BinOper::Overlaps
                 )
             }
             _ => false,
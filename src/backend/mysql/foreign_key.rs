@@ -25,7 +25,7 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
         write!(sql, "DROP FOREIGN KEY ").unwrap();
         if let Some(name) = &drop.foreign_key.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -56,7 +56,7 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
         write!(sql, "CONSTRAINT ").unwrap();
         if let Some(name) = &create.foreign_key.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -71,7 +71,7 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.prepare(sql.as_writer(), self.quote());
+            col.prepare(sql.as_iden_writer(), self.quote());
             false
         });
         write!(sql, ")").unwrap();
@@ -91,7 +91,7 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
                 if !first {
                     write!(sql, ", ").unwrap();
                 }
-                col.prepare(sql.as_writer(), self.quote());
+                col.prepare(sql.as_iden_writer(), self.quote());
                 false
             });
         write!(sql, ")").unwrap();
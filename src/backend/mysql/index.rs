@@ -11,7 +11,7 @@ impl IndexBuilder for MysqlQueryBuilder {
 
         if let Some(name) = &create.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{} ",
                 self.quote().left(),
                 name,
@@ -26,6 +26,7 @@ impl IndexBuilder for MysqlQueryBuilder {
         }
 
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
     }
 
     fn prepare_index_create_statement(
@@ -39,7 +40,7 @@ impl IndexBuilder for MysqlQueryBuilder {
 
         if let Some(name) = &create.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -54,10 +55,15 @@ impl IndexBuilder for MysqlQueryBuilder {
         }
         write!(sql, " ").unwrap();
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
 
         self.prepare_index_type(&create.index_type, sql);
     }
 
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        self.prepare_simple_expr(expr, sql);
+    }
+
     fn prepare_table_ref_index_stmt(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
         match table_ref {
             TableRef::Table(_) => self.prepare_table_ref_iden(table_ref, sql),
@@ -73,7 +79,7 @@ impl IndexBuilder for MysqlQueryBuilder {
 
         if let Some(name) = &drop.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -91,17 +97,13 @@ impl IndexBuilder for MysqlQueryBuilder {
     fn prepare_index_type(&self, col_index_type: &Option<IndexType>, sql: &mut dyn SqlWriter) {
         if let Some(index_type) = col_index_type {
             if !matches!(index_type, IndexType::FullText) {
-                write!(
-                    sql,
-                    " USING {}",
-                    match index_type {
-                        IndexType::BTree => "BTREE".to_owned(),
-                        IndexType::FullText => unreachable!(),
-                        IndexType::Hash => "HASH".to_owned(),
-                        IndexType::Custom(custom) => custom.to_string(),
-                    }
-                )
-                .unwrap();
+                write!(sql, " USING ").unwrap();
+                match index_type {
+                    IndexType::BTree => write!(sql, "BTREE").unwrap(),
+                    IndexType::FullText => unreachable!(),
+                    IndexType::Hash => write!(sql, "HASH").unwrap(),
+                    IndexType::Custom(custom) => custom.unquoted(sql.as_writer()),
+                }
             }
         }
     }
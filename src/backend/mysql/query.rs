@@ -6,6 +6,72 @@ impl QueryBuilder for MysqlQueryBuilder {
         "ROW"
     }
 
+    fn prepare_simple_expr(&self, simple_expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        match simple_expr {
+            SimpleExpr::FunctionCall(func) if func.filter.is_some() => {
+                let simple_expr = SimpleExpr::FunctionCall(func.filter_as_case());
+                self.prepare_simple_expr_common(&simple_expr, sql);
+            }
+            _ => QueryBuilder::prepare_simple_expr_common(self, simple_expr, sql),
+        }
+    }
+
+    fn prepare_match_against(
+        &self,
+        args: &[SimpleExpr],
+        mode: &MatchAgainstMode,
+        sql: &mut dyn SqlWriter,
+    ) {
+        let (search, cols) = args.split_last().unwrap();
+        write!(sql, "MATCH (").unwrap();
+        cols.iter().fold(true, |first, col| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(col, sql);
+            false
+        });
+        write!(sql, ") AGAINST (").unwrap();
+        self.prepare_simple_expr(search, sql);
+        write!(
+            sql,
+            " {})",
+            match mode {
+                MatchAgainstMode::NaturalLanguage => "IN NATURAL LANGUAGE MODE",
+                MatchAgainstMode::Boolean => "IN BOOLEAN MODE",
+                MatchAgainstMode::WithQueryExpansion => "WITH QUERY EXPANSION",
+            }
+        )
+        .unwrap();
+    }
+
+    fn prepare_ilike(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.prepare_simple_expr(&lower_like(left, op, right), sql);
+    }
+
+    fn prepare_bin_oper(&self, bin_oper: &BinOper, sql: &mut dyn SqlWriter) {
+        match bin_oper {
+            BinOper::Overlaps => panic!("Mysql doesn't support OVERLAPS"),
+            BinOper::SimilarTo | BinOper::NotSimilarTo => {
+                panic!("Mysql doesn't support SIMILAR TO, use REGEXP instead")
+            }
+            _ => self.prepare_bin_oper_common(bin_oper, sql),
+        }
+    }
+
+    fn prepare_frame_type(&self, frame_type: &FrameType, sql: &mut dyn SqlWriter) {
+        match frame_type {
+            FrameType::Groups => panic!("Mysql doesn't support GROUPS frames"),
+            _ => self.prepare_frame_type_common(frame_type, sql),
+        }
+    }
+
     fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, sql: &mut dyn SqlWriter) {
         match select_distinct {
             SelectDistinct::All => write!(sql, "ALL").unwrap(),
@@ -15,6 +81,111 @@ impl QueryBuilder for MysqlQueryBuilder {
         };
     }
 
+    fn prepare_explain_query(&self, explain: &ExplainQuery, sql: &mut dyn SqlWriter) {
+        if explain.analyze {
+            // EXPLAIN ANALYZE only supports the TREE format
+            write!(sql, "EXPLAIN ANALYZE ").unwrap();
+        } else {
+            write!(sql, "EXPLAIN FORMAT=JSON ").unwrap();
+        }
+        explain.query.prepare_statement(self, sql);
+    }
+
+    fn prepare_select_group_by(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
+        match select.grouping_sets.as_slice() {
+            [] => {
+                if !select.groups.is_empty() {
+                    write!(sql, " GROUP BY ").unwrap();
+                    select.groups.iter().fold(true, |first, (expr, _)| {
+                        if !first {
+                            write!(sql, ", ").unwrap()
+                        }
+                        self.prepare_simple_expr(expr, sql);
+                        false
+                    });
+                }
+            }
+            [GroupingSet::Rollup(exprs)] if select.groups.is_empty() => {
+                write!(sql, " GROUP BY ").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql);
+                    false
+                });
+                write!(sql, " WITH ROLLUP").unwrap();
+            }
+            _ => panic!("Mysql only supports ROLLUP of the whole GROUP BY list"),
+        }
+    }
+
+    fn prepare_keyword(&self, keyword: &Keyword, sql: &mut dyn SqlWriter) {
+        match keyword {
+            Keyword::RowId(_) => panic!("Mysql doesn't have a physical row id"),
+            _ => self.prepare_keyword_common(keyword, sql),
+        }
+    }
+
+    fn prepare_select_fetch(&self, _: &Value, _: bool, _: &mut dyn SqlWriter) {
+        panic!("Mysql doesn't support FETCH FIRST, use LIMIT instead")
+    }
+
+    fn prepare_update_join(&self, update: &UpdateStatement, sql: &mut dyn SqlWriter) {
+        for table_ref in update.from.iter() {
+            write!(sql, ", ").unwrap();
+            self.prepare_table_ref(table_ref, sql);
+        }
+    }
+
+    fn prepare_update_column(
+        &self,
+        update: &UpdateStatement,
+        col: &DynIden,
+        sql: &mut dyn SqlWriter,
+    ) {
+        // In a multi-table update, the columns of the target table have to be qualified
+        if let (Some(table), false) = (&update.table, update.from.is_empty()) {
+            table
+                .alias_or_name()
+                .prepare(sql.as_iden_writer(), self.quote());
+            write!(sql, ".").unwrap();
+        }
+        col.prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    fn prepare_update_value_tuple(
+        &self,
+        _: &UpdateStatement,
+        _: &[DynIden],
+        _: &SimpleExpr,
+        _: &mut dyn SqlWriter,
+    ) {
+        panic!("Mysql doesn't support assigning a row of columns in UPDATE")
+    }
+
+    fn prepare_update_from(&self, _: &UpdateStatement, _: &mut dyn SqlWriter) {
+        // MySQL puts the other tables after the target table
+    }
+
+    fn prepare_delete_using(&self, delete: &DeleteStatement, sql: &mut dyn SqlWriter) {
+        if !delete.using.is_empty() {
+            // The table references of a multi-table delete have to include the target table
+            write!(sql, " USING ").unwrap();
+            if let Some(table) = &delete.table {
+                self.prepare_table_ref(table, sql);
+                write!(sql, ", ").unwrap();
+            }
+            delete.using.iter().fold(true, |first, table_ref| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_table_ref(table_ref, sql);
+                false
+            });
+        }
+    }
+
     fn prepare_index_hints(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
         if !select.index_hints.is_empty() {
             write!(sql, " ").unwrap();
@@ -28,19 +199,19 @@ impl QueryBuilder for MysqlQueryBuilder {
                     write!(sql, "USE INDEX ",).unwrap();
                     self.prepare_index_hint_scope(&hint.scope, sql);
                     write!(sql, "(").unwrap();
-                    hint.index.prepare(sql.as_writer(), self.quote());
+                    hint.index.prepare(sql.as_iden_writer(), self.quote());
                 }
                 IndexHintType::Ignore => {
                     write!(sql, "IGNORE INDEX ",).unwrap();
                     self.prepare_index_hint_scope(&hint.scope, sql);
                     write!(sql, "(").unwrap();
-                    hint.index.prepare(sql.as_writer(), self.quote());
+                    hint.index.prepare(sql.as_iden_writer(), self.quote());
                 }
                 IndexHintType::Force => {
                     write!(sql, "FORCE INDEX ",).unwrap();
                     self.prepare_index_hint_scope(&hint.scope, sql);
                     write!(sql, "(").unwrap();
-                    hint.index.prepare(sql.as_writer(), self.quote());
+                    hint.index.prepare(sql.as_iden_writer(), self.quote());
                 }
             }
             write!(sql, ")").unwrap();
@@ -51,10 +222,77 @@ impl QueryBuilder for MysqlQueryBuilder {
         query.prepare_statement(self, sql);
     }
 
+    fn prepare_merge_statement(&self, _: &MergeStatement, _: &mut dyn SqlWriter) {
+        panic!("Mysql doesn't support MERGE statement, use INSERT with ON DUPLICATE KEY UPDATE instead")
+    }
+
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        // MySQL only accepts a handful of types as the target of `CAST`
+        match column_type {
+            ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text => {
+                write!(sql, "char").unwrap()
+            }
+            ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::Integer
+            | ColumnType::BigInteger => write!(sql, "signed").unwrap(),
+            ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned
+            | ColumnType::Unsigned
+            | ColumnType::BigUnsigned => write!(sql, "unsigned").unwrap(),
+            // `bool` is only an alias of `tinyint(1)`
+            ColumnType::Boolean => write!(sql, "signed").unwrap(),
+            ColumnType::Float => write!(sql, "float").unwrap(),
+            ColumnType::Double => write!(sql, "double").unwrap(),
+            ColumnType::Decimal(precision) | ColumnType::Money(precision) => match precision {
+                Some((precision, scale)) => write!(sql, "decimal({precision}, {scale})").unwrap(),
+                None => write!(sql, "decimal").unwrap(),
+            },
+            ColumnType::DateTime | ColumnType::TimestampWithTimeZone => {
+                write!(sql, "datetime").unwrap()
+            }
+            ColumnType::Timestamp(precision) => match precision {
+                Some(precision) => write!(sql, "datetime({precision})").unwrap(),
+                None => write!(sql, "datetime").unwrap(),
+            },
+            ColumnType::Time => write!(sql, "time").unwrap(),
+            ColumnType::Date => write!(sql, "date").unwrap(),
+            ColumnType::Year => write!(sql, "year").unwrap(),
+            ColumnType::Binary(length) | ColumnType::VarBinary(StringLen::N(length)) => {
+                write!(sql, "binary({length})").unwrap()
+            }
+            // The text form, which is how a `Value::Uuid` is written inline
+            ColumnType::Uuid => write!(sql, "char(36)").unwrap(),
+            ColumnType::VarBinary(_) | ColumnType::Blob => write!(sql, "binary").unwrap(),
+            ColumnType::Json | ColumnType::JsonBinary => write!(sql, "json").unwrap(),
+            ColumnType::Custom(iden) => iden.unquoted(sql.as_writer()),
+            ColumnType::Bit(_)
+            | ColumnType::VarBit(_)
+            | ColumnType::Enum { .. }
+            | ColumnType::Interval(_, _)
+            | ColumnType::Array(_)
+            | ColumnType::Vector(_)
+            | ColumnType::Cidr
+            | ColumnType::Inet
+            | ColumnType::MacAddr
+            | ColumnType::LTree => panic!("Mysql doesn't support CAST AS {column_type:?}"),
+        }
+    }
+
     fn prepare_with_clause_recursive_options(&self, _: &WithClause, _: &mut dyn SqlWriter) {
         // MySQL doesn't support sql recursive with query 'SEARCH' and 'CYCLE' options.
     }
 
+    fn prepare_with_query_clause_search_cycle(
+        &self,
+        cte: &CommonTableExpression,
+        _: &mut dyn SqlWriter,
+    ) {
+        if cte.search.is_some() || cte.cycle.is_some() {
+            panic!("SEARCH and CYCLE clauses of a common table expression are only supported by Postgres");
+        }
+    }
+
     fn prepare_with_query_clause_materialization(
         &self,
         _: &CommonTableExpression,
@@ -109,9 +347,9 @@ impl QueryBuilder for MysqlQueryBuilder {
                         if !first {
                             write!(sql, ", ").unwrap()
                         }
-                        pk_col.prepare(sql.as_writer(), self.quote());
+                        pk_col.prepare(sql.as_iden_writer(), self.quote());
                         write!(sql, " = ").unwrap();
-                        pk_col.prepare(sql.as_writer(), self.quote());
+                        pk_col.prepare(sql.as_iden_writer(), self.quote());
                         false
                     });
                 } else {
@@ -132,7 +370,7 @@ impl QueryBuilder for MysqlQueryBuilder {
 
     fn prepare_on_conflict_excluded_table(&self, col: &DynIden, sql: &mut dyn SqlWriter) {
         write!(sql, "VALUES(").unwrap();
-        col.prepare(sql.as_writer(), self.quote());
+        col.prepare(sql.as_iden_writer(), self.quote());
         write!(sql, ")").unwrap();
     }
 
@@ -140,10 +378,101 @@ impl QueryBuilder for MysqlQueryBuilder {
 
     fn prepare_returning(&self, _returning: &Option<ReturningClause>, _sql: &mut dyn SqlWriter) {}
 
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn supports_on_conflict(&self) -> bool {
+        false
+    }
+
+    fn supports_window_functions(&self) -> bool {
+        // Since MySQL 8.0
+        true
+    }
+
+    fn supports_grouping_sets(&self) -> bool {
+        false
+    }
+
+    fn prepare_hash(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        write!(sql, "CRC32(").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_format_date(&self, expr: &SimpleExpr, format: &str, sql: &mut dyn SqlWriter) {
+        write!(sql, "DATE_FORMAT(").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ", ").unwrap();
+        self.prepare_value(&self.translate_date_format(format).into(), sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn date_format_specifier(&self, spec: char) -> &str {
+        match spec {
+            'Y' => "%Y",
+            'm' => "%m",
+            'd' => "%d",
+            'H' => "%H",
+            'M' => "%i",
+            'S' => "%s",
+            _ => panic!("Unsupported date format specifier %{spec}"),
+        }
+    }
+
+    fn date_format_literal(&self, literal: &str) -> String {
+        literal.replace('%', "%%")
+    }
+
+    fn prepare_extract(&self, part: &DatePart, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        match part {
+            DatePart::Epoch => write!(sql, "UNIX_TIMESTAMP(").unwrap(),
+            _ => write!(sql, "EXTRACT({} FROM ", self.date_part(part)).unwrap(),
+        }
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_timestamp_diff(
+        &self,
+        start: &SimpleExpr,
+        end: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "TIMESTAMPDIFF(SECOND, ").unwrap();
+        self.prepare_simple_expr(start, sql);
+        write!(sql, ", ").unwrap();
+        self.prepare_simple_expr(end, sql);
+        write!(sql, ")").unwrap();
+    }
+
     fn random_function(&self) -> &str {
         "RAND"
     }
 
+    fn string_agg_function(&self) -> &str {
+        "GROUP_CONCAT"
+    }
+
+    fn prepare_function_arguments(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
+        match func.func {
+            // The separator goes last, after the ORDER BY
+            Function::StringAgg if func.args.len() == 2 => {
+                write!(sql, "(").unwrap();
+                if func.distinct || func.mods.first().map_or(false, |mod_| mod_.distinct) {
+                    write!(sql, "DISTINCT ").unwrap();
+                }
+                self.prepare_simple_expr(&func.args[0], sql);
+                self.prepare_function_order_by(&func.order_by, sql);
+                write!(sql, " SEPARATOR ").unwrap();
+                self.prepare_simple_expr(&func.args[1], sql);
+                write!(sql, ")").unwrap();
+            }
+            _ => self.prepare_function_arguments_common(func, sql),
+        }
+    }
+
     fn insert_default_keyword(&self) -> &str {
         "()"
     }
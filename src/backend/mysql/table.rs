@@ -5,13 +5,14 @@ impl TableBuilder for MysqlQueryBuilder {
         // comment
         if let Some(comment) = &create.comment {
             let comment = self.escape_string(comment);
-            write!(sql, " COMMENT '{comment}'").unwrap();
+            write!(sql, " COMMENT ").unwrap();
+            write!(sql.as_writer(), "'{comment}'").unwrap();
         }
         self.prepare_table_opt_def(create, sql)
     }
 
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut dyn SqlWriter) {
-        column_def.name.prepare(sql.as_writer(), self.quote());
+        column_def.name.prepare(sql.as_iden_writer(), self.quote());
 
         if let Some(column_type) = &column_def.types {
             write!(sql, " ").unwrap();
@@ -25,6 +26,24 @@ impl TableBuilder for MysqlQueryBuilder {
     }
 
     fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        match column_type {
+            ColumnType::Custom(iden) => return iden.unquoted(sql.as_writer()),
+            ColumnType::Enum { variants, .. } => {
+                write!(sql, "ENUM(").unwrap();
+                write!(
+                    sql.as_writer(),
+                    "'{}'",
+                    variants
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join("', '")
+                )
+                .unwrap();
+                return write!(sql, ")").unwrap();
+            }
+            _ => {}
+        }
         write!(
             sql,
             "{}",
@@ -50,7 +69,10 @@ impl TableBuilder for MysqlQueryBuilder {
                     None => "decimal".into(),
                 },
                 ColumnType::DateTime => "datetime".into(),
-                ColumnType::Timestamp => "timestamp".into(),
+                ColumnType::Timestamp(precision) => match precision {
+                    Some(precision) => format!("timestamp({precision})"),
+                    None => "timestamp".into(),
+                },
                 ColumnType::TimestampWithTimeZone => "timestamp".into(),
                 ColumnType::Time => "time".into(),
                 ColumnType::Date => "date".into(),
@@ -80,15 +102,7 @@ impl TableBuilder for MysqlQueryBuilder {
                 ColumnType::Json => "json".into(),
                 ColumnType::JsonBinary => "json".into(),
                 ColumnType::Uuid => "binary(16)".into(),
-                ColumnType::Custom(iden) => iden.to_string(),
-                ColumnType::Enum { variants, .. } => format!(
-                    "ENUM('{}')",
-                    variants
-                        .iter()
-                        .map(|v| v.to_string())
-                        .collect::<Vec<_>>()
-                        .join("', '")
-                ),
+                ColumnType::Custom(_) | ColumnType::Enum { .. } => unreachable!(),
                 ColumnType::Array(_) => unimplemented!("Array is not available in MySQL."),
                 ColumnType::Vector(_) => unimplemented!("Vector is not available in MySQL."),
                 ColumnType::Cidr => unimplemented!("Cidr is not available in MySQL."),
@@ -144,13 +158,34 @@ impl TableBuilder for MysqlQueryBuilder {
                 }
                 TableAlterOption::RenameColumn(from_name, to_name) => {
                     write!(sql, "RENAME COLUMN ").unwrap();
-                    from_name.prepare(sql.as_writer(), self.quote());
+                    from_name.prepare(sql.as_iden_writer(), self.quote());
                     write!(sql, " TO ").unwrap();
-                    to_name.prepare(sql.as_writer(), self.quote());
+                    to_name.prepare(sql.as_iden_writer(), self.quote());
                 }
                 TableAlterOption::DropColumn(column_name) => {
                     write!(sql, "DROP COLUMN ").unwrap();
-                    column_name.prepare(sql.as_writer(), self.quote());
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                }
+                TableAlterOption::SetDefault(column_name, value) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                    write!(sql, " SET DEFAULT ").unwrap();
+                    // Only literals can be used as is, any other expression must be parenthesized
+                    match value {
+                        SimpleExpr::Value(_) | SimpleExpr::Constant(_) => {
+                            self.prepare_column_default(value, sql)
+                        }
+                        _ => {
+                            write!(sql, "(").unwrap();
+                            self.prepare_column_default(value, sql);
+                            write!(sql, ")").unwrap();
+                        }
+                    }
+                }
+                TableAlterOption::DropDefault(column_name) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                    write!(sql, " DROP DEFAULT").unwrap();
                 }
                 TableAlterOption::DropForeignKey(name) => {
                     let mut foreign_key = TableForeignKey::new();
@@ -194,6 +229,7 @@ impl TableBuilder for MysqlQueryBuilder {
     /// column comment
     fn column_comment(&self, comment: &str, sql: &mut dyn SqlWriter) {
         let comment = self.escape_string(comment);
-        write!(sql, "COMMENT '{comment}'").unwrap()
+        write!(sql, "COMMENT ").unwrap();
+        write!(sql.as_writer(), "'{comment}'").unwrap()
     }
 }
@@ -13,14 +13,16 @@ impl ExtensionBuilder for PostgresQueryBuilder {
             write!(sql, "IF NOT EXISTS ").unwrap()
         }
 
-        write!(sql, "{}", create.name).unwrap();
+        write!(sql.as_writer(), "{}", create.name).unwrap();
 
         if let Some(schema) = create.schema.as_ref() {
-            write!(sql, " WITH SCHEMA {}", schema).unwrap();
+            write!(sql, " WITH SCHEMA ").unwrap();
+            write!(sql.as_writer(), "{}", schema).unwrap();
         }
 
         if let Some(version) = create.version.as_ref() {
-            write!(sql, " VERSION {}", version).unwrap();
+            write!(sql, " VERSION ").unwrap();
+            write!(sql.as_writer(), "{}", version).unwrap();
         }
 
         if create.cascade {
@@ -39,7 +41,7 @@ impl ExtensionBuilder for PostgresQueryBuilder {
             write!(sql, "IF EXISTS ").unwrap();
         }
 
-        write!(sql, "{}", drop.name).unwrap();
+        write!(sql.as_writer(), "{}", drop.name).unwrap();
 
         if drop.cascade {
             write!(sql, " CASCADE").unwrap();
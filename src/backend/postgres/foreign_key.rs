@@ -18,7 +18,7 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
         write!(sql, "DROP CONSTRAINT ").unwrap();
         if let Some(name) = &drop.foreign_key.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -49,7 +49,7 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
         if let Some(name) = &create.foreign_key.name {
             write!(sql, "CONSTRAINT ").unwrap();
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{} ",
                 self.quote().left(),
                 name,
@@ -63,7 +63,7 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.prepare(sql.as_writer(), self.quote());
+            col.prepare(sql.as_iden_writer(), self.quote());
             false
         });
         write!(sql, ")").unwrap();
@@ -83,7 +83,7 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
                 if !first {
                     write!(sql, ", ").unwrap();
                 }
-                col.prepare(sql.as_writer(), self.quote());
+                col.prepare(sql.as_iden_writer(), self.quote());
                 false
             });
         write!(sql, ")").unwrap();
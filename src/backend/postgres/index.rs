@@ -9,9 +9,10 @@ impl IndexBuilder for PostgresQueryBuilder {
         sql: &mut dyn SqlWriter,
     ) {
         if let Some(name) = &create.index.name {
+            write!(sql, "CONSTRAINT ").unwrap();
             write!(
-                sql,
-                "CONSTRAINT {}{}{} ",
+                sql.as_iden_writer(),
+                "{}{}{} ",
                 self.quote().left(),
                 name,
                 self.quote().right()
@@ -22,10 +23,12 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_prefix(create, sql);
 
         if create.nulls_not_distinct {
+            self.check_nulls_not_distinct(create);
             write!(sql, "NULLS NOT DISTINCT ").unwrap();
         }
 
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
     }
 
     fn prepare_index_create_statement(
@@ -43,7 +46,7 @@ impl IndexBuilder for PostgresQueryBuilder {
 
         if let Some(name) = &create.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -60,13 +63,34 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_type(&create.index_type, sql);
         write!(sql, " ").unwrap();
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
 
         if create.nulls_not_distinct {
+            self.check_nulls_not_distinct(create);
             write!(sql, " NULLS NOT DISTINCT").unwrap();
         }
         self.prepare_filter(&create.r#where, sql);
     }
 
+    fn prepare_index_include(&self, columns: &[DynIden], sql: &mut dyn SqlWriter) {
+        if columns.is_empty() {
+            return;
+        }
+        write!(sql, " INCLUDE (").unwrap();
+        columns.iter().fold(true, |first, col| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql.as_iden_writer(), self.quote());
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        self.prepare_simple_expr(expr, sql);
+    }
+
     fn prepare_table_ref_index_stmt(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
         match table_ref {
             TableRef::Table(_) | TableRef::SchemaTable(_, _) => {
@@ -87,7 +111,7 @@ impl IndexBuilder for PostgresQueryBuilder {
             match table {
                 TableRef::Table(_) => {}
                 TableRef::SchemaTable(schema, _) => {
-                    schema.prepare(sql.as_writer(), self.quote());
+                    schema.prepare(sql.as_iden_writer(), self.quote());
                     write!(sql, ".").unwrap();
                 }
                 _ => panic!("Not supported"),
@@ -95,7 +119,7 @@ impl IndexBuilder for PostgresQueryBuilder {
         }
         if let Some(name) = &drop.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -107,17 +131,13 @@ impl IndexBuilder for PostgresQueryBuilder {
 
     fn prepare_index_type(&self, col_index_type: &Option<IndexType>, sql: &mut dyn SqlWriter) {
         if let Some(index_type) = col_index_type {
-            write!(
-                sql,
-                " USING {}",
-                match index_type {
-                    IndexType::BTree => "BTREE".to_owned(),
-                    IndexType::FullText => "GIN".to_owned(),
-                    IndexType::Hash => "HASH".to_owned(),
-                    IndexType::Custom(custom) => custom.to_string(),
-                }
-            )
-            .unwrap();
+            write!(sql, " USING ").unwrap();
+            match index_type {
+                IndexType::BTree => write!(sql, "BTREE").unwrap(),
+                IndexType::FullText => write!(sql, "GIN").unwrap(),
+                IndexType::Hash => write!(sql, "HASH").unwrap(),
+                IndexType::Custom(custom) => custom.unquoted(sql.as_writer()),
+            }
         }
     }
 
@@ -134,3 +154,11 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_condition(condition, "WHERE", sql);
     }
 }
+
+impl PostgresQueryBuilder {
+    fn check_nulls_not_distinct(&self, create: &IndexCreateStatement) {
+        if !create.unique {
+            panic!("NULLS NOT DISTINCT is only supported by unique indexes");
+        }
+    }
+}
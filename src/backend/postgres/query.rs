@@ -38,6 +38,31 @@ impl QueryBuilder for PostgresQueryBuilder {
         ("$", true)
     }
 
+    fn prepare_group_by_expr(
+        &self,
+        select: &SelectStatement,
+        expr: &SimpleExpr,
+        positional: bool,
+        sql: &mut dyn SqlWriter,
+    ) {
+        // Postgres only matches a selected expression with a GROUP BY expression when their
+        // parameters are the same too, so an expression of `group_by_expr` is grouped by its
+        // position in the select list instead. Unlike an alias, the position can't be shadowed
+        // by a column.
+        let position = match expr {
+            SimpleExpr::Column(_) => None,
+            _ if positional => select
+                .selects
+                .iter()
+                .position(|select_expr| select_expr.window.is_none() && &select_expr.expr == expr),
+            _ => None,
+        };
+        match position {
+            Some(position) => write!(sql, "{}", position + 1).unwrap(),
+            None => self.prepare_simple_expr(expr, sql),
+        }
+    }
+
     fn prepare_simple_expr(&self, simple_expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
         match simple_expr {
             SimpleExpr::AsEnum(type_name, expr) => {
@@ -48,6 +73,36 @@ impl QueryBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn prepare_table_ref_only(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
+        write!(sql, "ONLY ").unwrap();
+        self.prepare_table_ref(table_ref, sql);
+    }
+
+    fn prepare_table_sample(
+        &self,
+        table_ref: &TableRef,
+        sample: &TableSample,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.prepare_table_ref(table_ref, sql);
+        write!(
+            sql,
+            " TABLESAMPLE {} (",
+            match sample.method {
+                TableSampleMethod::Bernoulli => "BERNOULLI",
+                TableSampleMethod::System => "SYSTEM",
+            }
+        )
+        .unwrap();
+        self.prepare_value(&sample.percentage, sql);
+        write!(sql, ")").unwrap();
+        if let Some(seed) = &sample.repeatable {
+            write!(sql, " REPEATABLE (").unwrap();
+            self.prepare_value(seed, sql);
+            write!(sql, ")").unwrap();
+        }
+    }
+
     fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, sql: &mut dyn SqlWriter) {
         match select_distinct {
             SelectDistinct::All => write!(sql, "ALL").unwrap(),
@@ -67,6 +122,54 @@ impl QueryBuilder for PostgresQueryBuilder {
         };
     }
 
+    fn prepare_select_having(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
+        if select.having.is_empty() {
+            return;
+        }
+        // Postgres does not allow referring to an output column alias in HAVING,
+        // so the aliased expression is inlined instead
+        let mut aliases: Vec<(String, &SimpleExpr)> = select
+            .selects
+            .iter()
+            .filter_map(
+                |select_expr| match (&select_expr.alias, &select_expr.window) {
+                    (Some(alias), None) => Some((alias.to_string(), &select_expr.expr)),
+                    _ => None,
+                },
+            )
+            .collect();
+        if !aliases.is_empty() {
+            // An alias named like a column referenced by the query is taken as the column
+            let mut columns = Vec::new();
+            let mut collect = |col: &ColumnRef| match col {
+                ColumnRef::Column(col)
+                | ColumnRef::TableColumn(_, col)
+                | ColumnRef::SchemaTableColumn(_, _, col) => columns.push(col.to_string()),
+                ColumnRef::Asterisk | ColumnRef::TableAsterisk(_) => {}
+            };
+            for select_expr in &select.selects {
+                select_expr.expr.visit_columns(&mut collect);
+            }
+            for (expr, _) in &select.groups {
+                expr.visit_columns(&mut collect);
+            }
+            select.r#where.visit_columns(&mut collect);
+            aliases.retain(|(alias, _)| !columns.contains(alias));
+        }
+        if aliases.is_empty() {
+            self.prepare_condition(&select.having, "HAVING", sql);
+        } else {
+            let having = select.having.replace_columns(&mut |col| match col {
+                ColumnRef::Column(col) => aliases
+                    .iter()
+                    .find(|(alias, _)| *alias == col.to_string())
+                    .map(|(_, expr)| (*expr).clone()),
+                _ => None,
+            });
+            self.prepare_condition(&having, "HAVING", sql);
+        }
+    }
+
     fn prepare_bin_oper(&self, bin_oper: &BinOper, sql: &mut dyn SqlWriter) {
         match bin_oper {
             BinOper::PgOperator(oper) => write!(
@@ -75,7 +178,7 @@ impl QueryBuilder for PostgresQueryBuilder {
                 match oper {
                     PgBinOper::ILike => "ILIKE",
                     PgBinOper::NotILike => "NOT ILIKE",
-                    PgBinOper::Matches => "@@",
+                    PgBinOper::Matches | PgBinOper::JsonPathMatches => "@@",
                     PgBinOper::Contains => "@>",
                     PgBinOper::Contained => "<@",
                     PgBinOper::Concatenate => "||",
@@ -107,6 +210,38 @@ impl QueryBuilder for PostgresQueryBuilder {
         query.prepare_statement(self, sql);
     }
 
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        TableBuilder::prepare_column_type(self, column_type, sql);
+    }
+
+    fn prepare_pg_cast(
+        &self,
+        expr: &SimpleExpr,
+        column_type: &ColumnType,
+        sql: &mut dyn SqlWriter,
+    ) {
+        // `::` binds tighter than any operator, so compound operands need parentheses
+        let paren = !matches!(
+            expr,
+            SimpleExpr::Column(_)
+                | SimpleExpr::Tuple(_)
+                | SimpleExpr::FunctionCall(_)
+                | SimpleExpr::SubQuery(None, _)
+                | SimpleExpr::Value(_)
+                | SimpleExpr::Keyword(_)
+                | SimpleExpr::Constant(_)
+        );
+        if paren {
+            write!(sql, "(").unwrap();
+        }
+        self.prepare_simple_expr(expr, sql);
+        if paren {
+            write!(sql, ")").unwrap();
+        }
+        write!(sql, "::").unwrap();
+        self.prepare_cast_type(column_type, sql);
+    }
+
     fn prepare_function_name(&self, function: &Function, sql: &mut dyn SqlWriter) {
         match function {
             Function::PgFunction(function) => write!(
@@ -120,10 +255,25 @@ impl QueryBuilder for PostgresQueryBuilder {
                     PgFunction::WebsearchToTsquery => "WEBSEARCH_TO_TSQUERY",
                     PgFunction::TsRank => "TS_RANK",
                     PgFunction::TsRankCd => "TS_RANK_CD",
+                    PgFunction::TsHeadline => "TS_HEADLINE",
                     PgFunction::StartsWith => "STARTS_WITH",
                     PgFunction::GenRandomUUID => "GEN_RANDOM_UUID",
                     PgFunction::JsonBuildObject => "JSON_BUILD_OBJECT",
+                    PgFunction::JsonbBuildObject => "JSONB_BUILD_OBJECT",
                     PgFunction::JsonAgg => "JSON_AGG",
+                    PgFunction::JsonbPathQuery => "JSONB_PATH_QUERY",
+                    PgFunction::JsonbPathQueryArray => "JSONB_PATH_QUERY_ARRAY",
+                    PgFunction::JsonbPathExists => "JSONB_PATH_EXISTS",
+                    PgFunction::ArrayLength => "ARRAY_LENGTH",
+                    PgFunction::Cardinality => "CARDINALITY",
+                    PgFunction::ArrayPosition => "ARRAY_POSITION",
+                    PgFunction::ArrayRemove => "ARRAY_REMOVE",
+                    PgFunction::ArrayAppend => "ARRAY_APPEND",
+                    PgFunction::ArrayToString => "ARRAY_TO_STRING",
+                    PgFunction::StringToArray => "STRING_TO_ARRAY",
+                    PgFunction::WidthBucket => "WIDTH_BUCKET",
+                    PgFunction::ToChar => "TO_CHAR",
+                    PgFunction::Unnest => "UNNEST",
                     #[cfg(feature = "postgres-array")]
                     PgFunction::Any => "ANY",
                     #[cfg(feature = "postgres-array")]
@@ -137,6 +287,37 @@ impl QueryBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn prepare_hash(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        // The first 32 bits of the MD5 digest, as `HASHTEXT` is internal and may change across versions.
+        // Mask the sign bit, as `ABS` overflows on the minimum integer
+        write!(sql, "(CAST(CAST('x' || SUBSTR(MD5(CAST(").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(
+            sql,
+            " AS TEXT)), 1, 8) AS BIT(32)) AS INTEGER) & 2147483647)"
+        )
+        .unwrap();
+    }
+
+    fn prepare_greatest_least_ignore_nulls(
+        &self,
+        function: &Function,
+        args: &[SimpleExpr],
+        sql: &mut dyn SqlWriter,
+    ) {
+        // Postgres already skips NULL arguments
+        self.prepare_function_name(function, sql);
+        write!(sql, "(").unwrap();
+        args.iter().fold(true, |first, arg| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(arg, sql);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut dyn SqlWriter) {
         if !matches!(order_expr.order, Order::Field(_)) {
             self.prepare_simple_expr(&order_expr.expr, sql);
@@ -185,12 +366,16 @@ fn is_pg_comparison(b: &BinOper) -> bool {
             | BinOper::PgOperator(PgBinOper::WordSimilarity)
             | BinOper::PgOperator(PgBinOper::StrictWordSimilarity)
             | BinOper::PgOperator(PgBinOper::Matches)
+            | BinOper::PgOperator(PgBinOper::JsonPathMatches)
     )
 }
 
 fn is_ilike(b: &BinOper) -> bool {
     matches!(
         b,
-        BinOper::PgOperator(PgBinOper::ILike) | BinOper::PgOperator(PgBinOper::NotILike)
+        BinOper::ILike
+            | BinOper::NotILike
+            | BinOper::PgOperator(PgBinOper::ILike)
+            | BinOper::PgOperator(PgBinOper::NotILike)
     )
 }
@@ -8,7 +8,34 @@ impl TableBuilder for PostgresQueryBuilder {
         self.prepare_column_def_common(column_def, sql, f);
     }
 
+    fn prepare_exclude_constraint(&self, exclude: &ExcludeConstraint, sql: &mut dyn SqlWriter) {
+        write!(sql, "EXCLUDE").unwrap();
+        self.prepare_index_type(&Some(exclude.index_type.clone()), sql);
+        write!(sql, " (").unwrap();
+        exclude.elements.iter().fold(true, |first, (col, oper)| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql.as_iden_writer(), self.quote());
+            write!(sql, " WITH ").unwrap();
+            self.prepare_bin_oper(oper, sql);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
     fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        match column_type {
+            ColumnType::Custom(iden) => return iden.unquoted(sql.as_writer()),
+            ColumnType::Enum { name, .. } => {
+                return name.prepare(sql.as_iden_writer(), self.quote())
+            }
+            ColumnType::Array(elem_type) => {
+                self.prepare_column_type(elem_type, sql);
+                return write!(sql, "[]").unwrap();
+            }
+            _ => {}
+        }
         write!(
             sql,
             "{}",
@@ -33,7 +60,10 @@ impl TableBuilder for PostgresQueryBuilder {
                     None => "decimal".into(),
                 },
                 ColumnType::DateTime => "timestamp without time zone".into(),
-                ColumnType::Timestamp => "timestamp".into(),
+                ColumnType::Timestamp(precision) => match precision {
+                    Some(precision) => format!("timestamp({precision})"),
+                    None => "timestamp".into(),
+                },
                 ColumnType::TimestampWithTimeZone => "timestamp with time zone".into(),
                 ColumnType::Time => "time".into(),
                 ColumnType::Date => "date".into(),
@@ -66,17 +96,13 @@ impl TableBuilder for PostgresQueryBuilder {
                 ColumnType::Json => "json".into(),
                 ColumnType::JsonBinary => "jsonb".into(),
                 ColumnType::Uuid => "uuid".into(),
-                ColumnType::Array(elem_type) => {
-                    let mut sql = String::new();
-                    self.prepare_column_type(elem_type, &mut sql);
-                    format!("{sql}[]")
-                }
                 ColumnType::Vector(size) => match size {
                     Some(size) => format!("vector({size})"),
                     None => "vector".into(),
                 },
-                ColumnType::Custom(iden) => iden.to_string(),
-                ColumnType::Enum { name, .. } => name.to_string(),
+                ColumnType::Custom(_) | ColumnType::Enum { .. } | ColumnType::Array(_) => {
+                    unreachable!()
+                }
                 ColumnType::Cidr => "cidr".into(),
                 ColumnType::Inet => "inet".into(),
                 ColumnType::MacAddr => "macaddr".into(),
@@ -133,7 +159,7 @@ impl TableBuilder for PostgresQueryBuilder {
                 TableAlterOption::ModifyColumn(column_def) => {
                     if let Some(column_type) = &column_def.types {
                         write!(sql, "ALTER COLUMN ").unwrap();
-                        column_def.name.prepare(sql.as_writer(), self.quote());
+                        column_def.name.prepare(sql.as_iden_writer(), self.quote());
                         write!(sql, " TYPE ").unwrap();
                         self.prepare_column_type(column_type, sql);
                     }
@@ -152,33 +178,35 @@ impl TableBuilder for PostgresQueryBuilder {
                             ColumnSpec::AutoIncrement => {}
                             ColumnSpec::Null => {
                                 write!(sql, "ALTER COLUMN ").unwrap();
-                                column_def.name.prepare(sql.as_writer(), self.quote());
+                                column_def.name.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, " DROP NOT NULL").unwrap();
                             }
                             ColumnSpec::NotNull => {
                                 write!(sql, "ALTER COLUMN ").unwrap();
-                                column_def.name.prepare(sql.as_writer(), self.quote());
+                                column_def.name.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, " SET NOT NULL").unwrap()
                             }
                             ColumnSpec::Default(v) => {
                                 write!(sql, "ALTER COLUMN ").unwrap();
-                                column_def.name.prepare(sql.as_writer(), self.quote());
+                                column_def.name.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, " SET DEFAULT ").unwrap();
-                                QueryBuilder::prepare_simple_expr(self, v, sql);
+                                self.prepare_column_default(v, sql);
                             }
                             ColumnSpec::UniqueKey => {
                                 write!(sql, "ADD UNIQUE (").unwrap();
-                                column_def.name.prepare(sql.as_writer(), self.quote());
+                                column_def.name.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, ")").unwrap();
                             }
                             ColumnSpec::PrimaryKey => {
                                 write!(sql, "ADD PRIMARY KEY (").unwrap();
-                                column_def.name.prepare(sql.as_writer(), self.quote());
+                                column_def.name.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, ")").unwrap();
                             }
                             ColumnSpec::Check(check) => self.prepare_check_constraint(check, sql),
                             ColumnSpec::Generated { .. } => {}
-                            ColumnSpec::Extra(string) => write!(sql, "{string}").unwrap(),
+                            ColumnSpec::Extra(string) => {
+                                write!(sql.as_writer(), "{string}").unwrap()
+                            }
                             ColumnSpec::Comment(_) => {}
                         }
                         false
@@ -186,13 +214,24 @@ impl TableBuilder for PostgresQueryBuilder {
                 }
                 TableAlterOption::RenameColumn(from_name, to_name) => {
                     write!(sql, "RENAME COLUMN ").unwrap();
-                    from_name.prepare(sql.as_writer(), self.quote());
+                    from_name.prepare(sql.as_iden_writer(), self.quote());
                     write!(sql, " TO ").unwrap();
-                    to_name.prepare(sql.as_writer(), self.quote());
+                    to_name.prepare(sql.as_iden_writer(), self.quote());
                 }
                 TableAlterOption::DropColumn(column_name) => {
                     write!(sql, "DROP COLUMN ").unwrap();
-                    column_name.prepare(sql.as_writer(), self.quote());
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                }
+                TableAlterOption::SetDefault(column_name, value) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                    write!(sql, " SET DEFAULT ").unwrap();
+                    self.prepare_column_default(value, sql);
+                }
+                TableAlterOption::DropDefault(column_name) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_iden_writer(), self.quote());
+                    write!(sql, " DROP DEFAULT").unwrap();
                 }
                 TableAlterOption::DropForeignKey(name) => {
                     let mut foreign_key = TableForeignKey::new();
@@ -268,7 +307,7 @@ impl PostgresQueryBuilder {
     where
         F: Fn(&ColumnDef, &mut dyn SqlWriter),
     {
-        column_def.name.prepare(sql.as_writer(), self.quote());
+        column_def.name.prepare(sql.as_iden_writer(), self.quote());
 
         f(column_def, sql);
 
@@ -37,7 +37,7 @@ pub trait QueryBuilder:
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
-                col.prepare(sql.as_writer(), self.quote());
+                col.prepare(sql.as_iden_writer(), self.quote());
                 false
             });
             write!(sql, ")").unwrap();
@@ -88,6 +88,8 @@ pub trait QueryBuilder:
             UnionType::Distinct => write!(sql, " UNION (").unwrap(),
             UnionType::Except => write!(sql, " EXCEPT (").unwrap(),
             UnionType::All => write!(sql, " UNION ALL (").unwrap(),
+            UnionType::IntersectAll => write!(sql, " INTERSECT ALL (").unwrap(),
+            UnionType::ExceptAll => write!(sql, " EXCEPT ALL (").unwrap(),
         }
         self.prepare_select_statement(select_statement, sql);
         write!(sql, ")").unwrap();
@@ -131,19 +133,24 @@ pub trait QueryBuilder:
 
         self.prepare_condition(&select.r#where, "WHERE", sql);
 
-        if !select.groups.is_empty() {
-            write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+        self.prepare_select_group_by(select, sql);
+
+        self.prepare_select_having(select, sql);
+
+        if !select.windows.is_empty() {
+            write!(sql, " WINDOW ").unwrap();
+            select.windows.iter().fold(true, |first, (name, window)| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
-                self.prepare_simple_expr(expr, sql);
+                name.prepare(sql.as_iden_writer(), self.quote());
+                write!(sql, " AS (").unwrap();
+                self.prepare_window_statement(window, sql);
+                write!(sql, ")").unwrap();
                 false
             });
         }
 
-        self.prepare_condition(&select.having, "HAVING", sql);
-
         if !select.unions.is_empty() {
             select.unions.iter().for_each(|(union_type, query)| {
                 self.prepare_union_statement(*union_type, query, sql);
@@ -168,17 +175,20 @@ pub trait QueryBuilder:
             self.prepare_select_lock(lock, sql);
         }
 
-        if let Some((name, query)) = &select.window {
-            write!(sql, " WINDOW ").unwrap();
-            name.prepare(sql.as_writer(), self.quote());
-            write!(sql, " AS ").unwrap();
-            self.prepare_window_statement(query, sql);
+        for expr in select.raw_tail.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_simple_expr(expr, sql);
         }
     }
 
+    /// Translate the HAVING clause in [`SelectStatement`]
+    fn prepare_select_having(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
+        self.prepare_condition(&select.having, "HAVING", sql);
+    }
+
     // Translate the LIMIT and OFFSET expression in [`SelectStatement`]
     fn prepare_select_limit_offset(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
-        if let Some(limit) = &select.limit {
+        if let (Some(limit), None) = (&select.limit, select.fetch) {
             write!(sql, " LIMIT ").unwrap();
             self.prepare_value(limit, sql);
         }
@@ -187,6 +197,22 @@ pub trait QueryBuilder:
             write!(sql, " OFFSET ").unwrap();
             self.prepare_value(offset, sql);
         }
+
+        if let (Some(count), Some(with_ties)) = (&select.limit, select.fetch) {
+            self.prepare_select_fetch(count, with_ties, sql);
+        }
+    }
+
+    #[doc(hidden)]
+    /// Translate the FETCH FIRST clause in [`SelectStatement`]
+    fn prepare_select_fetch(&self, count: &Value, with_ties: bool, sql: &mut dyn SqlWriter) {
+        write!(sql, " FETCH FIRST ").unwrap();
+        self.prepare_value(count, sql);
+        if with_ties {
+            write!(sql, " ROWS WITH TIES").unwrap();
+        } else {
+            write!(sql, " ROWS ONLY").unwrap();
+        }
     }
 
     /// Translate [`UpdateStatement`] into SQL statement.
@@ -197,21 +223,32 @@ pub trait QueryBuilder:
             self.prepare_table_ref(table, sql);
         }
 
+        self.prepare_update_join(update, sql);
+
         write!(sql, " SET ").unwrap();
 
-        update.values.iter().fold(true, |first, row| {
+        let first = update.values.iter().fold(true, |first, row| {
             if !first {
                 write!(sql, ", ").unwrap()
             }
             let (col, v) = row;
-            col.prepare(sql.as_writer(), self.quote());
+            self.prepare_update_column(update, col, sql);
             write!(sql, " = ").unwrap();
             self.prepare_simple_expr(v, sql);
             false
         });
+        update.value_tuples.iter().fold(first, |first, (cols, v)| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_update_value_tuple(update, cols, v, sql);
+            false
+        });
 
         self.prepare_output(&update.returning, sql);
 
+        self.prepare_update_from(update, sql);
+
         self.prepare_condition(&update.r#where, "WHERE", sql);
 
         self.prepare_update_order_by(update, sql);
@@ -221,6 +258,53 @@ pub trait QueryBuilder:
         self.prepare_returning(&update.returning, sql);
     }
 
+    /// Translate the tables updated together with the target table, i.e. `UPDATE t, other`.
+    fn prepare_update_join(&self, _update: &UpdateStatement, _sql: &mut dyn SqlWriter) {}
+
+    /// Translate a column being SET in [`UpdateStatement`].
+    fn prepare_update_column(
+        &self,
+        _update: &UpdateStatement,
+        col: &DynIden,
+        sql: &mut dyn SqlWriter,
+    ) {
+        col.prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    /// Translate a row of columns being SET at once in [`UpdateStatement`].
+    fn prepare_update_value_tuple(
+        &self,
+        update: &UpdateStatement,
+        cols: &[DynIden],
+        value: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "(").unwrap();
+        cols.iter().fold(true, |first, col| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_update_column(update, col, sql);
+            false
+        });
+        write!(sql, ") = ").unwrap();
+        self.prepare_simple_expr(value, sql);
+    }
+
+    /// Translate FROM clause in [`UpdateStatement`].
+    fn prepare_update_from(&self, update: &UpdateStatement, sql: &mut dyn SqlWriter) {
+        if !update.from.is_empty() {
+            write!(sql, " FROM ").unwrap();
+            update.from.iter().fold(true, |first, table_ref| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_table_ref(table_ref, sql);
+                false
+            });
+        }
+    }
+
     /// Translate ORDER BY expression in [`UpdateStatement`].
     fn prepare_update_order_by(&self, update: &UpdateStatement, sql: &mut dyn SqlWriter) {
         if !update.orders.is_empty() {
@@ -252,6 +336,8 @@ pub trait QueryBuilder:
             self.prepare_table_ref(table, sql);
         }
 
+        self.prepare_delete_using(delete, sql);
+
         self.prepare_output(&delete.returning, sql);
 
         self.prepare_condition(&delete.r#where, "WHERE", sql);
@@ -263,6 +349,115 @@ pub trait QueryBuilder:
         self.prepare_returning(&delete.returning, sql);
     }
 
+    /// Translate [`ExplainQuery`] into SQL statement.
+    fn prepare_explain_query(&self, explain: &ExplainQuery, sql: &mut dyn SqlWriter) {
+        if explain.analyze {
+            write!(sql, "EXPLAIN (ANALYZE, FORMAT JSON) ").unwrap();
+        } else {
+            write!(sql, "EXPLAIN (FORMAT JSON) ").unwrap();
+        }
+        self.prepare_query_statement(&explain.query, sql);
+    }
+
+    /// Translate [`MergeStatement`] into SQL statement.
+    fn prepare_merge_statement(&self, merge: &MergeStatement, sql: &mut dyn SqlWriter) {
+        write!(sql, "MERGE").unwrap();
+
+        if let Some(table) = &merge.table {
+            write!(sql, " INTO ").unwrap();
+            self.prepare_table_ref(table, sql);
+        }
+
+        if let Some(using) = &merge.using {
+            write!(sql, " USING ").unwrap();
+            self.prepare_table_ref(using, sql);
+        }
+
+        self.prepare_condition(&merge.on, "ON", sql);
+
+        if !merge.when_matched.is_empty() {
+            write!(sql, " WHEN MATCHED THEN UPDATE SET ").unwrap();
+            merge.when_matched.iter().fold(true, |first, update| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                match update {
+                    OnConflictUpdate::Column(col) => {
+                        col.prepare(sql.as_iden_writer(), self.quote());
+                        write!(sql, " = ").unwrap();
+                        self.prepare_merge_source_column(merge, col, sql);
+                    }
+                    OnConflictUpdate::Expr(col, expr) => {
+                        col.prepare(sql.as_iden_writer(), self.quote());
+                        write!(sql, " = ").unwrap();
+                        self.prepare_simple_expr(expr, sql);
+                    }
+                }
+                false
+            });
+        }
+
+        if !merge.when_not_matched.is_empty() {
+            write!(sql, " WHEN NOT MATCHED THEN INSERT (").unwrap();
+            merge.when_not_matched.iter().fold(true, |first, insert| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                match insert {
+                    OnConflictUpdate::Column(col) | OnConflictUpdate::Expr(col, _) => {
+                        col.prepare(sql.as_iden_writer(), self.quote());
+                    }
+                }
+                false
+            });
+            write!(sql, ") VALUES (").unwrap();
+            merge.when_not_matched.iter().fold(true, |first, insert| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                match insert {
+                    OnConflictUpdate::Column(col) => {
+                        self.prepare_merge_source_column(merge, col, sql);
+                    }
+                    OnConflictUpdate::Expr(_, expr) => {
+                        self.prepare_simple_expr(expr, sql);
+                    }
+                }
+                false
+            });
+            write!(sql, ")").unwrap();
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a column of the source rows in [`MergeStatement`]
+    fn prepare_merge_source_column(
+        &self,
+        merge: &MergeStatement,
+        col: &DynIden,
+        sql: &mut dyn SqlWriter,
+    ) {
+        if let Some(source) = merge.source_name() {
+            source.prepare(sql.as_iden_writer(), self.quote());
+            write!(sql, ".").unwrap();
+        }
+        col.prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    /// Translate USING clause in [`DeleteStatement`].
+    fn prepare_delete_using(&self, delete: &DeleteStatement, sql: &mut dyn SqlWriter) {
+        if !delete.using.is_empty() {
+            write!(sql, " USING ").unwrap();
+            delete.using.iter().fold(true, |first, table_ref| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_table_ref(table_ref, sql);
+                false
+            });
+        }
+    }
+
     /// Translate ORDER BY expression in [`DeleteStatement`].
     fn prepare_delete_order_by(&self, delete: &DeleteStatement, sql: &mut dyn SqlWriter) {
         if !delete.orders.is_empty() {
@@ -311,10 +506,7 @@ pub trait QueryBuilder:
                     write!(sql, ")").unwrap();
                 }
             }
-            SimpleExpr::FunctionCall(func) => {
-                self.prepare_function_name(&func.func, sql);
-                self.prepare_function_arguments(func, sql);
-            }
+            SimpleExpr::FunctionCall(func) => self.prepare_function_call(func, sql),
             SimpleExpr::Binary(left, op, right) => match (op, right.as_ref()) {
                 (BinOper::In, SimpleExpr::Tuple(t)) if t.is_empty() => {
                     self.binary_expr(&1i32.into(), &BinOper::Equal, &2i32.into(), sql)
@@ -322,6 +514,7 @@ pub trait QueryBuilder:
                 (BinOper::NotIn, SimpleExpr::Tuple(t)) if t.is_empty() => {
                     self.binary_expr(&1i32.into(), &BinOper::Equal, &1i32.into(), sql)
                 }
+                (BinOper::ILike | BinOper::NotILike, _) => self.prepare_ilike(left, op, right, sql),
                 _ => self.binary_expr(left, op, right, sql),
             },
             SimpleExpr::SubQuery(oper, sel) => {
@@ -347,7 +540,7 @@ pub trait QueryBuilder:
                 write!(sql, ")").unwrap();
             }
             SimpleExpr::Custom(s) => {
-                write!(sql, "{s}").unwrap();
+                write!(sql.as_writer(), "{s}").unwrap();
             }
             SimpleExpr::CustomWithExpr(expr, values) => {
                 let (placeholder, numbered) = self.placeholder();
@@ -357,7 +550,7 @@ pub trait QueryBuilder:
                     match token {
                         Token::Punctuation(mark) if mark == placeholder => match tokenizer.peek() {
                             Some(Token::Punctuation(mark)) if mark == placeholder => {
-                                write!(sql, "{mark}").unwrap();
+                                write!(sql.as_writer(), "{mark}").unwrap();
                                 tokenizer.next();
                             }
                             Some(Token::Unquoted(tok)) if numbered => {
@@ -371,7 +564,7 @@ pub trait QueryBuilder:
                                 count += 1;
                             }
                         },
-                        _ => write!(sql, "{token}").unwrap(),
+                        _ => write!(sql.as_writer(), "{token}").unwrap(),
                     };
                 }
             }
@@ -420,6 +613,71 @@ pub trait QueryBuilder:
         }
     }
 
+    /// Translate GROUP BY expressions and [`GroupingSet`]s in [`SelectStatement`].
+    fn prepare_select_group_by(&self, select: &SelectStatement, sql: &mut dyn SqlWriter) {
+        if !select.groups.is_empty() || !select.grouping_sets.is_empty() {
+            write!(sql, " GROUP BY ").unwrap();
+            let first = select
+                .groups
+                .iter()
+                .fold(true, |first, (expr, positional)| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_group_by_expr(select, expr, *positional, sql);
+                    false
+                });
+            select
+                .grouping_sets
+                .iter()
+                .fold(first, |first, grouping_set| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_grouping_set(grouping_set, sql);
+                    false
+                });
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a GROUP BY expression of the [`SelectStatement`].
+    /// A `positional` expression may be written as its position in the select list.
+    fn prepare_group_by_expr(
+        &self,
+        _select: &SelectStatement,
+        expr: &SimpleExpr,
+        _positional: bool,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.prepare_simple_expr(expr, sql);
+    }
+
+    /// Translate [`GroupingSet`] into SQL statement.
+    fn prepare_grouping_set(&self, grouping_set: &GroupingSet, sql: &mut dyn SqlWriter) {
+        match grouping_set {
+            GroupingSet::Rollup(exprs) => {
+                write!(sql, "ROLLUP ").unwrap();
+                self.prepare_tuple(exprs, sql);
+            }
+            GroupingSet::Cube(exprs) => {
+                write!(sql, "CUBE ").unwrap();
+                self.prepare_tuple(exprs, sql);
+            }
+            GroupingSet::Sets(sets) => {
+                write!(sql, "GROUPING SETS (").unwrap();
+                sets.iter().fold(true, |first, exprs| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_tuple(exprs, sql);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+        }
+    }
+
     /// Translate [`IndexHint`] into SQL statement.
     fn prepare_index_hints(&self, _select: &SelectStatement, _sql: &mut dyn SqlWriter) {}
 
@@ -460,7 +718,10 @@ pub trait QueryBuilder:
         match &select_expr.window {
             Some(WindowSelectType::Name(name)) => {
                 write!(sql, " OVER ").unwrap();
-                name.prepare(sql.as_writer(), self.quote())
+                name.prepare(sql.as_iden_writer(), self.quote())
+            }
+            Some(WindowSelectType::Query(window)) if window.is_empty() => {
+                write!(sql, " OVER ()").unwrap();
             }
             Some(WindowSelectType::Query(window)) => {
                 write!(sql, " OVER ").unwrap();
@@ -473,7 +734,7 @@ pub trait QueryBuilder:
 
         if let Some(alias) = &select_expr.alias {
             write!(sql, " AS ").unwrap();
-            alias.prepare(sql.as_writer(), self.quote());
+            alias.prepare(sql.as_iden_writer(), self.quote());
         };
     }
 
@@ -501,46 +762,95 @@ pub trait QueryBuilder:
                 write!(sql, "(").unwrap();
                 self.prepare_select_statement(query, sql);
                 write!(sql, ")").unwrap();
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                write!(sql, "{}", self.table_alias_keyword()).unwrap();
+                alias.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::ValuesList(values, alias) => {
-                write!(sql, "(").unwrap();
-                self.prepare_values_list(values, sql);
-                write!(sql, ")").unwrap();
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                self.prepare_values_list_table(values, alias, &[], sql);
+            }
+            TableRef::ValuesListColumns(values, alias, columns) => {
+                self.prepare_values_list_table(values, alias, columns, sql);
             }
             TableRef::FunctionCall(func, alias) => {
-                self.prepare_function_name(&func.func, sql);
-                self.prepare_function_arguments(func, sql);
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                self.prepare_function_call(func, sql);
+                write!(sql, "{}", self.table_alias_keyword()).unwrap();
+                alias.prepare(sql.as_iden_writer(), self.quote());
+            }
+            #[cfg(feature = "backend-postgres")]
+            TableRef::Only(table_ref) => self.prepare_table_ref_only(table_ref, sql),
+            #[cfg(feature = "backend-postgres")]
+            TableRef::TableSample(table_ref, sample) => {
+                self.prepare_table_sample(table_ref, sample, sql)
             }
             _ => self.prepare_table_ref_iden(table_ref, sql),
         }
     }
 
+    #[cfg(feature = "backend-postgres")]
+    /// Translate a table selected with `ONLY` into SQL statement.
+    /// Only Postgres renders the modifier; other backends select from the table as usual.
+    fn prepare_table_ref_only(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
+        self.prepare_table_ref(table_ref, sql);
+    }
+
+    #[cfg(feature = "backend-postgres")]
+    /// Translate a table sampled with `TABLESAMPLE` into SQL statement.
+    fn prepare_table_sample(
+        &self,
+        _table_ref: &TableRef,
+        _sample: &crate::extension::postgres::TableSample,
+        _sql: &mut dyn SqlWriter,
+    ) {
+        panic!("TABLESAMPLE is only supported by Postgres");
+    }
+
+    #[doc(hidden)]
+    /// Translate a values list used as a table, with its alias and column names, into SQL statement.
+    fn prepare_values_list_table(
+        &self,
+        values: &[ValueTuple],
+        alias: &DynIden,
+        columns: &[DynIden],
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "(").unwrap();
+        self.prepare_values_list(values, sql);
+        write!(sql, ")").unwrap();
+        write!(sql, "{}", self.table_alias_keyword()).unwrap();
+        alias.prepare(sql.as_iden_writer(), self.quote());
+        if !columns.is_empty() {
+            write!(sql, "(").unwrap();
+            columns.iter().fold(true, |first, column| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                column.prepare(sql.as_iden_writer(), self.quote());
+                false
+            });
+            write!(sql, ")").unwrap();
+        }
+    }
+
     fn prepare_column_ref(&self, column_ref: &ColumnRef, sql: &mut dyn SqlWriter) {
         match column_ref {
-            ColumnRef::Column(column) => column.prepare(sql.as_writer(), self.quote()),
+            ColumnRef::Column(column) => column.prepare(sql.as_iden_writer(), self.quote()),
             ColumnRef::TableColumn(table, column) => {
-                table.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                column.prepare(sql.as_writer(), self.quote());
+                column.prepare(sql.as_iden_writer(), self.quote());
             }
             ColumnRef::SchemaTableColumn(schema, table, column) => {
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                table.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                column.prepare(sql.as_writer(), self.quote());
+                column.prepare(sql.as_iden_writer(), self.quote());
             }
             ColumnRef::Asterisk => {
                 write!(sql, "*").unwrap();
             }
             ColumnRef::TableAsterisk(table) => {
-                table.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".*").unwrap();
             }
         };
@@ -559,6 +869,10 @@ pub trait QueryBuilder:
     }
 
     fn prepare_bin_oper_common(&self, bin_oper: &BinOper, sql: &mut dyn SqlWriter) {
+        if let BinOper::Custom(raw) = bin_oper {
+            write!(sql.as_writer(), "{raw}").unwrap();
+            return;
+        }
         write!(
             sql,
             "{}",
@@ -567,6 +881,8 @@ pub trait QueryBuilder:
                 BinOper::Or => "OR",
                 BinOper::Like => "LIKE",
                 BinOper::NotLike => "NOT LIKE",
+                BinOper::ILike => "ILIKE",
+                BinOper::NotILike => "NOT ILIKE",
                 BinOper::Is => "IS",
                 BinOper::IsNot => "IS NOT",
                 BinOper::In => "IN",
@@ -588,7 +904,9 @@ pub trait QueryBuilder:
                 BinOper::RShift => ">>",
                 BinOper::As => "AS",
                 BinOper::Escape => "ESCAPE",
-                BinOper::Custom(raw) => raw,
+                BinOper::Overlaps => "OVERLAPS",
+                BinOper::SimilarTo => "SIMILAR TO",
+                BinOper::NotSimilarTo => "NOT SIMILAR TO",
                 BinOper::BitAnd => "&",
                 BinOper::BitOr => "|",
                 #[allow(unreachable_patterns)]
@@ -664,10 +982,14 @@ pub trait QueryBuilder:
                     Function::Avg => "AVG",
                     Function::Abs => "ABS",
                     Function::Coalesce => "COALESCE",
+                    Function::NullIf => "NULLIF",
+                    Function::Trim => "TRIM",
                     Function::Count => "COUNT",
                     Function::IfNull => self.if_null_function(),
-                    Function::Greatest => self.greatest_function(),
-                    Function::Least => self.least_function(),
+                    Function::Greatest | Function::GreatestIgnoreNulls => {
+                        self.greatest_function()
+                    }
+                    Function::Least | Function::LeastIgnoreNulls => self.least_function(),
                     Function::CharLength => self.char_length_function(),
                     Function::Cast => "CAST",
                     Function::Lower => "LOWER",
@@ -678,6 +1000,15 @@ pub trait QueryBuilder:
                     Function::Random => self.random_function(),
                     Function::Round => "ROUND",
                     Function::Md5 => "MD5",
+                    Function::Extract(_) => "EXTRACT",
+                    Function::TimestampDiff => "TIMESTAMPDIFF",
+                    Function::RowNumber => "ROW_NUMBER",
+                    Function::CastAsType(_) | Function::PgCastAsType(_) => "CAST",
+                    Function::Hash => "MD5",
+                    Function::FormatDate(_) => "TO_CHAR",
+                    Function::StringAgg => self.string_agg_function(),
+                    #[cfg(feature = "backend-mysql")]
+                    Function::MySqlFunction(_) => unimplemented!(),
                     #[cfg(feature = "backend-postgres")]
                     Function::PgFunction(_) => unimplemented!(),
                 }
@@ -686,20 +1017,261 @@ pub trait QueryBuilder:
         }
     }
 
+    #[doc(hidden)]
+    /// Translate `EXTRACT` function call into SQL statement.
+    fn prepare_extract(&self, part: &DatePart, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        write!(sql, "EXTRACT({} FROM ", self.date_part(part)).unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Translate the number of seconds between two timestamps into SQL statement.
+    fn prepare_timestamp_diff(
+        &self,
+        start: &SimpleExpr,
+        end: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "EXTRACT(EPOCH FROM ").unwrap();
+        self.prepare_simple_expr(&end.clone().sub(start.clone()), sql);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Translate `GREATEST` / `LEAST` skipping NULL arguments into SQL statement.
+    fn prepare_greatest_least_ignore_nulls(
+        &self,
+        function: &Function,
+        args: &[SimpleExpr],
+        sql: &mut dyn SqlWriter,
+    ) {
+        if let [arg] = args {
+            self.prepare_simple_expr(arg, sql);
+            return;
+        }
+        self.prepare_function_name(function, sql);
+        write!(sql, "(").unwrap();
+        for (i, arg) in args.iter().enumerate() {
+            if i != 0 {
+                write!(sql, ", ").unwrap();
+            }
+            // A NULL argument takes the value of the first non-NULL one among the others
+            write!(sql, "COALESCE(").unwrap();
+            self.prepare_simple_expr(arg, sql);
+            for (j, other) in args.iter().enumerate() {
+                if j != i {
+                    write!(sql, ", ").unwrap();
+                    self.prepare_simple_expr(other, sql);
+                }
+            }
+            write!(sql, ")").unwrap();
+        }
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Translate a deterministic non-negative integer hash of the expression into SQL statement.
+    fn prepare_hash(&self, _expr: &SimpleExpr, _sql: &mut dyn SqlWriter) {
+        panic!("Func::hash_mod is only supported by Mysql and Postgres");
+    }
+
+    #[cfg(feature = "backend-mysql")]
+    #[doc(hidden)]
+    /// Translate a full-text search of the columns, followed by the search string, into SQL statement.
+    fn prepare_match_against(
+        &self,
+        _args: &[SimpleExpr],
+        _mode: &crate::extension::mysql::MatchAgainstMode,
+        _sql: &mut dyn SqlWriter,
+    ) {
+        panic!("MATCH ... AGAINST is only supported by Mysql");
+    }
+
+    #[doc(hidden)]
+    /// Translate a date / time formatting function call into SQL statement.
+    fn prepare_format_date(&self, expr: &SimpleExpr, format: &str, sql: &mut dyn SqlWriter) {
+        write!(sql, "TO_CHAR(").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ", ").unwrap();
+        self.prepare_value(&self.translate_date_format(format).into(), sql);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Translate the portable date format of [`Func::format_date`] into the backend's format.
+    fn translate_date_format(&self, format: &str) -> String {
+        let mut translated = String::new();
+        let mut literal = String::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('%') => literal.push('%'),
+                Some(spec) => {
+                    if !literal.is_empty() {
+                        translated.push_str(&self.date_format_literal(&literal));
+                        literal.clear();
+                    }
+                    translated.push_str(self.date_format_specifier(spec));
+                }
+                None => panic!("Incomplete date format specifier at the end of {format:?}"),
+            }
+        }
+        if !literal.is_empty() {
+            translated.push_str(&self.date_format_literal(&literal));
+        }
+        translated
+    }
+
+    #[doc(hidden)]
+    /// The backend's date format specifier of a portable `strftime` style specifier.
+    fn date_format_specifier(&self, spec: char) -> &str {
+        match spec {
+            'Y' => "YYYY",
+            'm' => "MM",
+            'd' => "DD",
+            'H' => "HH24",
+            'M' => "MI",
+            'S' => "SS",
+            _ => panic!("Unsupported date format specifier %{spec}"),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Escape literal text in the backend's date format.
+    fn date_format_literal(&self, literal: &str) -> String {
+        // Letters may be taken as template patterns unless double quoted
+        if literal.chars().any(char::is_alphabetic) {
+            format!("\"{}\"", literal.replace('"', "\\\""))
+        } else {
+            literal.to_owned()
+        }
+    }
+
+    #[doc(hidden)]
+    /// The keyword of a date / time field used by `EXTRACT`.
+    fn date_part(&self, part: &DatePart) -> &str {
+        match part {
+            DatePart::Year => "YEAR",
+            DatePart::Month => "MONTH",
+            DatePart::Day => "DAY",
+            DatePart::Hour => "HOUR",
+            DatePart::Minute => "MINUTE",
+            DatePart::Second => "SECOND",
+            DatePart::Epoch => "EPOCH",
+        }
+    }
+
+    /// Translate the target [`ColumnType`] of a `CAST` into SQL statement.
+    /// The default writes the SQL standard type names, backends override it with their own.
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        match column_type {
+            ColumnType::Char(Some(length)) => write!(sql, "char({length})").unwrap(),
+            ColumnType::Char(None) => write!(sql, "char").unwrap(),
+            ColumnType::String(StringLen::N(length)) => write!(sql, "varchar({length})").unwrap(),
+            ColumnType::String(_) => write!(sql, "varchar").unwrap(),
+            ColumnType::Text => write!(sql, "text").unwrap(),
+            ColumnType::TinyInteger
+            | ColumnType::SmallInteger
+            | ColumnType::TinyUnsigned
+            | ColumnType::SmallUnsigned => write!(sql, "smallint").unwrap(),
+            ColumnType::Integer | ColumnType::Unsigned => write!(sql, "integer").unwrap(),
+            ColumnType::BigInteger | ColumnType::BigUnsigned => write!(sql, "bigint").unwrap(),
+            ColumnType::Float => write!(sql, "real").unwrap(),
+            ColumnType::Double => write!(sql, "double precision").unwrap(),
+            ColumnType::Decimal(Some((precision, scale)))
+            | ColumnType::Money(Some((precision, scale))) => {
+                write!(sql, "decimal({precision}, {scale})").unwrap()
+            }
+            ColumnType::Decimal(None) | ColumnType::Money(None) => write!(sql, "decimal").unwrap(),
+            ColumnType::DateTime | ColumnType::Timestamp(None) => write!(sql, "timestamp").unwrap(),
+            ColumnType::Timestamp(Some(precision)) => {
+                write!(sql, "timestamp({precision})").unwrap()
+            }
+            ColumnType::TimestampWithTimeZone => write!(sql, "timestamp with time zone").unwrap(),
+            ColumnType::Time => write!(sql, "time").unwrap(),
+            ColumnType::Date => write!(sql, "date").unwrap(),
+            ColumnType::Year => write!(sql, "integer").unwrap(),
+            ColumnType::Interval(_, _) => write!(sql, "interval").unwrap(),
+            ColumnType::Binary(length) => write!(sql, "binary({length})").unwrap(),
+            ColumnType::VarBinary(StringLen::N(length)) => {
+                write!(sql, "varbinary({length})").unwrap()
+            }
+            ColumnType::VarBinary(_) => write!(sql, "varbinary").unwrap(),
+            ColumnType::Blob => write!(sql, "blob").unwrap(),
+            ColumnType::Bit(Some(length)) => write!(sql, "bit({length})").unwrap(),
+            ColumnType::Bit(None) => write!(sql, "bit").unwrap(),
+            ColumnType::VarBit(length) => write!(sql, "bit varying({length})").unwrap(),
+            ColumnType::Boolean => write!(sql, "boolean").unwrap(),
+            ColumnType::Json | ColumnType::JsonBinary => write!(sql, "json").unwrap(),
+            ColumnType::Uuid => write!(sql, "uuid").unwrap(),
+            ColumnType::Array(elem_type) => {
+                self.prepare_cast_type(elem_type, sql);
+                write!(sql, " array").unwrap();
+            }
+            ColumnType::Vector(_) => write!(sql, "vector").unwrap(),
+            ColumnType::Cidr => write!(sql, "cidr").unwrap(),
+            ColumnType::Inet => write!(sql, "inet").unwrap(),
+            ColumnType::MacAddr => write!(sql, "macaddr").unwrap(),
+            ColumnType::LTree => write!(sql, "ltree").unwrap(),
+            ColumnType::Custom(iden) => write!(sql.as_writer(), "{}", iden.to_string()).unwrap(),
+            ColumnType::Enum { name, .. } => name.prepare(sql.as_iden_writer(), self.quote()),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Translate a cast with the `::type` shorthand, which only Postgres supports.
+    fn prepare_pg_cast(
+        &self,
+        expr: &SimpleExpr,
+        column_type: &ColumnType,
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "CAST(").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, " AS ").unwrap();
+        self.prepare_cast_type(column_type, sql);
+        write!(sql, ")").unwrap();
+    }
+
     fn prepare_function_arguments(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
+        self.prepare_function_arguments_common(func, sql)
+    }
+
+    fn prepare_function_arguments_common(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
         write!(sql, "(").unwrap();
         for (i, expr) in func.args.iter().enumerate() {
             if i != 0 {
                 write!(sql, ", ").unwrap();
             }
-            if func.mods[i].distinct {
+            if func.mods[i].distinct || (i == 0 && func.distinct) {
                 write!(sql, "DISTINCT ").unwrap();
             }
             self.prepare_simple_expr(expr, sql);
         }
+        self.prepare_function_order_by(&func.order_by, sql);
         write!(sql, ")").unwrap();
     }
 
+    #[doc(hidden)]
+    /// Translate the ORDER BY of an ordered aggregate, inside its parentheses.
+    fn prepare_function_order_by(&self, order_by: &[OrderExpr], sql: &mut dyn SqlWriter) {
+        if !order_by.is_empty() {
+            write!(sql, " ORDER BY ").unwrap();
+            order_by.iter().fold(true, |first, expr| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_order_expr(expr, sql);
+                false
+            });
+        }
+    }
+
     /// Translate [`QueryStatement`] into SQL statement.
     fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter);
 
@@ -709,6 +1281,14 @@ pub trait QueryBuilder:
     }
 
     fn prepare_with_clause(&self, with_clause: &WithClause, sql: &mut dyn SqlWriter) {
+        if (with_clause.search.is_some() || with_clause.cycle.is_some())
+            && with_clause
+                .cte_expressions
+                .iter()
+                .any(|cte| cte.search.is_some() || cte.cycle.is_some())
+        {
+            panic!("SEARCH and CYCLE can be set on the WithClause or on its common table expressions, not both");
+        }
         self.prepare_with_clause_start(with_clause, sql);
         self.prepare_with_clause_common_tables(with_clause, sql);
         if with_clause.recursive {
@@ -723,53 +1303,82 @@ pub trait QueryBuilder:
     ) {
         if with_clause.recursive {
             if let Some(search) = &with_clause.search {
-                write!(
-                    sql,
-                    "SEARCH {} FIRST BY ",
-                    match &search.order.as_ref().unwrap() {
-                        SearchOrder::BREADTH => "BREADTH",
-                        SearchOrder::DEPTH => "DEPTH",
-                    }
-                )
-                .unwrap();
-
-                self.prepare_simple_expr(&search.expr.as_ref().unwrap().expr, sql);
-
-                write!(sql, " SET ").unwrap();
-
-                search
-                    .expr
-                    .as_ref()
-                    .unwrap()
-                    .alias
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
+                self.prepare_with_clause_search(search, sql);
                 write!(sql, " ").unwrap();
             }
             if let Some(cycle) = &with_clause.cycle {
-                write!(sql, "CYCLE ").unwrap();
-
-                self.prepare_simple_expr(cycle.expr.as_ref().unwrap(), sql);
-
-                write!(sql, " SET ").unwrap();
-
-                cycle
-                    .set_as
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
-                write!(sql, " USING ").unwrap();
-                cycle
-                    .using
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
+                self.prepare_with_clause_cycle(cycle, sql);
                 write!(sql, " ").unwrap();
             }
         }
     }
 
+    #[doc(hidden)]
+    /// Translate [`Search`] into SQL statement.
+    fn prepare_with_clause_search(&self, search: &Search, sql: &mut dyn SqlWriter) {
+        write!(
+            sql,
+            "SEARCH {} FIRST BY ",
+            match &search.order.as_ref().unwrap() {
+                SearchOrder::BREADTH => "BREADTH",
+                SearchOrder::DEPTH => "DEPTH",
+            }
+        )
+        .unwrap();
+
+        self.prepare_with_clause_column_list(&search.expr.as_ref().unwrap().expr, sql);
+
+        write!(sql, " SET ").unwrap();
+
+        search
+            .expr
+            .as_ref()
+            .unwrap()
+            .alias
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    #[doc(hidden)]
+    /// Translate [`Cycle`] into SQL statement.
+    fn prepare_with_clause_cycle(&self, cycle: &Cycle, sql: &mut dyn SqlWriter) {
+        write!(sql, "CYCLE ").unwrap();
+
+        self.prepare_with_clause_column_list(cycle.expr.as_ref().unwrap(), sql);
+
+        write!(sql, " SET ").unwrap();
+
+        cycle
+            .set_as
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_iden_writer(), self.quote());
+        write!(sql, " USING ").unwrap();
+        cycle
+            .using
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    #[doc(hidden)]
+    /// Translate the columns of a SEARCH or CYCLE clause, which are listed without parentheses.
+    fn prepare_with_clause_column_list(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        match expr {
+            SimpleExpr::Tuple(exprs) => {
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql);
+                    false
+                });
+            }
+            _ => self.prepare_simple_expr(expr, sql),
+        }
+    }
+
     fn prepare_with_clause_common_tables(&self, with_clause: &WithClause, sql: &mut dyn SqlWriter) {
         let mut cte_first = true;
         assert_ne!(
@@ -796,7 +1405,7 @@ pub trait QueryBuilder:
         cte.table_name
             .as_ref()
             .unwrap()
-            .prepare(sql.as_writer(), self.quote());
+            .prepare(sql.as_iden_writer(), self.quote());
 
         if cte.cols.is_empty() {
             write!(sql, " ").unwrap();
@@ -809,7 +1418,7 @@ pub trait QueryBuilder:
                     write!(sql, ", ").unwrap();
                 }
                 col_first = false;
-                col.prepare(sql.as_writer(), self.quote());
+                col.prepare(sql.as_iden_writer(), self.quote());
             }
 
             write!(sql, ") ").unwrap();
@@ -823,7 +1432,28 @@ pub trait QueryBuilder:
 
         self.prepare_query_statement(cte.query.as_ref().unwrap().deref(), sql);
 
-        write!(sql, ") ").unwrap();
+        write!(sql, ")").unwrap();
+
+        self.prepare_with_query_clause_search_cycle(cte, sql);
+
+        write!(sql, " ").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Translate the SEARCH and CYCLE clauses of a recursive [`CommonTableExpression`].
+    fn prepare_with_query_clause_search_cycle(
+        &self,
+        cte: &CommonTableExpression,
+        sql: &mut dyn SqlWriter,
+    ) {
+        if let Some(search) = &cte.search {
+            write!(sql, " ").unwrap();
+            self.prepare_with_clause_search(search, sql);
+        }
+        if let Some(cycle) = &cte.cycle {
+            write!(sql, " ").unwrap();
+            self.prepare_with_clause_cycle(cycle, sql);
+        }
     }
 
     fn prepare_with_query_clause_materialization(
@@ -921,7 +1551,7 @@ pub trait QueryBuilder:
             self.prepare_simple_expr(&order_expr.expr, sql);
             write!(sql, "=").unwrap();
             let value = self.value_to_string(value);
-            write!(sql, "{value}").unwrap();
+            write!(sql.as_writer(), "{value}").unwrap();
             write!(sql, " THEN {i} ").unwrap();
             i += 1;
         }
@@ -934,7 +1564,7 @@ pub trait QueryBuilder:
     /// Write [`Value`] inline.
     fn prepare_constant(&self, value: &Value, sql: &mut dyn SqlWriter) {
         let string = self.value_to_string(value);
-        write!(sql, "{string}").unwrap();
+        write!(sql.as_writer(), "{string}").unwrap();
     }
 
     /// Translate a `&[ValueTuple]` into a VALUES list.
@@ -973,11 +1603,26 @@ pub trait QueryBuilder:
 
     /// Translate [`Keyword`] into SQL statement.
     fn prepare_keyword(&self, keyword: &Keyword, sql: &mut dyn SqlWriter) {
+        self.prepare_keyword_common(keyword, sql)
+    }
+
+    fn prepare_keyword_common(&self, keyword: &Keyword, sql: &mut dyn SqlWriter) {
         match keyword {
             Keyword::Null => write!(sql, "NULL").unwrap(),
             Keyword::CurrentDate => write!(sql, "CURRENT_DATE").unwrap(),
             Keyword::CurrentTime => write!(sql, "CURRENT_TIME").unwrap(),
             Keyword::CurrentTimestamp => write!(sql, "CURRENT_TIMESTAMP").unwrap(),
+            Keyword::CurrentTimestampWithPrecision(precision) => {
+                write!(sql, "CURRENT_TIMESTAMP({precision})").unwrap()
+            }
+            Keyword::Default => write!(sql, "DEFAULT").unwrap(),
+            Keyword::RowId(table) => {
+                if let Some(table) = table {
+                    table.prepare(sql.as_iden_writer(), self.quote());
+                    write!(sql, ".").unwrap();
+                }
+                write!(sql, "{}", self.row_id_column()).unwrap();
+            }
             Keyword::Custom(iden) => iden.unquoted(sql.as_writer()),
         }
     }
@@ -1036,6 +1681,10 @@ pub trait QueryBuilder:
             Value::IpNetwork(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-mac_address")]
             Value::MacAddress(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "postgres-array")]
             Value::Array(_, None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "postgres-vector")]
@@ -1128,6 +1777,16 @@ pub trait QueryBuilder:
             Value::IpNetwork(Some(v)) => write!(s, "'{v}'").unwrap(),
             #[cfg(feature = "with-mac_address")]
             Value::MacAddress(Some(v)) => write!(s, "'{v}'").unwrap(),
+            #[cfg(feature = "with-bit-vec")]
+            Value::BitVec(Some(v)) => {
+                write!(s, "B'").unwrap();
+                for b in v.iter() {
+                    write!(s, "{}", if b { '1' } else { '0' }).unwrap();
+                }
+                write!(s, "'").unwrap();
+            }
+            #[cfg(feature = "postgres-interval")]
+            Value::Interval(Some(v)) => write!(s, "INTERVAL '{v}'").unwrap(),
         };
         s
     }
@@ -1162,7 +1821,7 @@ pub trait QueryBuilder:
             }
             match target {
                 OnConflictTarget::ConflictColumn(col) => {
-                    col.prepare(sql.as_writer(), self.quote());
+                    col.prepare(sql.as_iden_writer(), self.quote());
                 }
 
                 OnConflictTarget::ConflictExpr(expr) => {
@@ -1202,12 +1861,12 @@ pub trait QueryBuilder:
                         }
                         match update_strat {
                             OnConflictUpdate::Column(col) => {
-                                col.prepare(sql.as_writer(), self.quote());
+                                col.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, " = ").unwrap();
                                 self.prepare_on_conflict_excluded_table(col, sql);
                             }
                             OnConflictUpdate::Expr(col, expr) => {
-                                col.prepare(sql.as_writer(), self.quote());
+                                col.prepare(sql.as_iden_writer(), self.quote());
                                 write!(sql, " = ").unwrap();
                                 self.prepare_simple_expr(expr, sql);
                             }
@@ -1242,7 +1901,7 @@ pub trait QueryBuilder:
         )
         .unwrap();
         write!(sql, ".").unwrap();
-        col.prepare(sql.as_writer(), self.quote());
+        col.prepare(sql.as_iden_writer(), self.quote());
     }
 
     #[doc(hidden)]
@@ -1284,6 +1943,19 @@ pub trait QueryBuilder:
                         false
                     });
                 }
+                ReturningClause::ExprsAs(exprs) => {
+                    exprs.iter().fold(true, |first, (expr, alias)| {
+                        if !first {
+                            write!(sql, ", ").unwrap()
+                        }
+                        self.prepare_simple_expr(expr, sql);
+                        if let Some(alias) = alias {
+                            write!(sql, " AS ").unwrap();
+                            alias.prepare(sql.as_iden_writer(), self.quote());
+                        }
+                        false
+                    });
+                }
             }
         }
     }
@@ -1318,20 +1990,29 @@ pub trait QueryBuilder:
         self.prepare_simple_expr(&simple_expr, sql);
     }
 
+    #[doc(hidden)]
+    /// Translate [`FrameType`] into SQL statement.
+    fn prepare_frame_type(&self, frame_type: &FrameType, sql: &mut dyn SqlWriter) {
+        self.prepare_frame_type_common(frame_type, sql);
+    }
+
+    fn prepare_frame_type_common(&self, frame_type: &FrameType, sql: &mut dyn SqlWriter) {
+        match frame_type {
+            FrameType::Range => write!(sql, "RANGE").unwrap(),
+            FrameType::Rows => write!(sql, "ROWS").unwrap(),
+            FrameType::Groups => write!(sql, "GROUPS").unwrap(),
+        }
+    }
+
     #[doc(hidden)]
     /// Translate [`Frame`] into SQL statement.
     fn prepare_frame(&self, frame: &Frame, sql: &mut dyn SqlWriter) {
         match *frame {
             Frame::UnboundedPreceding => write!(sql, "UNBOUNDED PRECEDING").unwrap(),
-            Frame::Preceding(v) => {
-                self.prepare_value(&v.into(), sql);
-                write!(sql, "PRECEDING").unwrap();
-            }
+            // Offsets are inlined, as not every backend accepts a parameter here
+            Frame::Preceding(v) => write!(sql, "{v} PRECEDING").unwrap(),
             Frame::CurrentRow => write!(sql, "CURRENT ROW").unwrap(),
-            Frame::Following(v) => {
-                self.prepare_value(&v.into(), sql);
-                write!(sql, "FOLLOWING").unwrap();
-            }
+            Frame::Following(v) => write!(sql, "{v} FOLLOWING").unwrap(),
             Frame::UnboundedFollowing => write!(sql, "UNBOUNDED FOLLOWING").unwrap(),
         }
     }
@@ -1351,7 +2032,10 @@ pub trait QueryBuilder:
         }
 
         if !window.order_by.is_empty() {
-            write!(sql, " ORDER BY ").unwrap();
+            if !window.partition_by.is_empty() {
+                write!(sql, " ").unwrap();
+            }
+            write!(sql, "ORDER BY ").unwrap();
             window.order_by.iter().fold(true, |first, expr| {
                 if !first {
                     write!(sql, ", ").unwrap()
@@ -1362,10 +2046,11 @@ pub trait QueryBuilder:
         }
 
         if let Some(frame) = &window.frame {
-            match frame.r#type {
-                FrameType::Range => write!(sql, " RANGE ").unwrap(),
-                FrameType::Rows => write!(sql, " ROWS ").unwrap(),
-            };
+            if !window.partition_by.is_empty() || !window.order_by.is_empty() {
+                write!(sql, " ").unwrap();
+            }
+            self.prepare_frame_type(&frame.r#type, sql);
+            write!(sql, " ").unwrap();
             if let Some(end) = &frame.end {
                 write!(sql, "BETWEEN ").unwrap();
                 self.prepare_frame(&frame.start, sql);
@@ -1439,6 +2124,56 @@ pub trait QueryBuilder:
         }
     }
 
+    /// Translate [`FunctionCall`] into SQL statement.
+    fn prepare_function_call(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
+        match &func.func {
+            Function::Extract(part) => self.prepare_extract(part, &func.args[0], sql),
+            Function::TimestampDiff => {
+                self.prepare_timestamp_diff(&func.args[0], &func.args[1], sql)
+            }
+            Function::Hash => self.prepare_hash(&func.args[0], sql),
+            Function::GreatestIgnoreNulls | Function::LeastIgnoreNulls => {
+                self.prepare_greatest_least_ignore_nulls(&func.func, &func.args, sql)
+            }
+            Function::FormatDate(format) => self.prepare_format_date(&func.args[0], format, sql),
+            #[cfg(feature = "backend-mysql")]
+            Function::MySqlFunction(crate::extension::mysql::MySqlFunction::MatchAgainst(mode)) => {
+                self.prepare_match_against(&func.args, mode, sql)
+            }
+            Function::CastAsType(column_type) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr(&func.args[0], sql);
+                write!(sql, " AS ").unwrap();
+                self.prepare_cast_type(column_type, sql);
+                write!(sql, ")").unwrap();
+            }
+            Function::PgCastAsType(column_type) => {
+                self.prepare_pg_cast(&func.args[0], column_type, sql)
+            }
+            _ => {
+                self.prepare_function_name(&func.func, sql);
+                self.prepare_function_arguments(func, sql);
+                if let Some(filter) = &func.filter {
+                    write!(sql, " FILTER (WHERE ").unwrap();
+                    self.prepare_condition_where(filter, sql);
+                    write!(sql, ")").unwrap();
+                }
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a case-insensitive `ILIKE` or `NOT ILIKE` expression.
+    fn prepare_ilike(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.binary_expr(left, op, right, sql);
+    }
+
     #[doc(hidden)]
     /// Write a string surrounded by escaped quotes.
     fn write_string_quoted(&self, string: &str, buffer: &mut String) {
@@ -1479,6 +2214,12 @@ pub trait QueryBuilder:
         "CHAR_LENGTH"
     }
 
+    #[doc(hidden)]
+    /// The name of the pseudo column holding the physical row id
+    fn row_id_column(&self) -> &str {
+        "ctid"
+    }
+
     #[doc(hidden)]
     /// The name of the function that returns a random number
     fn random_function(&self) -> &str {
@@ -1486,6 +2227,60 @@ pub trait QueryBuilder:
         "RANDOM"
     }
 
+    #[doc(hidden)]
+    /// The name of the function that concatenates strings of a group.
+    fn string_agg_function(&self) -> &str {
+        "STRING_AGG"
+    }
+
+    #[doc(hidden)]
+    /// Whether the `ORDER BY` expressions of a `SELECT DISTINCT` must appear in the select list.
+    /// SQLite accepts ordering by any column.
+    fn distinct_order_by_must_be_selected(&self) -> bool {
+        true
+    }
+
+    /// Whether the `RETURNING` clause is supported.
+    ///
+    /// Capabilities can be probed to adapt generic code to the backend:
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// fn capabilities(builder: &dyn QueryBuilder) -> [bool; 4] {
+    ///     [
+    ///         builder.supports_returning(),
+    ///         builder.supports_on_conflict(),
+    ///         builder.supports_window_functions(),
+    ///         builder.supports_grouping_sets(),
+    ///     ]
+    /// }
+    ///
+    /// assert_eq!(capabilities(&MysqlQueryBuilder), [false, false, true, false]);
+    /// assert_eq!(capabilities(&PostgresQueryBuilder), [true, true, true, true]);
+    /// assert_eq!(capabilities(&SqliteQueryBuilder), [true, true, true, false]);
+    /// ```
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    /// Whether `ON CONFLICT` is supported, with a conflict target and a `WHERE` condition.
+    /// MySQL's `ON DUPLICATE KEY UPDATE` applies to any unique key and ignores both.
+    fn supports_on_conflict(&self) -> bool {
+        true
+    }
+
+    /// Whether window functions and the `OVER` clause are supported.
+    fn supports_window_functions(&self) -> bool {
+        true
+    }
+
+    /// Whether `GROUPING SETS`, `ROLLUP` and `CUBE` are supported.
+    /// MySQL only supports a `ROLLUP` of the whole `GROUP BY` list.
+    fn supports_grouping_sets(&self) -> bool {
+        true
+    }
+
     /// The keywords for insert default row.
     fn insert_default_keyword(&self) -> &str {
         "(DEFAULT)"
@@ -1526,7 +2321,9 @@ impl SubQueryStatement {
             InsertStatement(stmt) => query_builder.prepare_insert_statement(stmt, sql),
             UpdateStatement(stmt) => query_builder.prepare_update_statement(stmt, sql),
             DeleteStatement(stmt) => query_builder.prepare_delete_statement(stmt, sql),
+            MergeStatement(stmt) => query_builder.prepare_merge_statement(stmt, sql),
             WithStatement(stmt) => query_builder.prepare_with_query(stmt, sql),
+            ExplainStatement(stmt) => query_builder.prepare_explain_query(stmt, sql),
         }
     }
 }
@@ -1569,6 +2366,24 @@ impl EscapeBuilder for CommonSqlQueryBuilder {}
 
 impl TableRefBuilder for CommonSqlQueryBuilder {}
 
+/// Emulate `ILIKE` by matching the lowercased operands with `LIKE`
+#[cfg(any(feature = "backend-mysql", feature = "backend-sqlite"))]
+pub(crate) fn lower_like(left: &SimpleExpr, op: &BinOper, right: &SimpleExpr) -> SimpleExpr {
+    let op = match op {
+        BinOper::NotILike => BinOper::NotLike,
+        _ => BinOper::Like,
+    };
+    let lower = |expr: &SimpleExpr| SimpleExpr::FunctionCall(Func::lower(expr.clone()));
+    let right = match right {
+        // The escape character is not lowercased
+        SimpleExpr::Binary(pattern, BinOper::Escape, escape) => {
+            SimpleExpr::Binary(Box::new(lower(pattern)), BinOper::Escape, escape.clone())
+        }
+        _ => lower(right),
+    };
+    SimpleExpr::Binary(Box::new(lower(left)), op, Box::new(right))
+}
+
 #[cfg_attr(
     feature = "option-more-parentheses",
     allow(unreachable_code, unused_variables)
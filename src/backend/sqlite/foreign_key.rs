@@ -21,7 +21,7 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
         write!(sql, "DROP FOREIGN KEY ").unwrap();
         if let Some(name) = &drop.foreign_key.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -46,7 +46,7 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.prepare(sql.as_writer(), self.quote());
+            col.prepare(sql.as_iden_writer(), self.quote());
             false
         });
         write!(sql, ")").unwrap();
@@ -64,7 +64,7 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
                 if !first {
                     write!(sql, ", ").unwrap();
                 }
-                col.prepare(sql.as_writer(), self.quote());
+                col.prepare(sql.as_iden_writer(), self.quote());
                 false
             });
         write!(sql, ")").unwrap();
@@ -16,7 +16,7 @@ impl IndexBuilder for SqliteQueryBuilder {
 
         if let Some(name) = &create.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -32,9 +32,14 @@ impl IndexBuilder for SqliteQueryBuilder {
 
         write!(sql, " ").unwrap();
         self.prepare_index_columns(&create.index.columns, sql);
+        self.prepare_index_include(&create.include_columns, sql);
         self.prepare_filter(&create.r#where, sql);
     }
 
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        self.prepare_simple_expr(expr, sql);
+    }
+
     fn prepare_table_ref_index_stmt(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
         match table_ref {
             TableRef::Table(_) => self.prepare_table_ref_iden(table_ref, sql),
@@ -51,7 +56,7 @@ impl IndexBuilder for SqliteQueryBuilder {
 
         if let Some(name) = &drop.index.name {
             write!(
-                sql,
+                sql.as_iden_writer(),
                 "{}{}{}",
                 self.quote().left(),
                 name,
@@ -2,8 +2,11 @@ use super::*;
 use crate::extension::sqlite::SqliteBinOper;
 
 impl QueryBuilder for SqliteQueryBuilder {
-    fn prepare_select_lock(&self, _select_lock: &LockClause, _sql: &mut dyn SqlWriter) {
-        // SQLite doesn't supports row locking
+    fn prepare_select_lock(&self, select_lock: &LockClause, _sql: &mut dyn SqlWriter) {
+        // SQLite doesn't supports row locking, a plain lock is a no-op as writes lock the whole database
+        if !select_lock.tables.is_empty() || select_lock.behavior.is_some() {
+            panic!("Sqlite doesn't support row locking with tables or behavior");
+        }
     }
 
     fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut dyn SqlWriter) {
@@ -20,6 +23,16 @@ impl QueryBuilder for SqliteQueryBuilder {
         .unwrap();
     }
 
+    fn prepare_ilike(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.prepare_simple_expr(&lower_like(left, op, right), sql);
+    }
+
     fn prepare_bin_oper(&self, bin_oper: &BinOper, sql: &mut dyn SqlWriter) {
         match bin_oper {
             BinOper::SqliteOperator(bin_oper) => write!(
@@ -33,6 +46,10 @@ impl QueryBuilder for SqliteQueryBuilder {
                 }
             )
             .unwrap(),
+            BinOper::Overlaps => panic!("Sqlite doesn't support OVERLAPS"),
+            BinOper::SimilarTo | BinOper::NotSimilarTo => {
+                panic!("Sqlite doesn't support SIMILAR TO")
+            }
             _ => self.prepare_bin_oper_common(bin_oper, sql),
         }
     }
@@ -48,18 +65,136 @@ impl QueryBuilder for SqliteQueryBuilder {
             UnionType::Distinct => write!(sql, " UNION ").unwrap(),
             UnionType::Except => write!(sql, " EXCEPT ").unwrap(),
             UnionType::All => write!(sql, " UNION ALL ").unwrap(),
+            UnionType::IntersectAll => panic!("Sqlite doesn't support INTERSECT ALL"),
+            UnionType::ExceptAll => panic!("Sqlite doesn't support EXCEPT ALL"),
+        }
+        // Compound members can't be parenthesized, nor be ordered or limited on their own
+        let wrap = !select_statement.orders.is_empty()
+            || select_statement.limit.is_some()
+            || select_statement.offset.is_some()
+            || !select_statement.unions.is_empty();
+        if wrap {
+            write!(sql, "SELECT * FROM (").unwrap();
         }
         self.prepare_select_statement(select_statement, sql);
+        if wrap {
+            write!(sql, ")").unwrap();
+        }
     }
 
     fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter) {
         query.prepare_statement(self, sql);
     }
 
+    fn prepare_explain_query(&self, explain: &ExplainQuery, sql: &mut dyn SqlWriter) {
+        if explain.analyze {
+            panic!("Sqlite doesn't support EXPLAIN ANALYZE");
+        }
+        write!(sql, "EXPLAIN QUERY PLAN ").unwrap();
+        explain.query.prepare_statement(self, sql);
+    }
+
+    fn prepare_grouping_set(&self, _: &GroupingSet, _: &mut dyn SqlWriter) {
+        panic!("Sqlite doesn't support GROUPING SETS, ROLLUP and CUBE")
+    }
+
+    fn supports_window_functions(&self) -> bool {
+        // Since SQLite 3.25
+        true
+    }
+
+    fn supports_grouping_sets(&self) -> bool {
+        false
+    }
+
+    fn distinct_order_by_must_be_selected(&self) -> bool {
+        false
+    }
+
+    fn prepare_select_fetch(&self, _: &Value, _: bool, _: &mut dyn SqlWriter) {
+        panic!("Sqlite doesn't support FETCH FIRST, use LIMIT instead")
+    }
+
+    fn prepare_keyword(&self, keyword: &Keyword, sql: &mut dyn SqlWriter) {
+        match keyword {
+            // Sqlite's timestamps have a precision of seconds
+            Keyword::CurrentTimestampWithPrecision(_) => {
+                self.prepare_keyword_common(&Keyword::CurrentTimestamp, sql)
+            }
+            Keyword::Default => panic!("Sqlite doesn't support DEFAULT as a value"),
+            _ => self.prepare_keyword_common(keyword, sql),
+        }
+    }
+
+    fn prepare_values_list_table(
+        &self,
+        values: &[ValueTuple],
+        alias: &DynIden,
+        columns: &[DynIden],
+        sql: &mut dyn SqlWriter,
+    ) {
+        // Sqlite doesn't support column names in the alias, the columns are named `column1`, `column2` ...
+        write!(sql, "(").unwrap();
+        if !columns.is_empty() {
+            write!(sql, "SELECT ").unwrap();
+            columns.iter().enumerate().fold(true, |first, (i, column)| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                Alias::new(format!("column{}", i + 1)).prepare(sql.as_iden_writer(), self.quote());
+                write!(sql, " AS ").unwrap();
+                column.prepare(sql.as_iden_writer(), self.quote());
+                false
+            });
+            write!(sql, " FROM (").unwrap();
+        }
+        self.prepare_values_list(values, sql);
+        if !columns.is_empty() {
+            write!(sql, ")").unwrap();
+        }
+        write!(sql, ")").unwrap();
+        write!(sql, "{}", self.table_alias_keyword()).unwrap();
+        alias.prepare(sql.as_iden_writer(), self.quote());
+    }
+
+    fn prepare_merge_statement(&self, _: &MergeStatement, _: &mut dyn SqlWriter) {
+        panic!("Sqlite doesn't support MERGE statement")
+    }
+
+    fn prepare_delete_using(&self, delete: &DeleteStatement, _: &mut dyn SqlWriter) {
+        if !delete.using.is_empty() {
+            panic!("Sqlite doesn't support DELETE ... USING, use a sub query in WHERE instead")
+        }
+    }
+
+    fn prepare_cast_type(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        TableBuilder::prepare_column_type(self, column_type, sql);
+    }
+
+    fn prepare_function_arguments(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
+        if matches!(func.func, Function::StringAgg)
+            && func.args.len() > 1
+            && (func.distinct || func.mods.iter().any(|mod_| mod_.distinct))
+        {
+            panic!("Sqlite doesn't support DISTINCT in GROUP_CONCAT with a separator");
+        }
+        self.prepare_function_arguments_common(func, sql)
+    }
+
     fn prepare_with_clause_recursive_options(&self, _: &WithClause, _: &mut dyn SqlWriter) {
         // Sqlite doesn't support sql recursive with query 'SEARCH' and 'CYCLE' options.
     }
 
+    fn prepare_with_query_clause_search_cycle(
+        &self,
+        cte: &CommonTableExpression,
+        _: &mut dyn SqlWriter,
+    ) {
+        if cte.search.is_some() || cte.cycle.is_some() {
+            panic!("SEARCH and CYCLE clauses of a common table expression are only supported by Postgres");
+        }
+    }
+
     fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut dyn SqlWriter) {
         if !matches!(order_expr.order, Order::Field(_)) {
             self.prepare_simple_expr(&order_expr.expr, sql);
@@ -76,6 +211,73 @@ impl QueryBuilder for SqliteQueryBuilder {
         sql.push_param(value.clone(), self as _);
     }
 
+    fn prepare_hash(&self, _: &SimpleExpr, _: &mut dyn SqlWriter) {
+        panic!("Sqlite doesn't have a built-in hash function")
+    }
+
+    fn prepare_format_date(&self, expr: &SimpleExpr, format: &str, sql: &mut dyn SqlWriter) {
+        write!(sql, "STRFTIME(").unwrap();
+        self.prepare_value(&self.translate_date_format(format).into(), sql);
+        write!(sql, ", ").unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn date_format_specifier(&self, spec: char) -> &str {
+        match spec {
+            'Y' => "%Y",
+            'm' => "%m",
+            'd' => "%d",
+            'H' => "%H",
+            'M' => "%M",
+            'S' => "%S",
+            _ => panic!("Unsupported date format specifier %{spec}"),
+        }
+    }
+
+    fn date_format_literal(&self, literal: &str) -> String {
+        literal.replace('%', "%%")
+    }
+
+    fn prepare_extract(&self, part: &DatePart, expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        write!(sql, "CAST(STRFTIME(").unwrap();
+        // The format is a string literal, `%M` and `%m` differ
+        write!(sql.as_writer(), "'{}', ", self.date_part(part)).unwrap();
+        self.prepare_simple_expr(expr, sql);
+        write!(sql, ") AS INTEGER)").unwrap();
+    }
+
+    fn prepare_timestamp_diff(
+        &self,
+        start: &SimpleExpr,
+        end: &SimpleExpr,
+        sql: &mut dyn SqlWriter,
+    ) {
+        write!(sql, "(STRFTIME(").unwrap();
+        write!(sql.as_writer(), "'%s', ").unwrap();
+        self.prepare_simple_expr(end, sql);
+        write!(sql, ") - STRFTIME(").unwrap();
+        write!(sql.as_writer(), "'%s', ").unwrap();
+        self.prepare_simple_expr(start, sql);
+        write!(sql, "))").unwrap();
+    }
+
+    fn date_part(&self, part: &DatePart) -> &str {
+        match part {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+            DatePart::Hour => "%H",
+            DatePart::Minute => "%M",
+            DatePart::Second => "%S",
+            DatePart::Epoch => "%s",
+        }
+    }
+
+    fn row_id_column(&self) -> &str {
+        "rowid"
+    }
+
     fn greatest_function(&self) -> &str {
         "MAX"
     }
@@ -88,6 +290,10 @@ impl QueryBuilder for SqliteQueryBuilder {
         "LENGTH"
     }
 
+    fn string_agg_function(&self) -> &str {
+        "GROUP_CONCAT"
+    }
+
     fn insert_default_values(&self, _: u32, sql: &mut dyn SqlWriter) {
         // SQLite doesn't support inserting multiple rows with default values
         write!(sql, "DEFAULT VALUES").unwrap()
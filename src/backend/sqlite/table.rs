@@ -2,7 +2,7 @@ use super::*;
 
 impl TableBuilder for SqliteQueryBuilder {
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut dyn SqlWriter) {
-        column_def.name.prepare(sql.as_writer(), self.quote());
+        column_def.name.prepare(sql.as_iden_writer(), self.quote());
 
         if let Some(column_type) = &column_def.types {
             write!(sql, " ").unwrap();
@@ -42,6 +42,17 @@ impl TableBuilder for SqliteQueryBuilder {
         self.prepare_column_type(&[], column_type, sql)
     }
 
+    fn prepare_column_default(&self, value: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        match value {
+            // Boolean columns are integers, and `TRUE` / `FALSE` keywords need SQLite 3.23
+            SimpleExpr::Value(Value::Bool(Some(b)))
+            | SimpleExpr::Constant(Value::Bool(Some(b))) => {
+                write!(sql, "{}", i32::from(*b)).unwrap()
+            }
+            _ => QueryBuilder::prepare_simple_expr(self, value, sql),
+        }
+    }
+
     fn column_spec_auto_increment_keyword(&self) -> &str {
         "AUTOINCREMENT"
     }
@@ -83,13 +94,18 @@ impl TableBuilder for SqliteQueryBuilder {
             }
             TableAlterOption::RenameColumn(from_name, to_name) => {
                 write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql.as_writer(), self.quote());
+                from_name.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, " TO ").unwrap();
-                to_name.prepare(sql.as_writer(), self.quote());
+                to_name.prepare(sql.as_iden_writer(), self.quote());
             }
             TableAlterOption::DropColumn(col_name) => {
                 write!(sql, "DROP COLUMN ").unwrap();
-                col_name.prepare(sql.as_writer(), self.quote());
+                col_name.prepare(sql.as_iden_writer(), self.quote());
+            }
+            TableAlterOption::SetDefault(..) | TableAlterOption::DropDefault(_) => {
+                panic!(
+                    "Sqlite doesn't support altering column defaults; the table has to be rebuilt"
+                )
             }
             TableAlterOption::DropForeignKey(_) => {
                 panic!("Sqlite does not support modification of foreign key constraints to existing tables");
@@ -126,6 +142,9 @@ impl SqliteQueryBuilder {
         let is_auto_increment = column_specs
             .iter()
             .any(|s| matches!(s, ColumnSpec::AutoIncrement));
+        if let ColumnType::Custom(iden) = column_type {
+            return iden.unquoted(sql.as_writer());
+        }
         write!(
             sql,
             "{}",
@@ -161,7 +180,7 @@ impl SqliteQueryBuilder {
                     None => "real".into(),
                 },
                 ColumnType::DateTime => "datetime_text".into(),
-                ColumnType::Timestamp => "timestamp_text".into(),
+                ColumnType::Timestamp(_) => "timestamp_text".into(),
                 ColumnType::TimestampWithTimeZone => "timestamp_with_timezone_text".into(),
                 ColumnType::Time => "time_text".into(),
                 ColumnType::Date => "date_text".into(),
@@ -181,7 +200,7 @@ impl SqliteQueryBuilder {
                 ColumnType::Json => "json_text".into(),
                 ColumnType::JsonBinary => "jsonb_text".into(),
                 ColumnType::Uuid => "uuid_text".into(),
-                ColumnType::Custom(iden) => iden.to_string(),
+                ColumnType::Custom(_) => unreachable!(),
                 ColumnType::Enum { .. } => "enum_text".into(),
                 ColumnType::Array(_) => unimplemented!("Array is not available in Sqlite."),
                 ColumnType::Vector(_) => unimplemented!("Vector is not available in Sqlite."),
@@ -52,12 +52,20 @@ pub trait TableBuilder:
             first = false;
         });
 
+        create.exclude.iter().for_each(|exclude| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_exclude_constraint(exclude, sql);
+            first = false;
+        });
+
         write!(sql, " )").unwrap();
 
         self.prepare_table_opt(create, sql);
 
         if let Some(extra) = &create.extra {
-            write!(sql, " {extra}").unwrap();
+            write!(sql.as_writer(), " {extra}").unwrap();
         }
     }
 
@@ -94,7 +102,7 @@ pub trait TableBuilder:
             ColumnSpec::NotNull => write!(sql, "NOT NULL").unwrap(),
             ColumnSpec::Default(value) => {
                 write!(sql, "DEFAULT ").unwrap();
-                QueryBuilder::prepare_simple_expr(self, value, sql);
+                self.prepare_column_default(value, sql);
             }
             ColumnSpec::AutoIncrement => {
                 write!(sql, "{}", self.column_spec_auto_increment_keyword()).unwrap()
@@ -105,11 +113,16 @@ pub trait TableBuilder:
             ColumnSpec::Generated { expr, stored } => {
                 self.prepare_generated_column(expr, *stored, sql)
             }
-            ColumnSpec::Extra(string) => write!(sql, "{string}").unwrap(),
+            ColumnSpec::Extra(string) => write!(sql.as_writer(), "{string}").unwrap(),
             ColumnSpec::Comment(comment) => self.column_comment(comment, sql),
         }
     }
 
+    /// Translate the default value of a column into SQL statement.
+    fn prepare_column_default(&self, value: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        QueryBuilder::prepare_simple_expr(self, value, sql);
+    }
+
     /// column comment
     fn column_comment(&self, _comment: &str, _sql: &mut dyn SqlWriter) {}
 
@@ -125,16 +138,21 @@ pub trait TableBuilder:
     fn prepare_table_opt_def(&self, create: &TableCreateStatement, sql: &mut dyn SqlWriter) {
         for table_opt in create.options.iter() {
             write!(sql, " ").unwrap();
-            write!(
-                sql,
-                "{}",
-                match table_opt {
-                    TableOpt::Engine(s) => format!("ENGINE={s}"),
-                    TableOpt::Collate(s) => format!("COLLATE={s}"),
-                    TableOpt::CharacterSet(s) => format!("DEFAULT CHARSET={s}"),
+            let s = match table_opt {
+                TableOpt::Engine(s) => {
+                    write!(sql, "ENGINE=").unwrap();
+                    s
+                }
+                TableOpt::Collate(s) => {
+                    write!(sql, "COLLATE=").unwrap();
+                    s
                 }
-            )
-            .unwrap()
+                TableOpt::CharacterSet(s) => {
+                    write!(sql, "DEFAULT CHARSET=").unwrap();
+                    s
+                }
+            };
+            write!(sql.as_writer(), "{s}").unwrap()
         }
     }
 
@@ -196,6 +214,11 @@ pub trait TableBuilder:
         write!(sql, ")").unwrap();
     }
 
+    /// Translate [`ExcludeConstraint`] into SQL statement.
+    fn prepare_exclude_constraint(&self, _exclude: &ExcludeConstraint, _sql: &mut dyn SqlWriter) {
+        panic!("Exclusion constraint is only supported by Postgres")
+    }
+
     /// Translate the generated column into SQL statement
     fn prepare_generated_column(&self, gen: &SimpleExpr, stored: bool, sql: &mut dyn SqlWriter) {
         write!(sql, "GENERATED ALWAYS AS (").unwrap();
@@ -1,50 +1,66 @@
 use crate::*;
 
 pub trait TableRefBuilder: QuotedBuilder {
+    #[doc(hidden)]
+    /// The keyword written between a table and its alias.
+    /// With `option-omit-table-alias-as`, the `AS` is omitted (e.g. `FROM t x`).
+    fn table_alias_keyword(&self) -> &str {
+        if cfg!(feature = "option-omit-table-alias-as") {
+            " "
+        } else {
+            " AS "
+        }
+    }
+
     /// Translate [`TableRef`] that without values into SQL statement.
     fn prepare_table_ref_iden(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
         match table_ref {
             TableRef::Table(iden) => {
-                iden.prepare(sql.as_writer(), self.quote());
+                iden.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::SchemaTable(schema, table) => {
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                table.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::DatabaseSchemaTable(database, schema, table) => {
-                database.prepare(sql.as_writer(), self.quote());
+                database.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                table.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::TableAlias(iden, alias) => {
-                iden.prepare(sql.as_writer(), self.quote());
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                iden.prepare(sql.as_iden_writer(), self.quote());
+                write!(sql, "{}", self.table_alias_keyword()).unwrap();
+                alias.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::SchemaTableAlias(schema, table, alias) => {
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                table.prepare(sql.as_writer(), self.quote());
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
+                write!(sql, "{}", self.table_alias_keyword()).unwrap();
+                alias.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::DatabaseSchemaTableAlias(database, schema, table, alias) => {
-                database.prepare(sql.as_writer(), self.quote());
+                database.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                table.prepare(sql.as_writer(), self.quote());
-                write!(sql, " AS ").unwrap();
-                alias.prepare(sql.as_writer(), self.quote());
+                table.prepare(sql.as_iden_writer(), self.quote());
+                write!(sql, "{}", self.table_alias_keyword()).unwrap();
+                alias.prepare(sql.as_iden_writer(), self.quote());
             }
             TableRef::SubQuery(_, _)
             | TableRef::ValuesList(_, _)
+            | TableRef::ValuesListColumns(_, _, _)
             | TableRef::FunctionCall(_, _) => {
                 panic!("TableRef with values is not support")
             }
+            #[cfg(feature = "backend-postgres")]
+            TableRef::Only(_) | TableRef::TableSample(_, _) => {
+                panic!("TableRef with values is not support")
+            }
         }
     }
 }
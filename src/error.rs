@@ -7,6 +7,15 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     /// Column and value vector having different length
     ColValNumMismatch { col_len: usize, val_len: usize },
+    /// `RETURNING` clause is not supported by the backend, e.g. MySQL.
+    /// Use `last_insert_id` of the database driver to retrieve the auto increment primary key instead.
+    ReturningUnsupported,
+    /// The `ORDER BY` clause does not start with the `DISTINCT ON` columns, which Postgres rejects.
+    DistinctOnOrderMismatch,
+    /// An `ORDER BY` expression of a `SELECT DISTINCT` is not in the select list, which Postgres and MySQL reject.
+    DistinctOrderNotSelected,
+    /// `FETCH FIRST ... WITH TIES` is used without an `ORDER BY` clause to define the ties.
+    FetchWithTiesWithoutOrder,
 }
 
 impl std::error::Error for Error {}
@@ -18,6 +27,20 @@ impl std::fmt::Display for Error {
                 f,
                 "Columns and values length mismatch: {col_len} != {val_len}"
             ),
+            Self::ReturningUnsupported => write!(
+                f,
+                "RETURNING is not supported by the backend; use last_insert_id instead"
+            ),
+            Self::DistinctOnOrderMismatch => {
+                write!(f, "ORDER BY must start with the DISTINCT ON columns")
+            }
+            Self::DistinctOrderNotSelected => write!(
+                f,
+                "ORDER BY expressions of SELECT DISTINCT must appear in the select list"
+            ),
+            Self::FetchWithTiesWithoutOrder => {
+                write!(f, "FETCH FIRST ... WITH TIES requires an ORDER BY clause")
+            }
         }
     }
 }
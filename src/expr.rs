@@ -4,7 +4,7 @@
 //!
 //! [`SimpleExpr`] is the expression common among select fields, where clauses and many other places.
 
-use crate::{func::*, query::*, types::*, value::*};
+use crate::{func::*, query::*, types::*, value::*, ColumnType};
 
 /// Helper to build a [`SimpleExpr`].
 #[derive(Debug, Clone)]
@@ -193,6 +193,37 @@ pub trait ExprTrait: Sized {
         O: Into<BinOper>,
         R: Into<SimpleExpr>;
 
+    /// Replace a blank string, empty or made of spaces, and NULL by a default value,
+    /// as in `COALESCE(NULLIF(TRIM(x), ''), default)`.
+    /// Other strings are trimmed, see [`Func::trim`], [`Func::nullif`] and [`Func::coalesce`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Font::Language).blank_to_default("en"))
+    ///     .from(Font::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COALESCE(NULLIF(TRIM(`language`), ''), 'en') FROM `font`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COALESCE(NULLIF(TRIM("language"), ''), 'en') FROM "font""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COALESCE(NULLIF(TRIM("language"), ''), 'en') FROM "font""#
+    /// );
+    /// ```
+    fn blank_to_default<D>(self, default: D) -> SimpleExpr
+    where
+        D: Into<SimpleExpr>;
+
     /// Express a `CAST AS` expression.
     ///
     /// # Examples
@@ -221,6 +252,61 @@ pub trait ExprTrait: Sized {
     where
         N: IntoIden;
 
+    /// Express a `CAST AS` expression with a [`ColumnType`], see [`Func::cast_as_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr("1".cast_as_type(ColumnType::Double))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST('1' AS double)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CAST('1' AS double precision)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST('1' AS double)"#
+    /// );
+    /// ```
+    fn cast_as_type(self, column_type: ColumnType) -> SimpleExpr;
+
+    /// Express a cast with the Postgres `::type` shorthand.
+    /// Other backends fall back to `CAST AS`, the same as [`ExprTrait::cast_as_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Char::SizeW).pg_cast(ColumnType::Text))
+    ///     .expr(Expr::col(Char::SizeW).add(Expr::col(Char::SizeH)).pg_cast(ColumnType::Double))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST(`size_w` AS char), CAST(`size_w` + `size_h` AS double) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "size_w"::text, ("size_w" + "size_h")::double precision FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST("size_w" AS text), CAST("size_w" + "size_h" AS double) FROM "character""#
+    /// );
+    /// ```
+    fn pg_cast(self, column_type: ColumnType) -> SimpleExpr;
+
     /// Express an arithmetic division operation.
     ///
     /// # Examples
@@ -835,6 +921,95 @@ pub trait ExprTrait: Sized {
         ExprTrait::binary(self, BinOper::Like, like.into_like_expr())
     }
 
+    /// Express a case-insensitive `LIKE` expression.
+    ///
+    /// Postgres renders `ILIKE`. MySQL and SQLite have no `ILIKE`, so both sides are lowercased
+    /// and matched with `LIKE` instead. SQLite's `LOWER` folds ASCII characters only,
+    /// unless SQLite is built with the ICU extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).ilike(LikeExpr::new(r"100|%%").escape('|')))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE LOWER(`character`) LIKE LOWER('100|%%') ESCAPE '|'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "character" ILIKE '100|%%' ESCAPE '|'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE LOWER("character") LIKE LOWER('100|%%') ESCAPE '|'"#
+    /// );
+    /// ```
+    fn ilike<L>(self, like: L) -> SimpleExpr
+    where
+        L: IntoLikeExpr,
+    {
+        ExprTrait::binary(self, BinOper::ILike, like.into_like_expr())
+    }
+
+    /// Express a `SIMILAR TO` expression, matching a SQL regular expression.
+    ///
+    /// Only supported by Postgres, MySQL and SQLite panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).similar_to("%(b|d)%"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "character" SIMILAR TO '%(b|d)%'"#
+    /// );
+    /// ```
+    fn similar_to<R>(self, pattern: R) -> SimpleExpr
+    where
+        R: Into<SimpleExpr>,
+    {
+        ExprTrait::binary(self, BinOper::SimilarTo, pattern)
+    }
+
+    /// Express a `NOT SIMILAR TO` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).not_similar_to("%(b|d)%"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "character" NOT SIMILAR TO '%(b|d)%'"#
+    /// );
+    /// ```
+    fn not_similar_to<R>(self, pattern: R) -> SimpleExpr
+    where
+        R: Into<SimpleExpr>,
+    {
+        ExprTrait::binary(self, BinOper::NotSimilarTo, pattern)
+    }
+
     /// Express a less than (`<`) expression.
     ///
     /// # Examples
@@ -1173,6 +1348,14 @@ pub trait ExprTrait: Sized {
         ExprTrait::binary(self, BinOper::NotLike, like.into_like_expr())
     }
 
+    /// Express a case-insensitive `NOT LIKE` expression, see [`ExprTrait::ilike`].
+    fn not_ilike<L>(self, like: L) -> SimpleExpr
+    where
+        L: IntoLikeExpr,
+    {
+        ExprTrait::binary(self, BinOper::NotILike, like.into_like_expr())
+    }
+
     /// Express a logical `OR` operation.
     ///
     /// # Examples
@@ -1395,6 +1578,13 @@ where
         SimpleExpr::Binary(Box::new(self.into()), op.into(), Box::new(right.into()))
     }
 
+    fn blank_to_default<D>(self, default: D) -> SimpleExpr
+    where
+        D: Into<SimpleExpr>,
+    {
+        Func::coalesce([Func::nullif(Func::trim(self), "").into(), default.into()]).into()
+    }
+
     fn cast_as<N>(self, type_name: N) -> SimpleExpr
     where
         N: IntoIden,
@@ -1402,6 +1592,14 @@ where
         SimpleExpr::FunctionCall(Func::cast_as(self, type_name))
     }
 
+    fn cast_as_type(self, column_type: ColumnType) -> SimpleExpr {
+        SimpleExpr::FunctionCall(Func::cast_as_type(self, column_type))
+    }
+
+    fn pg_cast(self, column_type: ColumnType) -> SimpleExpr {
+        SimpleExpr::FunctionCall(FunctionCall::new(Function::PgCastAsType(column_type)).arg(self))
+    }
+
     fn unary(self, op: UnOper) -> SimpleExpr {
         SimpleExpr::Unary(op, Box::new(self.into()))
     }
@@ -1519,6 +1717,45 @@ impl Expr {
         ))
     }
 
+    /// Express a `(start1, end1) OVERLAPS (start2, end2)` expression.
+    ///
+    /// Only Postgres supports `OVERLAPS`; building it for MySQL or SQLite panics.
+    /// On those backends, compare the bounds instead:
+    /// `start1 < end2 AND start2 < end1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::overlaps(
+    ///         (Expr::col(Char::CreatedAt), Expr::val("2020-02-01")),
+    ///         (Expr::val("2020-01-01"), Expr::val("2020-03-01")),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE ("created_at", '2020-02-01') OVERLAPS ('2020-01-01', '2020-03-01')"#
+    /// );
+    /// ```
+    pub fn overlaps<A, B, C, D>(left: (A, B), right: (C, D)) -> SimpleExpr
+    where
+        A: Into<SimpleExpr>,
+        B: Into<SimpleExpr>,
+        C: Into<SimpleExpr>,
+        D: Into<SimpleExpr>,
+    {
+        SimpleExpr::Binary(
+            Box::new(SimpleExpr::Tuple(vec![left.0.into(), left.1.into()])),
+            BinOper::Overlaps,
+            Box::new(SimpleExpr::Tuple(vec![right.0.into(), right.1.into()])),
+        )
+    }
+
     #[deprecated(since = "0.29.0", note = "Please use the [`Asterisk`]")]
     pub fn table_asterisk<T>(t: T) -> Self
     where
@@ -1562,6 +1799,43 @@ impl Expr {
         Self::new_with_left(v)
     }
 
+    /// Express a [`Value`] cast to a type, for when Postgres can't infer the type of a parameter.
+    /// The value is still bound as a parameter, cast with `$1::type` on Postgres and `CAST(? AS type)` elsewhere.
+    /// See [`ExprTrait::pg_cast`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::CreatedAt).gt(Expr::val_as("2020-01-01", ColumnType::Date)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "character" WHERE "created_at" > $1::date"#.to_owned(),
+    ///         Values(vec!["2020-01-01".into()])
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"SELECT `id` FROM `character` WHERE `created_at` > CAST(? AS date)"#.to_owned(),
+    ///         Values(vec!["2020-01-01".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn val_as<V>(v: V, column_type: ColumnType) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        ExprTrait::pg_cast(Self::val(v), column_type)
+    }
+
     /// Wrap a [`SimpleExpr`] and perform some operation on it.
     ///
     /// # Examples
@@ -1814,6 +2088,27 @@ impl Expr {
     }
 
     /// Express any custom expression with [`SimpleExpr`]. Use this if your expression needs other expressions.
+    ///
+    /// Each placeholder is replaced by the rendered expression, so columns can be mixed with bound values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::cust_with_exprs(
+    ///         "COALESCE($1, $2) + $2",
+    ///         [Expr::col(Char::FontSize).into(), Expr::val(12).into()],
+    ///     ))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COALESCE("font_size", 12) + 12 FROM "character""#
+    /// );
+    /// ```
     pub fn cust_with_exprs<T, I>(s: T, v: I) -> SimpleExpr
     where
         T: Into<String>,
@@ -2492,6 +2787,20 @@ impl Expr {
         ExprTrait::not_like(self, like)
     }
 
+    /// Express a case-insensitive `ILIKE` expression.
+    ///
+    /// This is equivalent to a newer [ExprTrait::ilike] and may require more some wrapping beforehand.
+    pub fn ilike<L: IntoLikeExpr>(self, like: L) -> SimpleExpr {
+        ExprTrait::ilike(self, like)
+    }
+
+    /// Express a case-insensitive `NOT ILIKE` expression.
+    ///
+    /// This is equivalent to a newer [ExprTrait::not_ilike] and may require more some wrapping beforehand.
+    pub fn not_ilike<L: IntoLikeExpr>(self, like: L) -> SimpleExpr {
+        ExprTrait::not_ilike(self, like)
+    }
+
     /// Express a `IS NULL` expression.
     ///
     /// This is equivalent to a newer [ExprTrait::is_null] and may require more some wrapping beforehand.
@@ -3114,6 +3423,52 @@ impl Expr {
         ExprTrait::not_in_subquery(self, sel)
     }
 
+    /// Express a scalar sub-query, i.e. a sub-query returning a single value, in parentheses.
+    ///
+    /// # Examples
+    ///
+    /// The size of the latest character of each font, or 0 if it has none:
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let latest_size = Query::select()
+    ///     .column(Char::FontSize)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+    ///     .and_where(Expr::col(Char::SizeW).gt(10))
+    ///     .order_by(Char::CreatedAt, Order::Desc)
+    ///     .limit(1)
+    ///     .to_owned();
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .expr_as(
+    ///         Func::coalesce([Expr::subquery(latest_size), Expr::val(0).into()]),
+    ///         Alias::new("latest_size"),
+    ///     )
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Font::Language).eq("en"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         [
+    ///             r#"SELECT "id", COALESCE((SELECT "font_size" FROM "character""#,
+    ///             r#"WHERE "character"."font_id" = "font"."id" AND "size_w" > $1"#,
+    ///             r#"ORDER BY "created_at" DESC LIMIT $2), $3) AS "latest_size""#,
+    ///             r#"FROM "font" WHERE "language" = $4"#,
+    ///         ]
+    ///         .join(" "),
+    ///         Values(vec![10.into(), 1u64.into(), 0.into(), "en".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn subquery(sel: SelectStatement) -> SimpleExpr {
+        SimpleExpr::SubQuery(None, Box::new(sel.into_sub_query_statement()))
+    }
+
     /// Express a `EXISTS` sub-query expression.
     ///
     /// # Examples
@@ -3334,6 +3689,49 @@ impl Expr {
         ExprTrait::cast_as(self, type_name)
     }
 
+    /// Express a `CAST AS` expression with a [`ColumnType`].
+    ///
+    /// A bound `NULL` keeps being a parameter, but Postgres can now infer its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .value(
+    ///         Char::Character,
+    ///         Expr::val(Value::String(None)).cast_as_type(ColumnType::Text),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"UPDATE "character" SET "character" = CAST($1 AS text)"#.to_owned(),
+    ///         Values(vec![Value::String(None)])
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"UPDATE `character` SET `character` = CAST(? AS char)"#.to_owned(),
+    ///         Values(vec![Value::String(None)])
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     query.build(SqliteQueryBuilder),
+    ///     (
+    ///         r#"UPDATE "character" SET "character" = CAST(? AS text)"#.to_owned(),
+    ///         Values(vec![Value::String(None)])
+    ///     )
+    /// );
+    /// ```
+    pub fn cast_as_type(self, column_type: ColumnType) -> SimpleExpr {
+        ExprTrait::cast_as_type(self, column_type)
+    }
+
     /// Keyword `CURRENT_DATE`.
     ///
     /// # Examples
@@ -3406,6 +3804,116 @@ impl Expr {
         Expr::new_with_left(Keyword::CurrentTimestamp)
     }
 
+    /// Keyword `CURRENT_TIMESTAMP` with the precision of fractional seconds, e.g. 6 for microseconds.
+    /// SQLite renders `CURRENT_TIMESTAMP`, which has a precision of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{Expr, MysqlQueryBuilder, PostgresQueryBuilder, Query, SqliteQueryBuilder};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::current_timestamp_with_precision(6))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP(6)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP(6)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP"#
+    /// );
+    /// ```
+    pub fn current_timestamp_with_precision(precision: u32) -> Expr {
+        Expr::new_with_left(Keyword::CurrentTimestampWithPrecision(precision))
+    }
+
+    /// Physical row id of the current row, `ctid` on Postgres and `rowid` on SQLite.
+    /// MySQL has no physical row id, and panics.
+    ///
+    /// The row id tells duplicate rows apart, but it may change once the row is updated or the table vacuumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::row_id())
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ctid, "character" FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT rowid, "character" FROM "character""#
+    /// );
+    /// ```
+    ///
+    /// Delete all but one row of each group of duplicates:
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Char::Table)
+    ///     .and_where(
+    ///         Expr::row_id().not_in_subquery(
+    ///             Query::select()
+    ///                 .expr(Func::min(Expr::row_id()))
+    ///                 .from(Char::Table)
+    ///                 .group_by_col(Char::Character)
+    ///                 .to_owned(),
+    ///         ),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM "character" WHERE rowid NOT IN (SELECT MIN(rowid) FROM "character" GROUP BY "character")"#
+    /// );
+    /// ```
+    pub fn row_id() -> Expr {
+        Expr::new_with_left(Keyword::RowId(None))
+    }
+
+    /// Physical row id of the current row of a table, variation of [`Expr::row_id`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::table_row_id(Char::Table))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character".ctid FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "character".rowid FROM "character""#
+    /// );
+    /// ```
+    pub fn table_row_id<T>(table: T) -> Expr
+    where
+        T: IntoIden,
+    {
+        Expr::new_with_left(Keyword::RowId(Some(table.into_iden())))
+    }
+
     /// Custom keyword.
     ///
     /// # Examples
@@ -3948,6 +4456,20 @@ impl SimpleExpr {
         ExprTrait::not_like(self, like)
     }
 
+    /// Express a case-insensitive `ILIKE` expression.
+    ///
+    /// This is equivalent to a newer [ExprTrait::ilike] and may require more some wrapping beforehand.
+    pub fn ilike<L: IntoLikeExpr>(self, like: L) -> Self {
+        ExprTrait::ilike(self, like)
+    }
+
+    /// Express a case-insensitive `NOT ILIKE` expression.
+    ///
+    /// This is equivalent to a newer [ExprTrait::not_ilike] and may require more some wrapping beforehand.
+    pub fn not_ilike<L: IntoLikeExpr>(self, like: L) -> Self {
+        ExprTrait::not_ilike(self, like)
+    }
+
     pub(crate) fn is_binary(&self) -> bool {
         matches!(self, Self::Binary(_, _, _))
     }
@@ -3958,4 +4480,76 @@ impl SimpleExpr {
             _ => None,
         }
     }
+
+    #[cfg(feature = "backend-postgres")]
+    /// Visit the column references that [`SimpleExpr::replace_columns`] would see
+    pub(crate) fn visit_columns(&self, f: &mut dyn FnMut(&ColumnRef)) {
+        match self {
+            Self::Column(col) => f(col),
+            Self::Tuple(exprs) | Self::CustomWithExpr(_, exprs) => {
+                exprs.iter().for_each(|e| e.visit_columns(f))
+            }
+            Self::Unary(_, expr) | Self::AsEnum(_, expr) => expr.visit_columns(f),
+            Self::FunctionCall(func) => func.args.iter().for_each(|e| e.visit_columns(f)),
+            Self::Binary(left, _, right) => {
+                left.visit_columns(f);
+                right.visit_columns(f);
+            }
+            Self::Case(case) => {
+                for w in &case.when {
+                    w.condition.visit_columns(f);
+                    w.result.visit_columns(f);
+                }
+                if let Some(e) = &case.r#else {
+                    e.visit_columns(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cfg(feature = "backend-postgres")]
+    /// Replace the column references for which `f` returns an expression
+    pub(crate) fn replace_columns(
+        &self,
+        f: &mut dyn FnMut(&ColumnRef) -> Option<SimpleExpr>,
+    ) -> SimpleExpr {
+        match self {
+            Self::Column(col) => f(col).unwrap_or_else(|| self.clone()),
+            Self::Tuple(exprs) => Self::Tuple(exprs.iter().map(|e| e.replace_columns(f)).collect()),
+            Self::Unary(oper, expr) => Self::Unary(*oper, Box::new(expr.replace_columns(f))),
+            Self::FunctionCall(func) => Self::FunctionCall(FunctionCall {
+                func: func.func.clone(),
+                args: func.args.iter().map(|e| e.replace_columns(f)).collect(),
+                mods: func.mods.clone(),
+                distinct: func.distinct,
+                filter: func.filter.clone(),
+                order_by: func.order_by.clone(),
+            }),
+            Self::Binary(left, oper, right) => Self::Binary(
+                Box::new(left.replace_columns(f)),
+                *oper,
+                Box::new(right.replace_columns(f)),
+            ),
+            Self::CustomWithExpr(s, exprs) => Self::CustomWithExpr(
+                s.clone(),
+                exprs.iter().map(|e| e.replace_columns(f)).collect(),
+            ),
+            Self::AsEnum(iden, expr) => {
+                Self::AsEnum(iden.clone(), Box::new(expr.replace_columns(f)))
+            }
+            Self::Case(case) => Self::Case(Box::new(CaseStatement {
+                when: case
+                    .when
+                    .iter()
+                    .map(|w| CaseStatementCondition {
+                        condition: w.condition.replace_columns(f),
+                        result: w.result.replace_columns(f),
+                    })
+                    .collect(),
+                r#else: case.r#else.as_ref().map(|e| e.replace_columns(f)),
+            })),
+            _ => self.clone(),
+        }
+    }
 }
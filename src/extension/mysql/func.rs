@@ -0,0 +1,73 @@
+//! For calling built-in MySQL SQL functions.
+
+use crate::{expr::*, func::*, types::*, value::*};
+
+/// Functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum MySqlFunction {
+    MatchAgainst(MatchAgainstMode),
+}
+
+/// Search modifier of a full-text search, see [`MySqlFunc::match_against`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchAgainstMode {
+    /// `IN NATURAL LANGUAGE MODE`
+    NaturalLanguage,
+    /// `IN BOOLEAN MODE`, the search string can contain operators like `+` and `-`
+    Boolean,
+    /// `WITH QUERY EXPANSION`, i.e. natural language search repeated with the most relevant rows
+    WithQueryExpansion,
+}
+
+/// Function call helper.
+#[derive(Debug, Clone)]
+pub struct MySqlFunc;
+
+impl MySqlFunc {
+    /// Call `MATCH (...) AGAINST (...)`, full-text search over columns having a `FULLTEXT` index.
+    /// The search string is bound as a parameter.
+    ///
+    /// In the WHERE clause it filters the matching rows,
+    /// and in the select list it returns the relevance of the row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::mysql::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .and_where(
+    ///         MySqlFunc::match_against(
+    ///             [Font::Name, Font::Variant],
+    ///             "+sans -mono",
+    ///             MatchAgainstMode::Boolean,
+    ///         )
+    ///         .into(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"SELECT `id` FROM `font` WHERE MATCH (`name`, `variant`) AGAINST (? IN BOOLEAN MODE)"#
+    ///             .to_owned(),
+    ///         Values(vec!["+sans -mono".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn match_against<C, I, T>(cols: I, search: T, mode: MatchAgainstMode) -> FunctionCall
+    where
+        C: IntoColumnRef,
+        I: IntoIterator<Item = C>,
+        T: Into<Value>,
+    {
+        FunctionCall::new(Function::MySqlFunction(MySqlFunction::MatchAgainst(mode)))
+            .args(
+                cols.into_iter()
+                    .map(|c| SimpleExpr::Column(c.into_column_ref())),
+            )
+            .arg(SimpleExpr::Value(search.into()))
+    }
+}
@@ -1,7 +1,9 @@
 mod column;
+mod func;
 mod index;
 mod select;
 
 pub use column::*;
+pub use func::*;
 pub use index::*;
 pub use select::*;
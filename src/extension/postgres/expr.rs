@@ -1,4 +1,4 @@
-use super::PgBinOper;
+use super::{PgBinOper, PgFunc};
 use crate::{
     ColumnRef, Expr, ExprTrait, FunctionCall, IntoLikeExpr, Keyword, LikeExpr, SimpleExpr, Value,
 };
@@ -62,6 +62,41 @@ pub trait PgExpr: ExprTrait {
         self.binary(PgBinOper::Matches, expr)
     }
 
+    /// Express a postgres JSON path predicate check (`@@`) of a `jsonb` expression.
+    ///
+    /// Unlike [`PgExpr::matches`], which is for full-text search, the right hand side is a `jsonpath`.
+    /// A path given as a value is cast to `jsonpath`, as in [`PgFunc::jsonb_path_exists`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::PgExpr, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).json_matches("$.active == true"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "image" @@ '$.active == true'::jsonpath"#
+    /// );
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "glyph" WHERE "image" @@ $1::jsonpath"#.to_owned(),
+    ///         Values(vec!["$.active == true".into()])
+    ///     )
+    /// );
+    /// ```
+    fn json_matches<T>(self, path: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(PgBinOper::JsonPathMatches, PgFunc::json_path_arg(path))
+    }
+
     /// Express an postgres fulltext search contains (`@>`) expression.
     ///
     /// # Examples
@@ -115,36 +150,24 @@ pub trait PgExpr: ExprTrait {
     }
 
     /// Express a `ILIKE` expression.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use sea_query::{*, tests_cfg::*, extension::postgres::PgExpr};
-    ///
-    /// let query = Query::select()
-    ///     .columns([Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::col((Char::Table, Char::Character)).ilike("Ours'%"))
-    ///     .to_owned();
-    ///
-    /// assert_eq!(
-    ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" ILIKE E'Ours\'%'"#
-    /// );
-    /// ```
+    #[deprecated(since = "0.32.2", note = "Please use the [`ExprTrait::ilike`] method")]
     fn ilike<L>(self, like: L) -> SimpleExpr
     where
         L: IntoLikeExpr,
     {
-        self.binary(PgBinOper::ILike, like.into_like_expr())
+        ExprTrait::ilike(self, like)
     }
 
-    /// Express a `NOT ILIKE` expression
+    /// Express a `NOT ILIKE` expression.
+    #[deprecated(
+        since = "0.32.2",
+        note = "Please use the [`ExprTrait::not_ilike`] method"
+    )]
     fn not_ilike<L>(self, like: L) -> SimpleExpr
     where
         L: IntoLikeExpr,
     {
-        self.binary(PgBinOper::NotILike, like.into_like_expr())
+        ExprTrait::not_ilike(self, like)
     }
 
     /// Express a postgres retrieves JSON field as JSON value (`->`).
@@ -1,6 +1,6 @@
 //! For calling built-in Postgres SQL functions.
 
-use crate::{expr::*, func::*};
+use crate::{expr::*, func::*, Alias, ColumnType, IntoIden};
 
 /// Functions
 #[derive(Debug, Clone, PartialEq)]
@@ -12,10 +12,25 @@ pub enum PgFunction {
     WebsearchToTsquery,
     TsRank,
     TsRankCd,
+    TsHeadline,
     StartsWith,
     GenRandomUUID,
     JsonBuildObject,
+    JsonbBuildObject,
     JsonAgg,
+    JsonbPathQuery,
+    JsonbPathQueryArray,
+    JsonbPathExists,
+    ArrayLength,
+    Cardinality,
+    ArrayPosition,
+    ArrayRemove,
+    ArrayAppend,
+    ArrayToString,
+    StringToArray,
+    WidthBucket,
+    ToChar,
+    Unnest,
     #[cfg(feature = "postgres-array")]
     Any,
     #[cfg(feature = "postgres-array")]
@@ -244,6 +259,57 @@ impl PgFunc {
             .args([vector.into(), query.into()])
     }
 
+    /// Call `TS_HEADLINE` function. Postgres only.
+    ///
+    /// The parameter `regconfig` represents the OID of the text search configuration.
+    /// If the value is `None` the argument is omitted from the query, and hence the database default used.
+    /// Likewise, `options` is omitted if `None`.
+    ///
+    /// To share one tsquery between the match, the rank and the headline, select it
+    /// with [`SelectStatement::from_function`](crate::SelectStatement::from_function) and refer to it by its alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(PgFunc::ts_headline(
+    ///         None,
+    ///         Expr::col(Glyph::Image),
+    ///         PgFunc::to_tsquery("a & b", None),
+    ///         Some("MaxWords=10"),
+    ///     ))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT TS_HEADLINE("image", TO_TSQUERY('a & b'), 'MaxWords=10') FROM "glyph""#
+    /// );
+    /// ```
+    pub fn ts_headline<D, Q>(
+        regconfig: Option<u32>,
+        document: D,
+        query: Q,
+        options: Option<&str>,
+    ) -> FunctionCall
+    where
+        D: Into<SimpleExpr>,
+        Q: Into<SimpleExpr>,
+    {
+        let mut args = Vec::new();
+        if let Some(config) = regconfig {
+            args.push(SimpleExpr::Value(config.into()));
+        }
+        args.push(document.into());
+        args.push(query.into());
+        if let Some(options) = options {
+            args.push(SimpleExpr::Value(options.into()));
+        }
+        FunctionCall::new(Function::PgFunction(PgFunction::TsHeadline)).args(args)
+    }
+
     /// Call `ANY` function. Postgres only.
     ///
     /// # Examples
@@ -355,6 +421,9 @@ impl PgFunc {
 
     /// Call the `JSON_BUILD_OBJECT` function. Postgres only.
     ///
+    /// Keys given as values are written as string literals, so that Postgres does not need
+    /// to infer the type of a parameter; values are bound as usual.
+    ///
     /// # Examples
     ///
     /// ```
@@ -371,17 +440,63 @@ impl PgFunc {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT JSON_BUILD_OBJECT('a', 1, 'b', '2')"#
     /// );
+    ///
+    /// let (sql, values) = query.build(PostgresQueryBuilder);
+    ///
+    /// assert_eq!(sql, r#"SELECT JSON_BUILD_OBJECT('a', $1, 'b', $2)"#);
+    /// assert_eq!(values, Values(vec![1.into(), "2".into()]));
     /// ```
     pub fn json_build_object<T>(pairs: Vec<(T, T)>) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::JsonBuildObject))
+            .args(Self::build_object_args(pairs))
+    }
+
+    /// Call the `JSONB_BUILD_OBJECT` function. Postgres only.
+    ///
+    /// Keys given as values are written as string literals, the same as [`PgFunc::json_build_object`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(PgFunc::jsonb_build_object(vec![
+    ///         (Expr::val("id"), Expr::col(Font::Id)),
+    ///         (Expr::val("name"), Expr::col(Font::Name)),
+    ///     ]))
+    ///     .from(Font::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT JSONB_BUILD_OBJECT('id', "id", 'name', "name") FROM "font""#
+    /// );
+    /// ```
+    pub fn jsonb_build_object<T>(pairs: Vec<(T, T)>) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::JsonbBuildObject))
+            .args(Self::build_object_args(pairs))
+    }
+
+    fn build_object_args<T>(pairs: Vec<(T, T)>) -> Vec<SimpleExpr>
     where
         T: Into<SimpleExpr>,
     {
         let mut args = vec![];
         for (key, value) in pairs {
-            args.push(key.into());
+            args.push(match key.into() {
+                SimpleExpr::Value(key) => SimpleExpr::Constant(key),
+                key => key,
+            });
             args.push(value.into());
         }
-        FunctionCall::new(Function::PgFunction(PgFunction::JsonBuildObject)).args(args)
+        args
     }
 
     /// Call the `JSON_AGG` function. Postgres only.
@@ -407,4 +522,401 @@ impl PgFunc {
     {
         FunctionCall::new(Function::PgFunction(PgFunction::JsonAgg)).arg(expr)
     }
+
+    /// Call the `JSONB_PATH_QUERY` function, returning every item matched by the path as a set of rows. Postgres only.
+    ///
+    /// The path is cast to `jsonpath` when given as a value, as Postgres doesn't cast a text parameter implicitly.
+    /// Variables referenced in the path are passed as a JSON object with [`FunctionCall::arg`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::jsonb_path_query(
+    ///         Expr::col(Glyph::Image),
+    ///         "$.tokens[*] ? (@ > 5)",
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT JSONB_PATH_QUERY("image", '$.tokens[*] ? (@ > 5)'::jsonpath) FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT JSONB_PATH_QUERY("image", $1::jsonpath) FROM "glyph""#.to_owned(),
+    ///         Values(vec!["$.tokens[*] ? (@ > 5)".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn jsonb_path_query<T, P>(target: T, path: P) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        P: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::JsonbPathQuery))
+            .args([target.into(), Self::json_path_arg(path)])
+    }
+
+    /// Call the `JSONB_PATH_QUERY_ARRAY` function, returning the items matched by the path as a JSON array. Postgres only.
+    ///
+    /// The path is handled as in [`PgFunc::jsonb_path_query`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::jsonb_path_query_array(
+    ///         Expr::col(Glyph::Image),
+    ///         "$.tokens[*] ? (@ > 5)",
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT JSONB_PATH_QUERY_ARRAY("image", '$.tokens[*] ? (@ > 5)'::jsonpath) FROM "glyph""#
+    /// );
+    /// ```
+    pub fn jsonb_path_query_array<T, P>(target: T, path: P) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        P: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::JsonbPathQueryArray))
+            .args([target.into(), Self::json_path_arg(path)])
+    }
+
+    /// Call the `JSONB_PATH_EXISTS` function, returning whether the path matches any item. Postgres only.
+    ///
+    /// The path is handled as in [`PgFunc::jsonb_path_query`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(PgFunc::jsonb_path_exists(
+    ///         Expr::col(Glyph::Image),
+    ///         "$.tokens[*] ? (@ > 5)",
+    ///     ).into())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE JSONB_PATH_EXISTS("image", '$.tokens[*] ? (@ > 5)'::jsonpath)"#
+    /// );
+    /// ```
+    pub fn jsonb_path_exists<T, P>(target: T, path: P) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        P: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::JsonbPathExists))
+            .args([target.into(), Self::json_path_arg(path)])
+    }
+
+    pub(crate) fn json_path_arg<P>(path: P) -> SimpleExpr
+    where
+        P: Into<SimpleExpr>,
+    {
+        match path.into() {
+            path @ SimpleExpr::Value(_) => {
+                path.pg_cast(ColumnType::Custom(Alias::new("jsonpath").into_iden()))
+            }
+            path => path,
+        }
+    }
+    /// Call the `ARRAY_LENGTH` function. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::array_length(Expr::col(Glyph::Tokens), 1))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ARRAY_LENGTH("tokens", 1) FROM "glyph""#
+    /// );
+    /// ```
+    pub fn array_length<T>(expr: T, dimension: i32) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ArrayLength))
+            .args([expr.into(), dimension.into()])
+    }
+
+    /// Call the `CARDINALITY` function. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::cardinality(Expr::col(Glyph::Tokens)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CARDINALITY("tokens") FROM "glyph""#
+    /// );
+    /// ```
+    pub fn cardinality<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::Cardinality)).arg(expr)
+    }
+
+    /// Call the `ARRAY_POSITION` function. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::array_position(Expr::col(Glyph::Tokens), "a"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ARRAY_POSITION("tokens", 'a') FROM "glyph""#
+    /// );
+    /// ```
+    pub fn array_position<T, E>(expr: T, elem: E) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        E: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ArrayPosition))
+            .args([expr.into(), elem.into()])
+    }
+
+    /// Call the `ARRAY_REMOVE` function. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(
+    ///         Glyph::Tokens,
+    ///         PgFunc::array_remove(Expr::col(Glyph::Tokens), "a"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "tokens" = ARRAY_REMOVE("tokens", 'a')"#
+    /// );
+    /// ```
+    pub fn array_remove<T, E>(expr: T, elem: E) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        E: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ArrayRemove))
+            .args([expr.into(), elem.into()])
+    }
+
+    /// Call the `ARRAY_APPEND` function. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(
+    ///         Glyph::Tokens,
+    ///         PgFunc::array_append(Expr::col(Glyph::Tokens), "a"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "tokens" = ARRAY_APPEND("tokens", 'a')"#
+    /// );
+    /// ```
+    pub fn array_append<T, E>(expr: T, elem: E) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        E: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ArrayAppend))
+            .args([expr.into(), elem.into()])
+    }
+
+    /// Call the `ARRAY_TO_STRING` function, joining the elements of an array with a separator. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Glyph::Table)
+    ///     .expr(PgFunc::array_to_string(Expr::col(Glyph::Tokens), ","))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT ARRAY_TO_STRING("tokens", $1) FROM "glyph""#.to_owned(),
+    ///         Values(vec![",".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn array_to_string<T, S>(expr: T, separator: S) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        S: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ArrayToString))
+            .args([expr.into(), separator.into()])
+    }
+
+    /// Call the `STRING_TO_ARRAY` function, splitting a string into an array at a separator. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Tokens).eq(PgFunc::string_to_array("a,b", ",")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "glyph" WHERE "tokens" = STRING_TO_ARRAY($1, $2)"#.to_owned(),
+    ///         Values(vec!["a,b".into(), ",".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn string_to_array<T, S>(expr: T, separator: S) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        S: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::StringToArray))
+            .args([expr.into(), separator.into()])
+    }
+
+    /// Call the `WIDTH_BUCKET` function. Postgres only.
+    ///
+    /// Returns the number of the bucket `expr` falls into, out of `count` equal-width buckets spanning `low` to `high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Char::Table)
+    ///     .expr(PgFunc::width_bucket(Expr::col(Char::SizeW), 0, 100, 10))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT WIDTH_BUCKET("size_w", 0, 100, 10) FROM "character""#
+    /// );
+    /// ```
+    pub fn width_bucket<T, L, H>(expr: T, low: L, high: H, count: i32) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        L: Into<SimpleExpr>,
+        H: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::WidthBucket)).args([
+            expr.into(),
+            low.into(),
+            high.into(),
+            count.into(),
+        ])
+    }
+
+    /// Call `TO_CHAR` function, formatting a date / time or number with a Postgres template.
+    /// See [`Func::format_date`](crate::Func::format_date) for formatting dates portably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(PgFunc::to_char(Expr::col(Char::SizeW), "FM999,999.00"))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT TO_CHAR("size_w", 'FM999,999.00') FROM "character""#
+    /// );
+    /// ```
+    pub fn to_char<T, F>(expr: T, format: F) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        F: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::ToChar))
+            .args([expr.into(), format.into()])
+    }
+
+    /// Call `UNNEST` function, expanding an array into a set of rows.
+    ///
+    /// Functions in the FROM clause can refer to the columns of the preceding tables,
+    /// so selecting from `UNNEST` of an array column expands the array of each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::PgFunc, *};
+    ///
+    /// let query = Query::select()
+    ///     .column((Alias::new("p"), Alias::new("id")))
+    ///     .column(Alias::new("tag"))
+    ///     .from_as(Alias::new("post"), Alias::new("p"))
+    ///     .from_function(
+    ///         PgFunc::unnest(Expr::col((Alias::new("p"), Alias::new("tags")))),
+    ///         Alias::new("tag"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "p"."id", "tag" FROM "post" AS "p", UNNEST("p"."tags") AS "tag""#
+    /// );
+    /// ```
+    pub fn unnest<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::PgFunction(PgFunction::Unnest)).arg(expr)
+    }
 }
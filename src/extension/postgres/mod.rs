@@ -2,6 +2,7 @@ pub use expr::*;
 pub use extension::*;
 pub use func::*;
 pub use ltree::*;
+pub use select::*;
 pub use types::*;
 
 use crate::types::BinOper;
@@ -11,14 +12,20 @@ pub(crate) mod extension;
 pub(crate) mod func;
 pub(crate) mod interval;
 pub(crate) mod ltree;
+pub(crate) mod select;
 pub(crate) mod types;
 
 /// Binary operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PgBinOper {
+    /// `ILIKE`. Converts into the portable [`BinOper::ILike`].
     ILike,
+    /// `NOT ILIKE`. Converts into the portable [`BinOper::NotILike`].
     NotILike,
+    /// `@@`. Full-text search match of a `tsvector` and a `tsquery`.
     Matches,
+    /// `@@`. Result of a JSON path predicate check on a `jsonb` value.
+    JsonPathMatches,
     Contains,
     Contained,
     Concatenate,
@@ -47,6 +54,10 @@ pub enum PgBinOper {
 
 impl From<PgBinOper> for BinOper {
     fn from(o: PgBinOper) -> Self {
-        Self::PgOperator(o)
+        match o {
+            PgBinOper::ILike => Self::ILike,
+            PgBinOper::NotILike => Self::NotILike,
+            _ => Self::PgOperator(o),
+        }
     }
 }
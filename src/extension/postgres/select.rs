@@ -0,0 +1,119 @@
+use crate::{IntoTableRef, SelectStatement, TableRef, Value};
+
+/// Sampling method of `TABLESAMPLE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSampleMethod {
+    /// Each row is selected with the given probability
+    Bernoulli,
+    /// Each storage page is selected with the given probability, faster but less random
+    System,
+}
+
+/// `TABLESAMPLE` clause of a table in FROM
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSample {
+    pub(crate) method: TableSampleMethod,
+    pub(crate) percentage: Value,
+    pub(crate) repeatable: Option<Value>,
+}
+
+pub trait PostgresSelectStatementExt {
+    #[allow(clippy::wrong_self_convention)]
+    fn from_only<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef;
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_sampled<R>(
+        &mut self,
+        tbl_ref: R,
+        method: TableSampleMethod,
+        percentage: f64,
+        repeatable: Option<i64>,
+    ) -> &mut Self
+    where
+        R: IntoTableRef;
+}
+
+impl PostgresSelectStatementExt for SelectStatement {
+    /// From table, excluding the tables inheriting from it.
+    ///
+    /// The `ONLY` modifier is only rendered by Postgres; other backends select from the table as usual.
+    /// See [PostgreSQL documentation for Inheritance](https://www.postgresql.org/docs/current/ddl-inherit.html)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from_only(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM ONLY "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "character" FROM "character""#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_only<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from(TableRef::Only(Box::new(tbl_ref.into_table_ref())))
+    }
+
+    /// From a random sample of the table, of about `percentage` percent of its rows.
+    /// With a `repeatable` seed, the same sample is selected as long as the table is unchanged.
+    ///
+    /// Only supported by Postgres; other backends panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from_sampled(Char::Table, TableSampleMethod::Bernoulli, 10.0, Some(42))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "character" FROM "character" TABLESAMPLE BERNOULLI ($1) REPEATABLE ($2)"#
+    ///             .to_owned(),
+    ///         Values(vec![10.0.into(), 42i64.into()])
+    ///     )
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_sampled<R>(
+        &mut self,
+        tbl_ref: R,
+        method: TableSampleMethod,
+        percentage: f64,
+        repeatable: Option<i64>,
+    ) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from(TableRef::TableSample(
+            Box::new(tbl_ref.into_table_ref()),
+            TableSample {
+                method,
+                percentage: percentage.into(),
+                repeatable: repeatable.map(Into::into),
+            },
+        ))
+    }
+}
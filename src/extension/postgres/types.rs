@@ -117,19 +117,19 @@ pub trait TypeBuilder: QuotedBuilder {
     fn prepare_type_ref(&self, type_ref: &TypeRef, sql: &mut dyn SqlWriter) {
         match type_ref {
             TypeRef::Type(name) => {
-                name.prepare(sql.as_writer(), self.quote());
+                name.prepare(sql.as_iden_writer(), self.quote());
             }
             TypeRef::SchemaType(schema, name) => {
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                name.prepare(sql.as_writer(), self.quote());
+                name.prepare(sql.as_iden_writer(), self.quote());
             }
             TypeRef::DatabaseSchemaType(database, schema, name) => {
-                database.prepare(sql.as_writer(), self.quote());
+                database.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                schema.prepare(sql.as_writer(), self.quote());
+                schema.prepare(sql.as_iden_writer(), self.quote());
                 write!(sql, ".").unwrap();
-                name.prepare(sql.as_writer(), self.quote());
+                name.prepare(sql.as_iden_writer(), self.quote());
             }
         }
     }
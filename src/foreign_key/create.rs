@@ -1,7 +1,8 @@
 use inherent::inherent;
 
 use crate::{
-    backend::SchemaBuilder, types::*, ForeignKeyAction, SchemaStatementBuilder, TableForeignKey,
+    backend::SchemaBuilder, types::*, ForeignKeyAction, SchemaStatementBuilder, SqlWriter,
+    TableForeignKey,
 };
 
 /// Create a foreign key constraint for an existing table. Unsupported by Sqlite
@@ -191,5 +192,15 @@ impl SchemaStatementBuilder for ForeignKeyCreateStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_foreign_key_create_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
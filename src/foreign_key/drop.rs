@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder, TableForeignKey};
+use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder, SqlWriter, TableForeignKey};
 
 /// Drop a foreign key constraint for an existing table
 ///
@@ -69,5 +69,15 @@ impl SchemaStatementBuilder for ForeignKeyDropStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_foreign_key_drop_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
@@ -1,7 +1,9 @@
 //! For calling built-in SQL functions.
 
-use crate::{expr::*, types::*};
+use crate::{expr::*, types::*, ColumnType, Condition, IntoCondition};
 
+#[cfg(feature = "backend-mysql")]
+pub use crate::extension::mysql::{MySqlFunc, MySqlFunction};
 #[cfg(feature = "backend-postgres")]
 pub use crate::extension::postgres::{PgFunc, PgFunction};
 
@@ -17,10 +19,14 @@ pub enum Function {
     IfNull,
     Greatest,
     Least,
+    GreatestIgnoreNulls,
+    LeastIgnoreNulls,
     CharLength,
     Cast,
     Custom(DynIden),
     Coalesce,
+    NullIf,
+    Trim,
     Lower,
     Upper,
     BitAnd,
@@ -28,16 +34,43 @@ pub enum Function {
     Random,
     Round,
     Md5,
+    Extract(DatePart),
+    TimestampDiff,
+    RowNumber,
+    CastAsType(ColumnType),
+    PgCastAsType(ColumnType),
+    Hash,
+    FormatDate(String),
+    StringAgg,
+    #[cfg(feature = "backend-mysql")]
+    MySqlFunction(MySqlFunction),
     #[cfg(feature = "backend-postgres")]
     PgFunction(PgFunction),
 }
 
+/// Date / time field to be extracted by [`Func::extract`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Number of seconds since the Unix epoch, or the total number of seconds of an interval
+    Epoch,
+}
+
 /// Function call.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionCall {
     pub(crate) func: Function,
     pub(crate) args: Vec<SimpleExpr>,
     pub(crate) mods: Vec<FuncArgMod>,
+    /// Set by [`FunctionCall::distinct`], before or after the arguments are added
+    pub(crate) distinct: bool,
+    pub(crate) filter: Option<Condition>,
+    pub(crate) order_by: Vec<OrderExpr>,
 }
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
@@ -51,6 +84,9 @@ impl FunctionCall {
             func,
             args: Vec::new(),
             mods: Vec::new(),
+            distinct: false,
+            filter: None,
+            order_by: Vec::new(),
         }
     }
 
@@ -81,6 +117,98 @@ impl FunctionCall {
         self
     }
 
+    /// Aggregate only the rows matching the condition, i.e. `FILTER (WHERE ...)`.
+    ///
+    /// MySQL has no `FILTER` clause, the arguments are wrapped in `CASE WHEN ... THEN ... END` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::sum(Expr::col(Char::SizeW)).filter(Expr::col(Char::SizeH).gt(2)))
+    ///     .expr(Func::count(Expr::col(Asterisk)).filter(Expr::col(Char::FontId).is_null()))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT SUM((CASE WHEN (`size_h` > 2) THEN `size_w` END)), COUNT((CASE WHEN (`font_id` IS NULL) THEN 1 END)) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SUM("size_w") FILTER (WHERE "size_h" > 2), COUNT(*) FILTER (WHERE "font_id" IS NULL) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT SUM("size_w") FILTER (WHERE "size_h" > 2), COUNT(*) FILTER (WHERE "font_id" IS NULL) FROM "character""#
+    /// );
+    /// ```
+    pub fn filter<C>(mut self, condition: C) -> Self
+    where
+        C: IntoCondition,
+    {
+        self.filter = Some(condition.into_condition());
+        self
+    }
+
+    /// Aggregate only the distinct values of the first argument, i.e. `DISTINCT` in front of it.
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Order the rows fed into an ordered aggregate, e.g. [`Func::string_agg`], by column.
+    pub fn order_by<T>(self, col: T, order: Order) -> Self
+    where
+        T: IntoColumnRef,
+    {
+        self.order_by_expr(SimpleExpr::Column(col.into_column_ref()), order)
+    }
+
+    /// Order the rows fed into an ordered aggregate, e.g. [`Func::string_agg`], by expression.
+    pub fn order_by_expr(mut self, expr: SimpleExpr, order: Order) -> Self {
+        self.order_by.push(OrderExpr {
+            expr,
+            order,
+            nulls: None,
+        });
+        self
+    }
+
+    #[cfg(feature = "backend-mysql")]
+    /// The equivalent function call without the `FILTER` clause, passing only the matching rows with `CASE`
+    pub(crate) fn filter_as_case(&self) -> Self {
+        let filter = match &self.filter {
+            Some(filter) => filter,
+            None => return self.clone(),
+        };
+        Self {
+            func: self.func.clone(),
+            args: self
+                .args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    // The separator of a string aggregate is not a filtered value
+                    if i > 0 && matches!(self.func, Function::StringAgg) {
+                        return arg.clone();
+                    }
+                    let then = match arg {
+                        SimpleExpr::Column(ColumnRef::Asterisk) => SimpleExpr::Constant(1.into()),
+                        _ => arg.clone(),
+                    };
+                    Expr::case(filter.clone(), then).into()
+                })
+                .collect(),
+            mods: self.mods.clone(),
+            distinct: self.distinct,
+            filter: None,
+            order_by: self.order_by.clone(),
+        }
+    }
+
     pub fn get_func(&self) -> &Function {
         &self.func
     }
@@ -92,6 +220,10 @@ impl FunctionCall {
     pub fn get_mods(&self) -> &[FuncArgMod] {
         &self.mods
     }
+
+    pub fn get_order_by(&self) -> &[OrderExpr] {
+        &self.order_by
+    }
 }
 
 /// Function call helper.
@@ -330,6 +462,41 @@ impl Func {
         FunctionCall::new(Function::Count).arg(expr)
     }
 
+    /// Call `COUNT(*)` function.
+    ///
+    /// Combined with an empty window, it counts every row of the result before `LIMIT` and `OFFSET`
+    /// are applied, so a page of rows can be fetched together with the total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_window_as(Func::count_star(), WindowStatement::new(), Alias::new("total"))
+    ///     .from(Char::Table)
+    ///     .limit(10)
+    ///     .offset(20)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, COUNT(*) OVER () AS `total` FROM `character` LIMIT 10 OFFSET 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", COUNT(*) OVER () AS "total" FROM "character" LIMIT 10 OFFSET 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "character", COUNT(*) OVER () AS "total" FROM "character" LIMIT 10 OFFSET 20"#
+    /// );
+    /// ```
+    pub fn count_star() -> FunctionCall {
+        FunctionCall::new(Function::Count).arg(Expr::col(Asterisk))
+    }
+
     /// Call `COUNT` function with the `DISTINCT` modifier.
     ///
     /// # Examples
@@ -396,6 +563,10 @@ impl Func {
 
     /// Call `GREATEST` function.
     ///
+    /// Backends disagree on NULL arguments: Postgres skips them and only returns NULL when all arguments are NULL,
+    /// while MySQL and SQLite (`MAX`) return NULL as soon as any argument is NULL.
+    /// Use [`Func::greatest_ignore_nulls`] for the Postgres behavior on every backend.
+    ///
     /// # Examples
     ///
     /// ```
@@ -429,8 +600,51 @@ impl Func {
         FunctionCall::new(Function::Greatest).args(args)
     }
 
+    /// Call `GREATEST` function, skipping NULL arguments on every backend.
+    /// The result is the largest non-NULL argument, or NULL when all arguments are NULL.
+    ///
+    /// Postgres `GREATEST` behaves this way already. On MySQL and SQLite each argument falls back to the others
+    /// through `COALESCE`, so the arguments are repeated, including their bound values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::greatest_ignore_nulls([
+    ///         Expr::col(Char::SizeW).into(),
+    ///         Expr::col(Char::SizeH).into(),
+    ///     ]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT GREATEST(COALESCE(`size_w`, `size_h`), COALESCE(`size_h`, `size_w`)) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT GREATEST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MAX(COALESCE("size_w", "size_h"), COALESCE("size_h", "size_w")) FROM "character""#
+    /// );
+    /// ```
+    pub fn greatest_ignore_nulls<I>(args: I) -> FunctionCall
+    where
+        I: IntoIterator<Item = SimpleExpr>,
+    {
+        FunctionCall::new(Function::GreatestIgnoreNulls).args(args)
+    }
+
     /// Call `LEAST` function.
     ///
+    /// Backends disagree on NULL arguments: Postgres skips them and only returns NULL when all arguments are NULL,
+    /// while MySQL and SQLite (`MIN`) return NULL as soon as any argument is NULL.
+    /// Use [`Func::least_ignore_nulls`] for the Postgres behavior on every backend.
+    ///
     /// # Examples
     ///
     /// ```
@@ -464,6 +678,45 @@ impl Func {
         FunctionCall::new(Function::Least).args(args)
     }
 
+    /// Call `LEAST` function, skipping NULL arguments on every backend.
+    /// The result is the smallest non-NULL argument, or NULL when all arguments are NULL.
+    ///
+    /// Postgres `LEAST` behaves this way already. On MySQL and SQLite each argument falls back to the others
+    /// through `COALESCE`, so the arguments are repeated, including their bound values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::least_ignore_nulls([
+    ///         Expr::col(Char::SizeW).into(),
+    ///         Expr::col(Char::SizeH).into(),
+    ///     ]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT LEAST(COALESCE(`size_w`, `size_h`), COALESCE(`size_h`, `size_w`)) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT LEAST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MIN(COALESCE("size_w", "size_h"), COALESCE("size_h", "size_w")) FROM "character""#
+    /// );
+    /// ```
+    pub fn least_ignore_nulls<I>(args: I) -> FunctionCall
+    where
+        I: IntoIterator<Item = SimpleExpr>,
+    {
+        FunctionCall::new(Function::LeastIgnoreNulls).args(args)
+    }
+
     /// Call `IF NULL` function.
     ///
     /// # Examples
@@ -536,6 +789,45 @@ impl Func {
         ))
     }
 
+    /// Call `CAST` function with a [`ColumnType`].
+    ///
+    /// The type is written the same way as in a column definition of the backend,
+    /// except MySQL, which only accepts a few types as the target of `CAST`.
+    /// There textual types become `char`, integers and booleans `signed` or `unsigned`,
+    /// timestamps `datetime`, binary types `binary` and UUIDs `char(36)`, their text form.
+    /// The types MySQL can't cast to, like bit strings and enums, panic.
+    ///
+    /// Wrapping a bound `NULL` lets Postgres infer the type of the parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::cast_as_type(Value::String(None), ColumnType::Text))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST(NULL AS char)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CAST(NULL AS text)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST(NULL AS text)"#
+    /// );
+    /// ```
+    pub fn cast_as_type<V>(expr: V, column_type: ColumnType) -> FunctionCall
+    where
+        V: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::CastAsType(column_type)).arg(expr)
+    }
+
     /// Call `CAST` function with a case-sensitive custom type.
     ///
     /// # Examples
@@ -612,6 +904,71 @@ impl Func {
         FunctionCall::new(Function::Coalesce).args(args)
     }
 
+    /// Call `NULLIF` function, which returns NULL if both arguments are equal, or the first argument otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::nullif(Expr::col(Char::SizeW), 0))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT NULLIF(`size_w`, 0) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT NULLIF("size_w", 0) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT NULLIF("size_w", 0) FROM "character""#
+    /// );
+    /// ```
+    pub fn nullif<A, B>(a: A, b: B) -> FunctionCall
+    where
+        A: Into<SimpleExpr>,
+        B: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::NullIf).args([a.into(), b.into()])
+    }
+
+    /// Call `TRIM` function, which removes leading and trailing spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::trim(Expr::col(Char::Character)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT TRIM(`character`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT TRIM("character") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT TRIM("character") FROM "character""#
+    /// );
+    /// ```
+    pub fn trim<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::Trim).arg(expr)
+    }
+
     /// Call `LOWER` function.
     ///
     /// # Examples
@@ -835,6 +1192,39 @@ impl Func {
     ///
     /// assert_eq!(query.to_string(SqliteQueryBuilder), r#"SELECT RANDOM()"#);
     /// ```
+    ///
+    /// MySQL and Postgres return a float in `[0, 1)`, which can be compared with a probability
+    /// to sample rows, or used in `ORDER BY` to pick random rows.
+    /// Note that SQLite returns a signed 64-bit integer instead.
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::expr(Func::random()).lt(0.01))
+    ///     .order_by_expr(Func::random().into(), Order::Asc)
+    ///     .limit(1)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"SELECT `id` FROM `character` WHERE RAND() < ? ORDER BY RAND() ASC LIMIT ?"#
+    ///             .to_owned(),
+    ///         Values(vec![0.01.into(), 1u64.into()])
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "character" WHERE RANDOM() < $1 ORDER BY RANDOM() ASC LIMIT $2"#
+    ///             .to_owned(),
+    ///         Values(vec![0.01.into(), 1u64.into()])
+    ///     )
+    /// );
+    /// ```
     pub fn random() -> FunctionCall {
         FunctionCall::new(Function::Random)
     }
@@ -867,4 +1257,254 @@ impl Func {
     {
         FunctionCall::new(Function::Md5).arg(expr)
     }
+
+    /// Hash the expression into one of `buckets` buckets, numbered from 0, for deterministic sampling
+    /// and A/B bucketing. The bucket count is bound as a parameter.
+    ///
+    /// Postgres takes the first 32 bits of the `MD5` digest of the text of the expression and MySQL uses `CRC32`,
+    /// so the buckets differ across backends, but are stable across database versions.
+    /// SQLite has no built-in hash function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Func::hash_mod(Expr::col(Char::Id), 100).lt(5))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE CRC32(`id`) % 100 < 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE (CAST(CAST('x' || SUBSTR(MD5(CAST("id" AS TEXT)), 1, 8) AS BIT(32)) AS INTEGER) & 2147483647) % 100 < 5"#
+    /// );
+    /// ```
+    pub fn hash_mod<T>(expr: T, buckets: u32) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        SimpleExpr::from(FunctionCall::new(Function::Hash).arg(expr)).modulo(buckets)
+    }
+
+    /// Call `EXTRACT` function.
+    ///
+    /// SQLite has no `EXTRACT`, so it is rendered with `STRFTIME`.
+    /// To get the number of seconds between two timestamps, use [`Func::timestamp_diff`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::extract(DatePart::Year, Expr::col(Char::CreatedAt)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT EXTRACT(YEAR FROM `created_at`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT EXTRACT(YEAR FROM "created_at") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST(STRFTIME('%Y', "created_at") AS INTEGER) FROM "character""#
+    /// );
+    /// ```
+    pub fn extract<T>(part: DatePart, expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::Extract(part)).arg(expr)
+    }
+
+    /// Call a function giving the number of seconds between two timestamps.
+    ///
+    /// Postgres subtracts the timestamps and extracts [`DatePart::Epoch`] from the interval.
+    /// MySQL has no interval type, so it is rendered as `TIMESTAMPDIFF(SECOND, start, end)` instead;
+    /// SQLite has no `EXTRACT`, so it is rendered with `STRFTIME`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::timestamp_diff(
+    ///         Expr::col(Alias::new("started_at")),
+    ///         Expr::col(Alias::new("ended_at")),
+    ///     ))
+    ///     .from(Alias::new("session"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT TIMESTAMPDIFF(SECOND, `started_at`, `ended_at`) FROM `session`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT EXTRACT(EPOCH FROM "ended_at" - "started_at") FROM "session""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT (STRFTIME('%s', "ended_at") - STRFTIME('%s', "started_at")) FROM "session""#
+    /// );
+    /// ```
+    pub fn timestamp_diff<S, E>(start: S, end: E) -> FunctionCall
+    where
+        S: Into<SimpleExpr>,
+        E: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::TimestampDiff)
+            .arg(start)
+            .arg(end)
+    }
+
+    /// Format a date / time as text, with a portable `strftime` style format.
+    ///
+    /// The format supports `%Y` (4-digit year), `%m` (month), `%d` (day of month), `%H` (hour of day),
+    /// `%M` (minute), `%S` (second) and `%%`, other specifiers panic. It is translated for each backend:
+    /// Postgres `TO_CHAR`, MySQL `DATE_FORMAT` and SQLite `STRFTIME`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::format_date(Expr::col(Char::CreatedAt), "%Y-%m-%d %H:%M"))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT DATE_FORMAT(`created_at`, '%Y-%m-%d %H:%i') FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT TO_CHAR("created_at", 'YYYY-MM-DD HH24:MI') FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT STRFTIME('%Y-%m-%d %H:%M', "created_at") FROM "character""#
+    /// );
+    /// ```
+    pub fn format_date<T, F>(expr: T, format: F) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        F: Into<String>,
+    {
+        FunctionCall::new(Function::FormatDate(format.into())).arg(expr)
+    }
+
+    /// Call the string aggregate function, concatenating the values with a separator.
+    ///
+    /// It is `STRING_AGG` on Postgres and `GROUP_CONCAT` on MySQL and SQLite.
+    /// Combine with [`FunctionCall::distinct`] and [`FunctionCall::order_by`] as needed.
+    /// SQLite only accepts `DISTINCT` with the default separator, so it panics on a distinct string aggregate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(
+    ///         Func::string_agg(Expr::col(Font::Name), ", ")
+    ///             .distinct()
+    ///             .order_by(Font::Name, Order::Asc),
+    ///     )
+    ///     .from(Font::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT GROUP_CONCAT(DISTINCT `name` ORDER BY `name` ASC SEPARATOR ', ') FROM `font`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT STRING_AGG(DISTINCT "name", ', ' ORDER BY "name" ASC) FROM "font""#
+    /// );
+    /// ```
+    pub fn string_agg<T, S>(expr: T, separator: S) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+        S: Into<String>,
+    {
+        FunctionCall::new(Function::StringAgg)
+            .arg(expr)
+            .arg(SimpleExpr::Constant(separator.into().into()))
+    }
+
+    /// Call `ROW_NUMBER` window function, to be used with [`SelectStatement::expr_window_as`].
+    ///
+    /// Filtering on `ROW_NUMBER() = 1` in an outer query selects the first row per group.
+    /// Unlike Postgres' `DISTINCT ON`, this works on MySQL 8, Postgres and SQLite 3.25 alike.
+    ///
+    /// # Examples
+    ///
+    /// Select the latest character of each font:
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([Char::Id, Char::FontId, Char::Character])
+    ///     .from_subquery(
+    ///         Query::select()
+    ///             .columns([Char::Id, Char::FontId, Char::Character])
+    ///             .expr_window_as(
+    ///                 Func::row_number(),
+    ///                 WindowStatement::partition_by(Char::FontId)
+    ///                     .order_by(Char::CreatedAt, Order::Desc)
+    ///                     .to_owned(),
+    ///                 Alias::new("rn"),
+    ///             )
+    ///             .from(Char::Table)
+    ///             .to_owned(),
+    ///         Alias::new("t"),
+    ///     )
+    ///     .and_where(Expr::col(Alias::new("rn")).eq(1))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     [
+    ///         r#"SELECT `id`, `font_id`, `character` FROM"#,
+    ///         r#"(SELECT `id`, `font_id`, `character`, ROW_NUMBER() OVER ( PARTITION BY `font_id` ORDER BY `created_at` DESC ) AS `rn` FROM `character`) AS `t`"#,
+    ///         r#"WHERE `rn` = 1"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"SELECT "id", "font_id", "character" FROM"#,
+    ///         r#"(SELECT "id", "font_id", "character", ROW_NUMBER() OVER ( PARTITION BY "font_id" ORDER BY "created_at" DESC ) AS "rn" FROM "character") AS "t""#,
+    ///         r#"WHERE "rn" = 1"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     [
+    ///         r#"SELECT "id", "font_id", "character" FROM"#,
+    ///         r#"(SELECT "id", "font_id", "character", ROW_NUMBER() OVER ( PARTITION BY "font_id" ORDER BY "created_at" DESC ) AS "rn" FROM "character") AS "t""#,
+    ///         r#"WHERE "rn" = 1"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn row_number() -> FunctionCall {
+        FunctionCall::new(Function::RowNumber)
+    }
 }
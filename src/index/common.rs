@@ -1,4 +1,4 @@
-use crate::types::*;
+use crate::{types::*, SimpleExpr};
 
 /// Specification of a table index
 #[derive(Default, Debug, Clone)]
@@ -9,9 +9,12 @@ pub struct TableIndex {
 
 #[derive(Debug, Clone)]
 pub struct IndexColumn {
-    pub(crate) name: DynIden,
+    /// Name of the table column, absent for an index expression
+    pub(crate) name: Option<DynIden>,
     pub(crate) prefix: Option<u32>,
     pub(crate) order: Option<IndexOrder>,
+    /// Expression of a functional index column, written instead of the name
+    pub(crate) expr: Option<SimpleExpr>,
 }
 
 #[derive(Debug, Clone)]
@@ -36,9 +39,10 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.into_iden(),
+            name: Some(self.into_iden()),
             prefix: None,
             order: None,
+            expr: None,
         }
     }
 }
@@ -49,9 +53,10 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            name: Some(self.0.into_iden()),
             prefix: Some(self.1),
             order: None,
+            expr: None,
         }
     }
 }
@@ -62,9 +67,10 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            name: Some(self.0.into_iden()),
             prefix: None,
             order: Some(self.1),
+            expr: None,
         }
     }
 }
@@ -75,9 +81,10 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            name: Some(self.0.into_iden()),
             prefix: Some(self.1),
             order: Some(self.2),
+            expr: None,
         }
     }
 }
@@ -103,10 +110,11 @@ impl TableIndex {
         self
     }
 
+    /// Names of the table columns in the index, index expressions are skipped
     pub fn get_column_names(&self) -> Vec<String> {
         self.columns
             .iter()
-            .map(|col| col.name.to_string())
+            .filter_map(|col| col.name.as_ref().map(|name| name.to_string()))
             .collect()
     }
 
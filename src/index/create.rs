@@ -1,7 +1,7 @@
 use inherent::inherent;
 
-use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder};
-use crate::{ConditionHolder, ConditionalStatement, IntoCondition};
+use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder, SqlWriter};
+use crate::{ConditionHolder, ConditionalStatement, IntoCondition, SimpleExpr};
 
 use super::common::*;
 
@@ -170,6 +170,27 @@ use super::common::*;
 ///     r#"CREATE INDEX "idx-glyph-aspect" ON "glyph" ("aspect" ASC) WHERE "glyph"."aspect" IN (3, 4)"#
 /// );
 /// ```
+///
+/// The predicate of a partial index can be any expression, including function calls; values are inlined
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let index = Index::create()
+///     .name("idx-font-name-en")
+///     .table(Font::Table)
+///     .col(Font::Name)
+///     .and_where(Func::lower(Expr::col(Font::Language)).eq("en"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"CREATE INDEX "idx-font-name-en" ON "font" ("name") WHERE LOWER("language") = 'en'"#
+/// );
+/// assert_eq!(
+///     index.to_string(SqliteQueryBuilder),
+///     r#"CREATE INDEX "idx-font-name-en" ON "font" ("name") WHERE LOWER("language") = 'en'"#
+/// );
+/// ```
 #[derive(Default, Debug, Clone)]
 pub struct IndexCreateStatement {
     pub(crate) table: Option<TableRef>,
@@ -177,6 +198,7 @@ pub struct IndexCreateStatement {
     pub(crate) primary: bool,
     pub(crate) unique: bool,
     pub(crate) nulls_not_distinct: bool,
+    pub(crate) include_columns: Vec<DynIden>,
     pub(crate) index_type: Option<IndexType>,
     pub(crate) if_not_exists: bool,
     pub(crate) r#where: ConditionHolder,
@@ -200,6 +222,7 @@ impl IndexCreateStatement {
             primary: false,
             unique: false,
             nulls_not_distinct: false,
+            include_columns: Vec::new(),
             index_type: None,
             if_not_exists: false,
             r#where: ConditionHolder::new(),
@@ -239,6 +262,47 @@ impl IndexCreateStatement {
         self
     }
 
+    /// Add index expression, for a functional index on Postgres and SQLite, or a functional key part on MySQL 8.0.13+.
+    /// Values in the expression are inlined, as parameters can't be bound to DDL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-font-name-lower")
+    ///     .table(Font::Table)
+    ///     .col_expr(Func::lower(Expr::col(Font::Name)))
+    ///     .unique()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX `idx-font-name-lower` ON `font` ((LOWER(`name`)))"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-font-name-lower" ON "font" ((LOWER("name")))"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-font-name-lower" ON "font" ((LOWER("name")))"#
+    /// );
+    /// ```
+    pub fn col_expr<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.index.col(IndexColumn {
+            name: None,
+            prefix: None,
+            order: None,
+            expr: Some(expr.into()),
+        });
+        self
+    }
+
     /// Set index as primary
     pub fn primary(&mut self) -> &mut Self {
         self.primary = true;
@@ -251,12 +315,68 @@ impl IndexCreateStatement {
         self
     }
 
-    /// Set nulls to not be treated as distinct values. Only available on Postgres.
+    /// Set nulls to not be treated as distinct values, i.e. `UNIQUE NULLS NOT DISTINCT`, so that a second
+    /// row with NULL in the indexed columns collides with the first. Only available on Postgres 15+, other
+    /// backends ignore it. The index has to be [`unique`](Self::unique), Postgres panics otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-image")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Image)
+    ///     .unique()
+    ///     .nulls_not_distinct()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-glyph-image" ON "glyph" ("image") NULLS NOT DISTINCT"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-glyph-image" ON "glyph" ("image")"#
+    /// );
+    /// ```
     pub fn nulls_not_distinct(&mut self) -> &mut Self {
         self.nulls_not_distinct = true;
         self
     }
 
+    /// Add non-key columns to a covering index, i.e. `INCLUDE (...)`. Only available on Postgres,
+    /// other backends panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .include([Glyph::Image, Glyph::Tokens])
+    ///     .unique()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") INCLUDE ("image", "tokens")"#
+    /// );
+    /// ```
+    pub fn include<I, C>(&mut self, cols: I) -> &mut Self
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIden,
+    {
+        self.include_columns
+            .extend(cols.into_iter().map(IntoIden::into_iden));
+        self
+    }
+
     /// Set index as full text.
     /// On MySQL, this is `FULLTEXT`.
     /// On PgSQL, this is `GIN`.
@@ -286,6 +406,10 @@ impl IndexCreateStatement {
         &self.index
     }
 
+    pub fn get_include_columns(&self) -> &[DynIden] {
+        &self.include_columns
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
@@ -293,6 +417,7 @@ impl IndexCreateStatement {
             primary: self.primary,
             unique: self.unique,
             nulls_not_distinct: self.nulls_not_distinct,
+            include_columns: std::mem::take(&mut self.include_columns),
             index_type: self.index_type.take(),
             if_not_exists: self.if_not_exists,
             r#where: self.r#where.clone(),
@@ -314,6 +439,16 @@ impl SchemaStatementBuilder for IndexCreateStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_index_create_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
 
@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder, TableIndex};
+use crate::{backend::SchemaBuilder, types::*, SchemaStatementBuilder, SqlWriter, TableIndex};
 
 /// Drop an index for an existing table
 ///
@@ -78,5 +78,15 @@ impl SchemaStatementBuilder for IndexDropStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_index_drop_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
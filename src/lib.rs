@@ -52,7 +52,7 @@
 //! SQL engine: `backend-mysql`, `backend-postgres`, `backend-sqlite`
 //!
 //! Type support: `with-chrono`, `with-time`, `with-json`, `with-rust_decimal`, `with-bigdecimal`, `with-uuid`,
-//! `with-ipnetwork`, `with-mac_address`, `postgres-array`, `postgres-interval`
+//! `with-ipnetwork`, `with-mac_address`, `with-bit-vec`, `postgres-array`, `postgres-interval`
 //!
 //! ## Usage
 //!
@@ -7,6 +7,12 @@ pub trait SqlWriter: Write + ToString {
     fn push_param(&mut self, value: Value, query_builder: &dyn QueryBuilder);
 
     fn as_writer(&mut self) -> &mut dyn Write;
+
+    #[doc(hidden)]
+    /// The writer of quoted identifiers, which [`LowercaseIdenWriter`] folds to lowercase.
+    fn as_iden_writer(&mut self) -> &mut dyn Write {
+        self.as_writer()
+    }
 }
 
 impl SqlWriter for String {
@@ -115,6 +121,199 @@ where
     output.into_iter().collect()
 }
 
+/// Letter case of SQL keywords, see [`KeywordCaseWriter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordCase {
+    /// `SELECT`, as rendered by the builders
+    Upper,
+    /// `select`
+    Lower,
+}
+
+/// A [`SqlWriter`] writing the keywords of a statement in the given letter case.
+///
+/// Keywords and built-in function names are written in the given case.
+/// Identifiers, values, string literals such as comments, custom functions, types and operators,
+/// and raw SQL from [`Expr::cust`] or [`ColumnDef::extra`] are written as is.
+///
+/// Schema statements are written into it with [`SchemaStatementBuilder::build_collect_into`].
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let mut writer = KeywordCaseWriter::new(SqlWriterValues::new("$", true), KeywordCase::Lower);
+/// Query::select()
+///     .column(Char::Character)
+///     .from(Char::Table)
+///     .and_where(Expr::col(Char::Character).like("A%"))
+///     .and_where(Expr::col(Char::FontId).is_null())
+///     .and_where(Expr::cust("TRUE"))
+///     .build_collect_into(PostgresQueryBuilder, &mut writer);
+/// let (sql, values) = writer.into_inner().into_parts();
+///
+/// assert_eq!(
+///     sql,
+///     r#"select "character" from "character" where "character" like $1 and "font_id" is null and (TRUE)"#
+/// );
+/// assert_eq!(values, Values(vec!["A%".into()]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeywordCaseWriter<W> {
+    inner: W,
+    case: KeywordCase,
+}
+
+impl<W> KeywordCaseWriter<W>
+where
+    W: SqlWriter,
+{
+    pub fn new(inner: W, case: KeywordCase) -> Self {
+        Self { inner, case }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Write for KeywordCaseWriter<W>
+where
+    W: SqlWriter,
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        match self.case {
+            KeywordCase::Upper => self.inner.write_str(s),
+            KeywordCase::Lower => self.inner.write_str(&s.to_lowercase()),
+        }
+    }
+}
+
+impl<W> std::fmt::Display for KeywordCaseWriter<W>
+where
+    W: SqlWriter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner.to_string())
+    }
+}
+
+impl<W> SqlWriter for KeywordCaseWriter<W>
+where
+    W: SqlWriter,
+{
+    fn push_param(&mut self, value: Value, query_builder: &dyn QueryBuilder) {
+        self.inner.push_param(value, query_builder)
+    }
+
+    fn as_writer(&mut self) -> &mut dyn Write {
+        // Bypass the case conversion, for text which is not a keyword
+        self.inner.as_writer()
+    }
+
+    fn as_iden_writer(&mut self) -> &mut dyn Write {
+        self.inner.as_iden_writer()
+    }
+}
+
+/// A [`SqlWriter`] writing identifiers in lowercase, the way Postgres folds unquoted identifiers.
+///
+/// Table, column, alias and constraint names, derived [`Iden`]s included, are lowercased before quoting,
+/// so the same names refer to the same objects on every backend.
+/// Keywords, values and raw SQL are written as is.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let mut writer = LowercaseIdenWriter::new(SqlWriterValues::new("$", true));
+/// Query::select()
+///     .column(Alias::new("FontSize"))
+///     .from(Char::Table)
+///     .and_where(Expr::col(Char::Character).eq("A"))
+///     .build_collect_into(PostgresQueryBuilder, &mut writer);
+/// let (sql, values) = writer.into_inner().into_parts();
+///
+/// assert_eq!(
+///     sql,
+///     r#"SELECT "fontsize" FROM "character" WHERE "character" = $1"#
+/// );
+/// assert_eq!(values, Values(vec!["A".into()]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LowercaseIdenWriter<W> {
+    inner: Lowercase<W>,
+}
+
+#[derive(Debug, Clone)]
+struct Lowercase<W>(W);
+
+impl<W> LowercaseIdenWriter<W>
+where
+    W: SqlWriter,
+{
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Lowercase(inner),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner.0
+    }
+}
+
+impl<W> Write for LowercaseIdenWriter<W>
+where
+    W: SqlWriter,
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.0.write_str(s)
+    }
+}
+
+impl<W> Write for Lowercase<W>
+where
+    W: SqlWriter,
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let writer = self.0.as_iden_writer();
+        if s.chars().any(char::is_uppercase) {
+            writer.write_str(&s.to_lowercase())
+        } else {
+            writer.write_str(s)
+        }
+    }
+}
+
+impl<W> std::fmt::Display for LowercaseIdenWriter<W>
+where
+    W: SqlWriter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner.0.to_string())
+    }
+}
+
+impl<W> SqlWriter for LowercaseIdenWriter<W>
+where
+    W: SqlWriter,
+{
+    fn push_param(&mut self, value: Value, query_builder: &dyn QueryBuilder) {
+        self.inner.0.push_param(value, query_builder)
+    }
+
+    fn as_writer(&mut self) -> &mut dyn Write {
+        self.inner.0.as_writer()
+    }
+
+    fn as_iden_writer(&mut self) -> &mut dyn Write {
+        &mut self.inner
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "backend-mysql")]
 mod tests_mysql {
@@ -1,4 +1,6 @@
-use crate::{expr::SimpleExpr, types::LogicalChainOper};
+use crate::{
+    backend::QueryBuilder, expr::SimpleExpr, types::LogicalChainOper, SqlWriterValues, Values,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConditionType {
@@ -261,6 +263,97 @@ impl Condition {
         self.conditions.len()
     }
 
+    /// Build the condition alone, without the `WHERE` keyword, and collect query parameters into a vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let (sql, values) = Cond::all()
+    ///     .add(Expr::col(Glyph::Aspect).gt(2))
+    ///     .add(Cond::any().add(Expr::col(Glyph::Image).like("A%")).add(Expr::col(Glyph::Image).like("B%")))
+    ///     .build(PostgresQueryBuilder);
+    ///
+    /// assert_eq!(
+    ///     sql,
+    ///     r#""aspect" > $1 AND ("image" LIKE $2 OR "image" LIKE $3)"#
+    /// );
+    /// assert_eq!(
+    ///     values,
+    ///     Values(vec![2.into(), "A%".into(), "B%".into()])
+    /// );
+    /// ```
+    pub fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values) {
+        let (placeholder, numbered) = query_builder.placeholder();
+        let mut sql = SqlWriterValues::new(placeholder, numbered);
+        query_builder.prepare_condition_where(self, &mut sql);
+        sql.into_parts()
+    }
+
+    /// Build the condition alone, without the `WHERE` keyword, and return SQL string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let cond = Cond::all()
+    ///     .add(Expr::col(Glyph::Aspect).gt(2))
+    ///     .add(Expr::col(Glyph::Image).like("A%"));
+    ///
+    /// assert_eq!(
+    ///     cond.to_string(MysqlQueryBuilder),
+    ///     r#"`aspect` > 2 AND `image` LIKE 'A%'"#
+    /// );
+    /// assert_eq!(
+    ///     cond.to_string(PostgresQueryBuilder),
+    ///     r#""aspect" > 2 AND "image" LIKE 'A%'"#
+    /// );
+    /// assert_eq!(
+    ///     cond.to_string(SqliteQueryBuilder),
+    ///     r#""aspect" > 2 AND "image" LIKE 'A%'"#
+    /// );
+    /// ```
+    pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String {
+        let mut sql = String::with_capacity(256);
+        query_builder.prepare_condition_where(self, &mut sql);
+        sql
+    }
+
+    #[cfg(feature = "backend-postgres")]
+    pub(crate) fn visit_columns(&self, f: &mut dyn FnMut(&crate::ColumnRef)) {
+        for ce in &self.conditions {
+            match ce {
+                ConditionExpression::Condition(c) => c.visit_columns(f),
+                ConditionExpression::SimpleExpr(e) => e.visit_columns(f),
+            }
+        }
+    }
+
+    #[cfg(feature = "backend-postgres")]
+    pub(crate) fn replace_columns(
+        &self,
+        f: &mut dyn FnMut(&crate::ColumnRef) -> Option<SimpleExpr>,
+    ) -> Condition {
+        Condition {
+            negate: self.negate,
+            condition_type: self.condition_type.clone(),
+            conditions: self
+                .conditions
+                .iter()
+                .map(|ce| match ce {
+                    ConditionExpression::Condition(c) => {
+                        ConditionExpression::Condition(c.replace_columns(f))
+                    }
+                    ConditionExpression::SimpleExpr(e) => {
+                        ConditionExpression::SimpleExpr(e.replace_columns(f))
+                    }
+                })
+                .collect(),
+        }
+    }
+
     pub(crate) fn to_simple_expr(&self) -> SimpleExpr {
         let mut inner_exprs = vec![];
         for ce in &self.conditions {
@@ -638,6 +731,44 @@ impl ConditionHolder {
         }
     }
 
+    #[cfg(feature = "backend-postgres")]
+    pub(crate) fn visit_columns(&self, f: &mut dyn FnMut(&crate::ColumnRef)) {
+        match &self.contents {
+            ConditionHolderContents::Empty => {}
+            ConditionHolderContents::Chain(chain) => {
+                for oper in chain {
+                    match oper {
+                        LogicalChainOper::And(e) | LogicalChainOper::Or(e) => e.visit_columns(f),
+                    }
+                }
+            }
+            ConditionHolderContents::Condition(c) => c.visit_columns(f),
+        }
+    }
+
+    #[cfg(feature = "backend-postgres")]
+    pub(crate) fn replace_columns(
+        &self,
+        f: &mut dyn FnMut(&crate::ColumnRef) -> Option<SimpleExpr>,
+    ) -> ConditionHolder {
+        let contents = match &self.contents {
+            ConditionHolderContents::Empty => ConditionHolderContents::Empty,
+            ConditionHolderContents::Chain(chain) => ConditionHolderContents::Chain(
+                chain
+                    .iter()
+                    .map(|oper| match oper {
+                        LogicalChainOper::And(e) => LogicalChainOper::And(e.replace_columns(f)),
+                        LogicalChainOper::Or(e) => LogicalChainOper::Or(e.replace_columns(f)),
+                    })
+                    .collect(),
+            ),
+            ConditionHolderContents::Condition(c) => {
+                ConditionHolderContents::Condition(c.replace_columns(f))
+            }
+        };
+        Self { contents }
+    }
+
     pub fn add_condition(&mut self, mut addition: Condition) {
         match std::mem::take(&mut self.contents) {
             ConditionHolderContents::Empty => {
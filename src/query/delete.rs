@@ -4,8 +4,8 @@ use crate::{
     query::{condition::*, OrderedStatement},
     types::*,
     value::*,
-    QueryStatementBuilder, QueryStatementWriter, ReturningClause, SimpleExpr, SubQueryStatement,
-    WithClause, WithQuery,
+    QueryStatementBuilder, QueryStatementWriter, ReturningClause, SimpleExpr, StatementApply,
+    SubQueryStatement, WithClause, WithQuery,
 };
 use inherent::inherent;
 
@@ -40,6 +40,7 @@ use inherent::inherent;
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) using: Vec<TableRef>,
     pub(crate) r#where: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
@@ -86,7 +87,71 @@ impl DeleteStatement {
         self
     }
 
-    /// Limit number of updated rows.
+    /// Delete rows matching other tables, which are correlated with the deleted table in `WHERE`.
+    ///
+    /// Postgres renders `DELETE FROM t USING other`, while MySQL renders the multi-table form
+    /// `DELETE FROM t USING t, other`, which doesn't allow `ORDER BY` and `LIMIT`.
+    /// SQLite doesn't support it, use a sub query in `WHERE` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Char::Table)
+    ///     .using(Font::Table)
+    ///     .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+    ///     .and_where(Expr::col((Font::Table, Font::Name)).eq("Comic Sans"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `character` USING `character`, `font` WHERE `character`.`font_id` = `font`.`id` AND `font`.`name` = 'Comic Sans'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "character" USING "font" WHERE "character"."font_id" = "font"."id" AND "font"."name" = 'Comic Sans'"#
+    /// );
+    /// ```
+    pub fn using<T>(&mut self, tbl_ref: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.using.push(tbl_ref.into_table_ref());
+        self
+    }
+
+    /// Limit number of deleted rows, usually together with `ORDER BY` to delete in batches.
+    ///
+    /// Every query builder writes the `LIMIT` clause, but only MySQL accepts it on `DELETE`,
+    /// and SQLite when compiled with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`.
+    /// Postgres rejects the statement; select the keys to delete in a sub query instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).lt(0))
+    ///     .order_by(Glyph::Id, Order::Asc)
+    ///     .limit(100)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `aspect` < 0 ORDER BY `id` ASC LIMIT 100"#
+    /// );
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"DELETE FROM `glyph` WHERE `aspect` < ? ORDER BY `id` ASC LIMIT ?"#.to_owned(),
+    ///         Values(vec![0i32.into(), 100u64.into()])
+    ///     )
+    /// );
+    /// ```
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(limit.into());
         self
@@ -186,6 +251,69 @@ impl DeleteStatement {
         self.returning(ReturningClause::All)
     }
 
+    /// Append an expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).eq("12A"))
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr(Expr::col(Glyph::Aspect).is_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "image" = '12A' RETURNING "id", "aspect" IS NULL"#
+    /// );
+    /// ```
+    pub fn returning_expr<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            None,
+        ));
+        self
+    }
+
+    /// Append an aliased expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).eq("12A"))
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr_as(Func::cust(Alias::new("now")), Alias::new("ts"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "image" = '12A' RETURNING "id", now() AS "ts""#
+    /// );
+    /// ```
+    pub fn returning_expr_as<T, A>(&mut self, expr: T, alias: A) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            Some(alias.into_iden()),
+        ));
+        self
+    }
+
     /// Create a [WithQuery] by specifying a [WithClause] to execute this query with.
     ///
     /// # Examples
@@ -265,6 +393,16 @@ impl QueryStatementWriter for DeleteStatement {
     pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
 }
 
+#[inherent]
+impl StatementApply for DeleteStatement {
+    pub fn apply<F>(&mut self, func: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self);
+    pub fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self, T);
+}
+
 #[inherent]
 impl OrderedStatement for DeleteStatement {
     pub fn add_order_by(&mut self, order: OrderExpr) -> &mut Self {
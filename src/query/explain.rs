@@ -0,0 +1,99 @@
+use crate::{
+    backend::QueryBuilder, prepare::*, value::*, QueryStatementBuilder, QueryStatementWriter,
+    SubQueryStatement,
+};
+use inherent::inherent;
+
+/// Show the execution plan of a query, e.g. to read the estimated row count
+///
+/// Postgres renders `EXPLAIN (FORMAT JSON)`, MySQL renders `EXPLAIN FORMAT=JSON`
+/// and SQLite renders `EXPLAIN QUERY PLAN`.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let query = Query::select()
+///     .column(Char::Id)
+///     .from(Char::Table)
+///     .and_where(Expr::col(Char::SizeW).gt(10))
+///     .to_owned()
+///     .explain();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"EXPLAIN FORMAT=JSON SELECT `id` FROM `character` WHERE `size_w` > 10"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"EXPLAIN (FORMAT JSON) SELECT "id" FROM "character" WHERE "size_w" > 10"#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"EXPLAIN QUERY PLAN SELECT "id" FROM "character" WHERE "size_w" > 10"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainQuery {
+    pub(crate) analyze: bool,
+    pub(crate) query: Box<SubQueryStatement>,
+}
+
+impl ExplainQuery {
+    /// Construct a new [`ExplainQuery`] of the given query
+    pub fn new<Q>(query: Q) -> Self
+    where
+        Q: QueryStatementBuilder,
+    {
+        Self {
+            analyze: false,
+            query: Box::new(query.into_sub_query_statement()),
+        }
+    }
+
+    /// Execute the query to show the actual run time and row counts.
+    /// Postgres renders `EXPLAIN (ANALYZE, FORMAT JSON)` and MySQL renders `EXPLAIN ANALYZE`,
+    /// SQLite doesn't support it.
+    pub fn analyze(&mut self) -> &mut Self {
+        self.analyze = true;
+        self
+    }
+}
+
+#[inherent]
+impl QueryStatementBuilder for ExplainQuery {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.prepare_explain_query(self, sql);
+    }
+
+    pub fn into_sub_query_statement(self) -> SubQueryStatement {
+        SubQueryStatement::ExplainStatement(self)
+    }
+
+    pub fn build_any(&self, query_builder: &dyn QueryBuilder) -> (String, Values);
+    pub fn build_collect_any(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+}
+
+#[inherent]
+impl QueryStatementWriter for ExplainQuery {
+    pub fn build_collect_into<T: QueryBuilder>(&self, query_builder: T, sql: &mut dyn SqlWriter) {
+        query_builder.prepare_explain_query(self, sql);
+    }
+
+    pub fn build_collect<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values);
+    pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
+}
@@ -1,7 +1,7 @@
 use crate::{
     backend::QueryBuilder, error::*, prepare::*, types::*, OnConflict, QueryStatementBuilder,
-    QueryStatementWriter, ReturningClause, SelectStatement, SimpleExpr, SubQueryStatement, Values,
-    WithClause, WithQuery,
+    QueryStatementWriter, ReturningClause, SelectStatement, SimpleExpr, StatementApply,
+    SubQueryStatement, Value, Values, WithClause, WithQuery,
 };
 use inherent::inherent;
 
@@ -117,6 +117,10 @@ impl InsertStatement {
 
     /// Specify a select query whose values to be inserted.
     ///
+    /// Returns [`Error::ColValNumMismatch`] if the number of selected expressions differs from
+    /// the number of columns set by [`InsertStatement::columns`]. The parameters of the select
+    /// are collected in order into the [`Values`] of the insert statement.
+    ///
     /// # Examples
     ///
     /// ```
@@ -278,6 +282,38 @@ impl InsertStatement {
         self.values(values).unwrap()
     }
 
+    /// Specify a row of already converted values to be inserted, variation of [`InsertStatement::values`].
+    /// Returns [`Error::ColValNumMismatch`] instead of panicking if the row doesn't match the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::*, tests_cfg::*, *};
+    ///
+    /// let mut query = Query::insert();
+    /// query
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Aspect, Glyph::Image]);
+    ///
+    /// let row: Vec<Value> = vec![2.1345.into(), "24B".into()];
+    /// assert!(query.values_from_vec(row).is_ok());
+    /// assert_eq!(
+    ///     query.values_from_vec(vec![5.15.into()]).unwrap_err(),
+    ///     Error::ColValNumMismatch {
+    ///         col_len: 2,
+    ///         val_len: 1,
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("aspect", "image") VALUES (2.1345, '24B')"#
+    /// );
+    /// ```
+    pub fn values_from_vec(&mut self, values: Vec<Value>) -> Result<&mut Self> {
+        self.values(values.into_iter().map(SimpleExpr::Value))
+    }
+
     /// Add rows to be inserted from an iterator, variation of [`InsertStatement::values_panic`].
     ///
     /// # Examples
@@ -362,6 +398,8 @@ impl InsertStatement {
 
     /// RETURNING expressions for a column.
     ///
+    /// MySQL does not support `RETURNING`, use [`InsertStatement::try_build`] to detect it at build time.
+    ///
     /// # Examples
     ///
     /// ```
@@ -425,6 +463,71 @@ impl InsertStatement {
         self.returning(ReturningClause::All)
     }
 
+    /// Append an expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Image])
+    ///     .values_panic(["12A".into()])
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr(Expr::col(Glyph::Aspect).is_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("image") VALUES ('12A') RETURNING "id", "aspect" IS NULL"#
+    /// );
+    /// ```
+    pub fn returning_expr<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            None,
+        ));
+        self
+    }
+
+    /// Append an aliased expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Image])
+    ///     .values_panic(["12A".into()])
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr_as(Func::cust(Alias::new("now")), Alias::new("ts"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("image") VALUES ('12A') RETURNING "id", now() AS "ts""#
+    /// );
+    /// ```
+    pub fn returning_expr_as<T, A>(&mut self, expr: T, alias: A) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            Some(alias.into_iden()),
+        ));
+        self
+    }
+
     /// Create a [WithQuery] by specifying a [WithClause] to execute this query with.
     ///
     /// # Examples
@@ -519,6 +622,22 @@ impl InsertStatement {
     ///     query.to_string(SqliteQueryBuilder),
     ///     r#"INSERT INTO "glyph" ("image") VALUES ('ABC')"#
     /// );
+    ///
+    /// // Placeholder row, returning its id
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .or_default_values()
+    ///     .returning_col(Glyph::Id)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" VALUES (DEFAULT) RETURNING "id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO "glyph" DEFAULT VALUES RETURNING "id""#
+    /// );
     /// ```
     pub fn or_default_values(&mut self) -> &mut Self {
         self.default_values = Some(1);
@@ -576,6 +695,144 @@ impl InsertStatement {
         self.default_values = Some(num_rows);
         self
     }
+
+    /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector,
+    /// returning an error if the statement has a `RETURNING` clause that the backend cannot honour.
+    ///
+    /// MySQL has no `RETURNING`, and [`InsertStatement::build`] silently omits it there.
+    /// For a single-row insert, use `last_insert_id` of the database driver to get the auto increment primary key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Image])
+    ///     .values_panic(["12A".into()])
+    ///     .returning_col(Glyph::Id)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(MysqlQueryBuilder),
+    ///     Err(Error::ReturningUnsupported)
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Ok((
+    ///         r#"INSERT INTO "glyph" ("image") VALUES ($1) RETURNING "id""#.to_owned(),
+    ///         Values(vec!["12A".into()])
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(SqliteQueryBuilder),
+    ///     Ok((
+    ///         r#"INSERT INTO "glyph" ("image") VALUES (?) RETURNING "id""#.to_owned(),
+    ///         Values(vec!["12A".into()])
+    ///     ))
+    /// );
+    /// ```
+    pub fn try_build<T: QueryBuilder>(&self, query_builder: T) -> Result<(String, Values)> {
+        if self.returning.is_some() && !query_builder.supports_returning() {
+            return Err(Error::ReturningUnsupported);
+        }
+        Ok(self.build(query_builder))
+    }
+
+    /// Build the statement as several statements, splitting the value rows so that each statement
+    /// binds at most `max_params` query parameters, e.g. to stay under the 65535 limit of Postgres.
+    ///
+    /// Columns, `ON CONFLICT` and `RETURNING` are repeated in every statement.
+    /// A single row binding more than `max_params` parameters is still emitted on its own.
+    /// Statements not inserting from a list of values are built as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let chunks = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Aspect, Glyph::Image])
+    ///     .values_panic([5.15.into(), "12A".into()])
+    ///     .values_panic([4.21.into(), "123".into()])
+    ///     .values_panic([3.11.into(), "ABC".into()])
+    ///     .to_owned()
+    ///     .build_chunked(PostgresQueryBuilder, 4);
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     [
+    ///         (
+    ///             r#"INSERT INTO "glyph" ("aspect", "image") VALUES ($1, $2), ($3, $4)"#.to_owned(),
+    ///             Values(vec![5.15.into(), "12A".into(), 4.21.into(), "123".into()])
+    ///         ),
+    ///         (
+    ///             r#"INSERT INTO "glyph" ("aspect", "image") VALUES ($1, $2)"#.to_owned(),
+    ///             Values(vec![3.11.into(), "ABC".into()])
+    ///         ),
+    ///     ]
+    /// );
+    /// ```
+    pub fn build_chunked<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        max_params: usize,
+    ) -> Vec<(String, Values)> {
+        let rows = match &self.source {
+            Some(InsertValueSource::Values(rows)) if !rows.is_empty() => rows,
+            _ => return vec![self.build(query_builder)],
+        };
+
+        let (placeholder, numbered) = query_builder.placeholder();
+        let row_params: Vec<usize> = rows
+            .iter()
+            .map(|row| {
+                let mut sql = SqlWriterValues::new(placeholder, numbered);
+                for expr in row {
+                    query_builder.prepare_simple_expr(expr, &mut sql);
+                }
+                let (_, values) = sql.into_parts();
+                values.0.len()
+            })
+            .collect();
+
+        let mut chunk = Self {
+            source: Some(InsertValueSource::Values(vec![rows[0].clone()])),
+            ..self.clone_without_source()
+        };
+        // Parameters outside of the values list, repeated in every chunk
+        let (_, values) = chunk.build_any(&query_builder);
+        let fixed_params = values.0.len() - row_params[0];
+
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < rows.len() {
+            let mut end = start + 1;
+            let mut params = fixed_params + row_params[start];
+            while end < rows.len() && params + row_params[end] <= max_params {
+                params += row_params[end];
+                end += 1;
+            }
+            chunk.source = Some(InsertValueSource::Values(rows[start..end].to_vec()));
+            chunks.push(chunk.build_any(&query_builder));
+            start = end;
+        }
+        chunks
+    }
+
+    fn clone_without_source(&self) -> Self {
+        Self {
+            replace: self.replace,
+            table: self.table.clone(),
+            columns: self.columns.clone(),
+            source: None,
+            on_conflict: self.on_conflict.clone(),
+            returning: self.returning.clone(),
+            default_values: self.default_values,
+        }
+    }
 }
 
 #[inherent]
@@ -614,3 +871,13 @@ impl QueryStatementWriter for InsertStatement {
     pub fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values);
     pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
 }
+
+#[inherent]
+impl StatementApply for InsertStatement {
+    pub fn apply<F>(&mut self, func: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self);
+    pub fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self, T);
+}
@@ -0,0 +1,232 @@
+use crate::{
+    backend::QueryBuilder, prepare::*, query::condition::*, types::*, value::*, OnConflictUpdate,
+    QueryStatementBuilder, QueryStatementWriter, SimpleExpr, SubQueryStatement,
+};
+use inherent::inherent;
+
+/// Merge rows of a source into the target table
+///
+/// MERGE is supported by Postgres 15+ only.
+/// MySQL users should build an [`InsertStatement`](crate::InsertStatement) with [`OnConflict`](crate::OnConflict) instead.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let query = Query::merge()
+///     .into_table(Glyph::Table)
+///     .using(Alias::new("staging").into_table_ref().alias(Alias::new("s")))
+///     .on(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("s"), Glyph::Id)))
+///     .when_matched_then_update_columns([Glyph::Aspect, Glyph::Image])
+///     .when_not_matched_then_insert_columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     [
+///         r#"MERGE INTO "glyph" USING "staging" AS "s" ON "glyph"."id" = "s"."id""#,
+///         r#"WHEN MATCHED THEN UPDATE SET "aspect" = "s"."aspect", "image" = "s"."image""#,
+///         r#"WHEN NOT MATCHED THEN INSERT ("id", "aspect", "image") VALUES ("s"."id", "s"."aspect", "s"."image")"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct MergeStatement {
+    pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) using: Option<Box<TableRef>>,
+    pub(crate) on: ConditionHolder,
+    pub(crate) when_matched: Vec<OnConflictUpdate>,
+    pub(crate) when_not_matched: Vec<OnConflictUpdate>,
+}
+
+impl MergeStatement {
+    /// Construct a new [`MergeStatement`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specify which table to merge into.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_table<T>(&mut self, tbl_ref: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(Box::new(tbl_ref.into_table_ref()));
+        self
+    }
+
+    /// Specify the source rows to merge, a table or a [`TableRef::SubQuery`].
+    pub fn using<T>(&mut self, tbl_ref: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.using = Some(Box::new(tbl_ref.into_table_ref()));
+        self
+    }
+
+    /// Condition matching the source rows with the rows of the target table.
+    /// Calling it multiple times joins the conditions with `AND`.
+    pub fn on<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.on.add_condition(condition.into_condition());
+        self
+    }
+
+    /// Update the matched rows with the value of the same column from the source.
+    pub fn when_matched_then_update_columns<C, I>(&mut self, columns: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.when_matched.extend(
+            columns
+                .into_iter()
+                .map(|c| OnConflictUpdate::Column(c.into_iden())),
+        );
+        self
+    }
+
+    /// Update the matched rows with the given expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::merge()
+    ///     .into_table(Glyph::Table)
+    ///     .using(Alias::new("staging"))
+    ///     .on(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("staging"), Glyph::Id)))
+    ///     .when_matched_then_update([(
+    ///         Glyph::Aspect,
+    ///         Expr::col((Glyph::Table, Glyph::Aspect)).add(1),
+    ///     )])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         [
+    ///             r#"MERGE INTO "glyph" USING "staging" ON "glyph"."id" = "staging"."id""#,
+    ///             r#"WHEN MATCHED THEN UPDATE SET "aspect" = "glyph"."aspect" + $1"#,
+    ///         ]
+    ///         .join(" "),
+    ///         Values(vec![1.into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn when_matched_then_update<C, I>(&mut self, values: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = (C, SimpleExpr)>,
+    {
+        self.when_matched.extend(
+            values
+                .into_iter()
+                .map(|(c, e)| OnConflictUpdate::Expr(c.into_iden(), e)),
+        );
+        self
+    }
+
+    /// Insert the unmatched rows with the value of the same column from the source.
+    pub fn when_not_matched_then_insert_columns<C, I>(&mut self, columns: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.when_not_matched.extend(
+            columns
+                .into_iter()
+                .map(|c| OnConflictUpdate::Column(c.into_iden())),
+        );
+        self
+    }
+
+    /// Insert the unmatched rows with the given expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::merge()
+    ///     .into_table(Glyph::Table)
+    ///     .using(Alias::new("staging").into_table_ref().alias(Alias::new("s")))
+    ///     .on(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("s"), Glyph::Id)))
+    ///     .when_not_matched_then_insert([
+    ///         (Glyph::Id, Expr::col((Alias::new("s"), Glyph::Id)).into()),
+    ///         (Glyph::Image, "new".into()),
+    ///     ])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         [
+    ///             r#"MERGE INTO "glyph" USING "staging" AS "s" ON "glyph"."id" = "s"."id""#,
+    ///             r#"WHEN NOT MATCHED THEN INSERT ("id", "image") VALUES ("s"."id", $1)"#,
+    ///         ]
+    ///         .join(" "),
+    ///         Values(vec!["new".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn when_not_matched_then_insert<C, I>(&mut self, values: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = (C, SimpleExpr)>,
+    {
+        self.when_not_matched.extend(
+            values
+                .into_iter()
+                .map(|(c, e)| OnConflictUpdate::Expr(c.into_iden(), e)),
+        );
+        self
+    }
+
+    /// The name the source rows are referred by, i.e. the alias or the table name.
+    pub(crate) fn source_name(&self) -> Option<&DynIden> {
+        self.using.as_deref().map(TableRef::alias_or_name)
+    }
+}
+
+#[inherent]
+impl QueryStatementBuilder for MergeStatement {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.prepare_merge_statement(self, sql);
+    }
+
+    pub fn into_sub_query_statement(self) -> SubQueryStatement {
+        SubQueryStatement::MergeStatement(self)
+    }
+
+    pub fn build_any(&self, query_builder: &dyn QueryBuilder) -> (String, Values);
+    pub fn build_collect_any(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+}
+
+#[inherent]
+impl QueryStatementWriter for MergeStatement {
+    pub fn build_collect_into<T: QueryBuilder>(&self, query_builder: T, sql: &mut dyn SqlWriter) {
+        query_builder.prepare_merge_statement(self, sql);
+    }
+
+    pub fn build_collect<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values);
+    pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
+}
@@ -6,11 +6,15 @@
 //! - Query Insert, see [`InsertStatement`]
 //! - Query Update, see [`UpdateStatement`]
 //! - Query Delete, see [`DeleteStatement`]
+//! - Query Merge, see [`MergeStatement`]
+//! - Query Explain, see [`ExplainQuery`]
 
 mod case;
 mod condition;
 mod delete;
+mod explain;
 mod insert;
+mod merge;
 mod on_conflict;
 mod ordered;
 mod returning;
@@ -20,10 +24,14 @@ mod update;
 mod window;
 mod with;
 
+use crate::{IntoIden, IntoValueTuple, TableRef, ValueTuple};
+
 pub use case::*;
 pub use condition::*;
 pub use delete::*;
+pub use explain::*;
 pub use insert::*;
+pub use merge::*;
 pub use on_conflict::*;
 pub use ordered::*;
 pub use returning::*;
@@ -44,6 +52,7 @@ pub enum QueryStatement {
     Insert(InsertStatement),
     Update(UpdateStatement),
     Delete(DeleteStatement),
+    Merge(MergeStatement),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -52,7 +61,9 @@ pub enum SubQueryStatement {
     InsertStatement(InsertStatement),
     UpdateStatement(UpdateStatement),
     DeleteStatement(DeleteStatement),
+    MergeStatement(MergeStatement),
     WithStatement(WithQuery),
+    ExplainStatement(ExplainQuery),
 }
 
 impl Query {
@@ -76,6 +87,71 @@ impl Query {
         DeleteStatement::new()
     }
 
+    /// Construct table [`MergeStatement`]
+    pub fn merge() -> MergeStatement {
+        MergeStatement::new()
+    }
+
+    /// Construct a [`TableRef::ValuesListColumns`] of constant rows with an alias and column names,
+    /// to select from or join with.
+    ///
+    /// Sqlite doesn't support column names in the alias, so the values list is wrapped in a
+    /// subquery naming its columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value_tuples` is empty, as SQL has no empty `VALUES` list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([(Alias::new("t"), Alias::new("id")), (Alias::new("t"), Alias::new("name"))])
+    ///     .from(Query::values_list(
+    ///         [(1, "hello"), (2, "world")],
+    ///         Alias::new("t"),
+    ///         [Alias::new("id"), Alias::new("name")],
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `t`.`id`, `t`.`name` FROM (VALUES ROW(1, 'hello'), ROW(2, 'world')) AS `t`(`id`, `name`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "t"."id", "t"."name" FROM (VALUES (1, 'hello'), (2, 'world')) AS "t"("id", "name")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "t"."id", "t"."name" FROM (SELECT "column1" AS "id", "column2" AS "name" FROM (VALUES (1, 'hello'), (2, 'world'))) AS "t""#
+    /// );
+    /// ```
+    pub fn values_list<I, V, A, C, N>(value_tuples: I, alias: A, columns: C) -> TableRef
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoValueTuple,
+        A: IntoIden,
+        C: IntoIterator<Item = N>,
+        N: IntoIden,
+    {
+        let value_tuples: Vec<ValueTuple> = value_tuples
+            .into_iter()
+            .map(|vt| vt.into_value_tuple())
+            .collect();
+        assert!(
+            !value_tuples.is_empty(),
+            "Query::values_list requires at least one row"
+        );
+        TableRef::ValuesListColumns(
+            value_tuples,
+            alias.into_iden(),
+            columns.into_iter().map(|c| c.into_iden()).collect(),
+        )
+    }
+
     /// Construct [`WithClause`]
     pub fn with() -> WithClause {
         WithClause::new()
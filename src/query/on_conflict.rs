@@ -424,7 +424,8 @@ impl OnConflict {
         self.values([(col, value.into())])
     }
 
-    /// Set target WHERE
+    /// Set target WHERE, the predicate of a partial unique index to infer as the conflict target.
+    /// This is distinct from the action WHERE, which filters the rows to be updated.
     ///
     /// # Examples
     ///
@@ -480,7 +481,9 @@ impl OnConflict {
         self
     }
 
-    /// Set action WHERE
+    /// Set action WHERE, the condition for a conflicting row to be updated,
+    /// e.g. only when the inserted row is newer.
+    /// MySQL has no equivalent for `ON DUPLICATE KEY UPDATE` and ignores it.
     ///
     /// # Examples
     ///
@@ -1,4 +1,4 @@
-use crate::{ColumnRef, IntoColumnRef, SimpleExpr};
+use crate::{ColumnRef, DynIden, IntoColumnRef, IntoIden, SimpleExpr};
 
 /// RETURNING clause.
 /// ## Note:
@@ -12,6 +12,30 @@ pub enum ReturningClause {
     All,
     Columns(Vec<ColumnRef>),
     Exprs(Vec<SimpleExpr>),
+    /// Expressions, each with an optional `AS` alias
+    ExprsAs(Vec<(SimpleExpr, Option<DynIden>)>),
+}
+
+impl ReturningClause {
+    /// Append an expression to what is returned already
+    pub(crate) fn append_expr(
+        returning: Option<Self>,
+        expr: SimpleExpr,
+        alias: Option<DynIden>,
+    ) -> Self {
+        let mut exprs = match returning {
+            None => Vec::new(),
+            Some(Self::All) => vec![(SimpleExpr::Column(ColumnRef::Asterisk), None)],
+            Some(Self::Columns(cols)) => cols
+                .into_iter()
+                .map(|col| (SimpleExpr::Column(col), None))
+                .collect(),
+            Some(Self::Exprs(exprs)) => exprs.into_iter().map(|expr| (expr, None)).collect(),
+            Some(Self::ExprsAs(exprs)) => exprs,
+        };
+        exprs.push((expr, alias));
+        Self::ExprsAs(exprs)
+    }
 }
 
 /// Shorthand for constructing [`ReturningClause`]
@@ -168,4 +192,34 @@ impl Returning {
     {
         ReturningClause::Exprs(exprs.into_iter().map(Into::into).collect())
     }
+
+    /// Constructs a new [`ReturningClause::ExprsAs`] with an aliased expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Id).eq(1))
+    ///     .returning(Query::returning().expr_as(Expr::col(Glyph::Aspect).mul(2), Alias::new("double")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING "aspect" * 2 AS "double""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING "aspect" * 2 AS "double""#
+    /// );
+    /// ```
+    pub fn expr_as<T, A>(&self, expr: T, alias: A) -> ReturningClause
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        ReturningClause::ExprsAs(vec![(expr.into(), Some(alias.into_iden()))])
+    }
 }
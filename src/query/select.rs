@@ -1,12 +1,13 @@
 use crate::{
     backend::QueryBuilder,
+    error::*,
     expr::*,
     prepare::*,
     query::{condition::*, OrderedStatement},
     types::*,
     value::*,
-    FunctionCall, QueryStatementBuilder, QueryStatementWriter, SubQueryStatement, WindowStatement,
-    WithClause, WithQuery,
+    ExplainQuery, Func, FunctionCall, QueryStatementBuilder, QueryStatementWriter, StatementApply,
+    SubQueryStatement, WindowStatement, WithClause, WithQuery,
 };
 use inherent::inherent;
 
@@ -46,14 +47,19 @@ pub struct SelectStatement {
     pub(crate) from: Vec<TableRef>,
     pub(crate) join: Vec<JoinExpr>,
     pub(crate) r#where: ConditionHolder,
-    pub(crate) groups: Vec<SimpleExpr>,
+    /// Set for the expressions added by `group_by_expr`, which may refer to the select list
+    pub(crate) groups: Vec<(SimpleExpr, bool)>,
+    pub(crate) grouping_sets: Vec<GroupingSet>,
     pub(crate) having: ConditionHolder,
     pub(crate) unions: Vec<(UnionType, SelectStatement)>,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
     pub(crate) offset: Option<Value>,
+    /// Set if `limit` is rendered as `FETCH FIRST`, to whether rows tied with the last one are included
+    pub(crate) fetch: Option<bool>,
     pub(crate) lock: Option<LockClause>,
-    pub(crate) window: Option<(DynIden, WindowStatement)>,
+    pub(crate) windows: Vec<(DynIden, WindowStatement)>,
+    pub(crate) raw_tail: Vec<SimpleExpr>,
     #[cfg(feature = "backend-mysql")]
     pub(crate) index_hints: Vec<crate::extension::mysql::IndexHint>,
 }
@@ -67,6 +73,17 @@ pub enum SelectDistinct {
     DistinctOn(Vec<ColumnRef>),
 }
 
+/// Grouping of rows by multiple grouping sets in a single GROUP BY
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupingSet {
+    /// `ROLLUP (a, b)`, i.e. the sets `(a, b)`, `(a)` and `()`
+    Rollup(Vec<SimpleExpr>),
+    /// `CUBE (a, b)`, i.e. all the subsets of `(a, b)`
+    Cube(Vec<SimpleExpr>),
+    /// `GROUPING SETS ((a, b), (a), ())`
+    Sets(Vec<Vec<SimpleExpr>>),
+}
+
 /// Window type in [`SelectExpr`]
 #[derive(Debug, Clone, PartialEq)]
 pub enum WindowSelectType {
@@ -98,16 +115,20 @@ pub struct JoinExpr {
 pub enum LockType {
     /// Exclusive lock
     Update,
+    /// Exclusive lock that doesn't block `KeyShare`, Postgres only
     NoKeyUpdate,
     /// Shared lock
     Share,
+    /// Shared lock that only blocks `Update`, Postgres only
     KeyShare,
 }
 
 /// List of lock behavior can be used in select statement
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LockBehavior {
+    /// Fail instead of waiting for the locked rows
     Nowait,
+    /// Skip the locked rows
     SkipLocked,
 }
 
@@ -119,12 +140,26 @@ pub struct LockClause {
 }
 
 /// List of union types that can be used in union clause
+///
+/// Except for the `ALL` variants, set operations remove duplicate rows.
+/// Unlike `=`, set operations compare rows as `IS NOT DISTINCT FROM`, so `NULL` matches `NULL`,
+/// which makes `EXCEPT` suitable for diffing two snapshots of a table with nullable columns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnionType {
+    /// `INTERSECT`, distinct rows present in both queries
     Intersect,
+    /// `UNION`, distinct rows of both queries
     Distinct,
+    /// `EXCEPT`, distinct rows of the first query not present in the second
     Except,
+    /// `UNION ALL`, all rows of both queries
     All,
+    /// `INTERSECT ALL`, keeping duplicates as many times as present in both queries.
+    /// Not supported by SQLite.
+    IntersectAll,
+    /// `EXCEPT ALL`, keeping duplicates as many times as more present in the first query.
+    /// Not supported by SQLite.
+    ExceptAll,
 }
 
 impl<T> From<T> for SelectExpr
@@ -155,13 +190,16 @@ impl SelectStatement {
             join: std::mem::take(&mut self.join),
             r#where: std::mem::replace(&mut self.r#where, ConditionHolder::new()),
             groups: std::mem::take(&mut self.groups),
+            grouping_sets: std::mem::take(&mut self.grouping_sets),
             having: std::mem::replace(&mut self.having, ConditionHolder::new()),
             unions: std::mem::take(&mut self.unions),
             orders: std::mem::take(&mut self.orders),
             limit: self.limit.take(),
             offset: self.offset.take(),
+            fetch: self.fetch.take(),
             lock: self.lock.take(),
-            window: self.window.take(),
+            windows: std::mem::take(&mut self.windows),
+            raw_tail: std::mem::take(&mut self.raw_tail),
             #[cfg(feature = "backend-mysql")]
             index_hints: std::mem::take(&mut self.index_hints),
         }
@@ -375,7 +413,28 @@ impl SelectStatement {
         self
     }
 
-    /// Select distinct on for *POSTGRES ONLY*
+    /// Select distinct on for *POSTGRES ONLY*, MySQL and SQLite don't support it and ignore it.
+    ///
+    /// Postgres keeps the first row of each group, and requires the `DISTINCT ON` expressions
+    /// to be the leading `ORDER BY` keys. Order by the rest to choose which row is kept,
+    /// e.g. the latest row per group:
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .distinct_on([Char::FontId])
+    ///     .columns([Char::FontId, Char::Id, Char::Character])
+    ///     .from(Char::Table)
+    ///     .order_by(Char::FontId, Order::Asc)
+    ///     .order_by(Char::CreatedAt, Order::Desc)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DISTINCT ON ("font_id") "font_id", "id", "character" FROM "character" ORDER BY "font_id" ASC, "created_at" DESC"#
+    /// )
+    /// ```
     ///
     /// # Examples
     ///
@@ -726,15 +785,15 @@ impl SelectStatement {
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character` OVER `w` FROM `character` WINDOW `w` AS PARTITION BY `font_size`"#
+    ///     r#"SELECT `character` OVER `w` FROM `character` WINDOW `w` AS (PARTITION BY `font_size`)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// ```
     pub fn expr_window_name<T, W>(&mut self, expr: T, window: W) -> &mut Self
@@ -765,15 +824,15 @@ impl SelectStatement {
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character` OVER `w` AS `C` FROM `character` WINDOW `w` AS PARTITION BY `font_size`"#
+    ///     r#"SELECT `character` OVER `w` AS `C` FROM `character` WINDOW `w` AS (PARTITION BY `font_size`)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// ```
     pub fn expr_window_name_as<T, W, A>(&mut self, expr: T, window: W, alias: A) -> &mut Self
@@ -1224,6 +1283,8 @@ impl SelectStatement {
 
     /// Right join.
     ///
+    /// SQLite supports `RIGHT JOIN` since 3.39.0; older versions will reject the statement.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1348,6 +1409,9 @@ impl SelectStatement {
 
     /// Full outer join.
     ///
+    /// SQLite supports `FULL OUTER JOIN` since 3.39.0; older versions will reject the statement.
+    /// MySQL has no `FULL OUTER JOIN`, building the statement with `MysqlQueryBuilder` panics.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1678,6 +1742,57 @@ impl SelectStatement {
         )
     }
 
+    /// Cross join lateral with sub-query, without any join condition. Not supported by SQLite.
+    ///
+    /// This is the building block of a top-N-per-group query: the correlated sub-query
+    /// can refer to the columns of the outer query, and is evaluated once per outer row.
+    ///
+    /// # Examples
+    ///
+    /// Select the 3 widest characters of each font:
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let top_char: DynIden = SeaRc::new(Alias::new("top_char"));
+    /// let query = Query::select()
+    ///     .column((Font::Table, Font::Name))
+    ///     .column((top_char.clone(), Char::Character))
+    ///     .from(Font::Table)
+    ///     .cross_join_lateral(
+    ///         Query::select()
+    ///             .column(Char::Character)
+    ///             .from(Char::Table)
+    ///             .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+    ///             .order_by(Char::SizeW, Order::Desc)
+    ///             .limit(3)
+    ///             .take(),
+    ///         top_char.clone(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font`.`name`, `top_char`.`character` FROM `font` CROSS JOIN LATERAL (SELECT `character` FROM `character` WHERE `character`.`font_id` = `font`.`id` ORDER BY `size_w` DESC LIMIT 3) AS `top_char`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font"."name", "top_char"."character" FROM "font" CROSS JOIN LATERAL (SELECT "character" FROM "character" WHERE "character"."font_id" = "font"."id" ORDER BY "size_w" DESC LIMIT 3) AS "top_char""#
+    /// );
+    /// ```
+    pub fn cross_join_lateral<T>(&mut self, query: SelectStatement, alias: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.join.push(JoinExpr {
+            join: JoinType::CrossJoin,
+            table: Box::new(TableRef::SubQuery(query, alias.into_iden())),
+            on: None,
+            lateral: true,
+        });
+        self
+    }
+
     fn join_join(
         &mut self,
         join: JoinType,
@@ -1796,6 +1911,49 @@ impl SelectStatement {
         self.group_by_columns([col])
     }
 
+    /// Add a group by expression, e.g. a [`CaseStatement`] bucketing the rows.
+    ///
+    /// If the same expression is selected, Postgres groups by its position in the select list,
+    /// as it would not match the two expressions once their values are bound as separate parameters.
+    /// Unlike the alias, the position can't be shadowed by a column of the same name.
+    /// MySQL and SQLite repeat the expression.
+    /// Expressions added by [`SelectStatement::add_group_by`] are always repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let bucket = Expr::case(Expr::col(Char::SizeW).lt(10), "small").finally("large");
+    ///
+    /// let query = Query::select()
+    ///     .expr_as(bucket.clone(), Alias::new("bucket"))
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .from(Char::Table)
+    ///     .group_by_expr(bucket)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT (CASE WHEN (`size_w` < 10) THEN 'small' ELSE 'large' END) AS `bucket`, COUNT(`id`) FROM `character` GROUP BY (CASE WHEN (`size_w` < 10) THEN 'small' ELSE 'large' END)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT (CASE WHEN ("size_w" < 10) THEN 'small' ELSE 'large' END) AS "bucket", COUNT("id") FROM "character" GROUP BY 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT (CASE WHEN ("size_w" < 10) THEN 'small' ELSE 'large' END) AS "bucket", COUNT("id") FROM "character" GROUP BY (CASE WHEN ("size_w" < 10) THEN 'small' ELSE 'large' END)"#
+    /// );
+    /// ```
+    pub fn group_by_expr<E>(&mut self, expr: E) -> &mut Self
+    where
+        E: Into<SimpleExpr>,
+    {
+        self.groups.push((expr.into(), true));
+        self
+    }
+
     /// Add group by expressions from vector of [`SelectExpr`].
     ///
     /// # Examples
@@ -1826,12 +1984,127 @@ impl SelectStatement {
     where
         I: IntoIterator<Item = SimpleExpr>,
     {
-        self.groups.append(&mut expr.into_iter().collect());
+        self.groups
+            .extend(expr.into_iter().map(|expr| (expr, false)));
         self
     }
 
+    /// Group by `ROLLUP` of the columns, producing subtotals and a grand total.
+    ///
+    /// MySQL only supports a rollup of the whole GROUP BY list, rendered with `WITH ROLLUP`.
+    /// SQLite doesn't support it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .from(Char::Table)
+    ///     .group_by_rollup([Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, `size_w`, COUNT(`id`) FROM `character` GROUP BY `font_id`, `size_w` WITH ROLLUP"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY ROLLUP ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_rollup<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.grouping_sets
+            .push(GroupingSet::Rollup(Self::group_by_exprs(cols)));
+        self
+    }
+
+    /// Group by `CUBE` of the columns, producing subtotals for every combination of them.
+    ///
+    /// Only supported by Postgres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .from(Char::Table)
+    ///     .group_by_cube([Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY CUBE ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_cube<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.grouping_sets
+            .push(GroupingSet::Cube(Self::group_by_exprs(cols)));
+        self
+    }
+
+    /// Group by `GROUPING SETS`, an empty set stands for the grand total.
+    ///
+    /// Only supported by Postgres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .from(Char::Table)
+    ///     .group_by_grouping_sets([vec![Char::FontId, Char::SizeW], vec![Char::FontId], vec![]])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY GROUPING SETS (("font_id", "size_w"), ("font_id"), ())"#
+    /// );
+    /// ```
+    pub fn group_by_grouping_sets<T, I, S>(&mut self, sets: S) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+        S: IntoIterator<Item = I>,
+    {
+        self.grouping_sets.push(GroupingSet::Sets(
+            sets.into_iter().map(Self::group_by_exprs).collect(),
+        ));
+        self
+    }
+
+    fn group_by_exprs<T, I>(cols: I) -> Vec<SimpleExpr>
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        cols.into_iter()
+            .map(|c| SimpleExpr::Column(c.into_column_ref()))
+            .collect()
+    }
+
     /// Having condition, expressed with [`any!`](crate::any) and [`all!`](crate::all).
     ///
+    /// On Postgres, a column reference named after an alias of [`SelectStatement::expr_as`]
+    /// is replaced by the aliased expression, see [`SelectStatement::and_having`].
+    /// Other column references are left unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1870,6 +2143,11 @@ impl SelectStatement {
 
     /// And having condition.
     ///
+    /// An alias given by [`SelectStatement::expr_as`] can be referred to with `Expr::col(alias)`
+    /// in GROUP BY and HAVING. As Postgres does not allow aliases in HAVING, the aliased expression
+    /// is inlined there instead, unless a column of the same name is referenced by the query.
+    /// Column references not named after an alias are left unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1899,6 +2177,31 @@ impl SelectStatement {
     ///     r#"SELECT "aspect", MAX("image") FROM "glyph" GROUP BY "aspect" HAVING "aspect" > 2 AND "aspect" < 8"#
     /// );
     /// ```
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .expr_as(Expr::col(Glyph::Image).count(), Alias::new("image_count"))
+    ///     .from(Glyph::Table)
+    ///     .group_by_col(Glyph::Aspect)
+    ///     .and_having(Expr::col(Alias::new("image_count")).gt(2))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `aspect`, COUNT(`image`) AS `image_count` FROM `glyph` GROUP BY `aspect` HAVING `image_count` > 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect", COUNT("image") AS "image_count" FROM "glyph" GROUP BY "aspect" HAVING COUNT("image") > 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "aspect", COUNT("image") AS "image_count" FROM "glyph" GROUP BY "aspect" HAVING "image_count" > 2"#
+    /// );
+    /// ```
     pub fn and_having(&mut self, other: SimpleExpr) -> &mut Self {
         self.cond_having(other)
     }
@@ -1931,15 +2234,52 @@ impl SelectStatement {
     /// ```
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(limit.into());
+        self.fetch = None;
         self
     }
 
     /// Reset limit
     pub fn reset_limit(&mut self) -> &mut Self {
         self.limit = None;
+        self.fetch = None;
         self
     }
 
+    /// Select `n` random rows, by ordering them with [`Func::random`] and limiting the result.
+    ///
+    /// Every row is read and sorted, which is slow on large tables.
+    /// On Postgres, select from a `TABLESAMPLE` of the table with `PostgresSelectStatementExt::from_sampled`
+    /// to only order a fraction of the rows, keeping in mind that a too small sample may have fewer than `n` rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .random_sample(10)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `glyph` ORDER BY RAND() ASC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" ORDER BY RANDOM() ASC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" ORDER BY RANDOM() ASC LIMIT 10"#
+    /// );
+    /// ```
+    pub fn random_sample(&mut self, n: u64) -> &mut Self {
+        self.order_by_expr(Func::random().into(), Order::Asc)
+            .limit(n)
+    }
+
     /// Offset number of returned rows.
     ///
     /// # Examples
@@ -1978,6 +2318,44 @@ impl SelectStatement {
         self
     }
 
+    /// Limit the number of returned rows with the standard `FETCH FIRST n ROWS` clause, replacing any `LIMIT`.
+    /// With `with_ties`, rows that tie with the last row in the `ORDER BY` order are returned as well,
+    /// which needs an `ORDER BY`; [`SelectStatement::try_build`] rejects the query without one.
+    ///
+    /// Only Postgres supports `FETCH FIRST`, MySQL and SQLite panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .order_by(Glyph::Aspect, Order::Desc)
+    ///     .offset(10)
+    ///     .fetch_first(10, true)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect" FROM "glyph" ORDER BY "aspect" DESC OFFSET 10 FETCH FIRST 10 ROWS WITH TIES"#
+    /// );
+    /// ```
+    pub fn fetch_first(&mut self, count: u64, with_ties: bool) -> &mut Self {
+        self.limit = Some(count.into());
+        self.fetch = Some(with_ties);
+        self
+    }
+
+    /// Reset fetch first
+    pub fn reset_fetch_first(&mut self) -> &mut Self {
+        if self.fetch.take().is_some() {
+            self.limit = None;
+        }
+        self
+    }
+
     /// Row locking (if supported).
     ///
     /// # Examples
@@ -2015,6 +2393,7 @@ impl SelectStatement {
     }
 
     /// Row locking with tables (if supported).
+    /// SQLite panics, as it doesn't support row locking on specific tables.
     ///
     /// # Examples
     ///
@@ -2036,10 +2415,6 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 FOR UPDATE OF "glyph""#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 "#
-    /// );
     /// ```
     pub fn lock_with_tables<T, I>(&mut self, r#type: LockType, tables: I) -> &mut Self
     where
@@ -2054,7 +2429,8 @@ impl SelectStatement {
         self
     }
 
-    /// Row locking with behavior (if supported).
+    /// Row locking with behavior (if supported), e.g. `SKIP LOCKED` for consuming a job queue.
+    /// SQLite panics, as it can't skip or refuse to wait for locked rows.
     ///
     /// # Examples
     ///
@@ -2076,10 +2452,6 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 FOR UPDATE NOWAIT"#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 "#
-    /// );
     /// ```
     pub fn lock_with_behavior(&mut self, r#type: LockType, behavior: LockBehavior) -> &mut Self {
         self.lock = Some(LockClause {
@@ -2091,6 +2463,7 @@ impl SelectStatement {
     }
 
     /// Row locking with tables and behavior (if supported).
+    /// SQLite panics, see [`SelectStatement::lock_with_behavior`].
     ///
     /// # Examples
     ///
@@ -2112,10 +2485,6 @@ impl SelectStatement {
     ///     query.to_string(PostgresQueryBuilder),
     ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 FOR UPDATE OF "glyph" NOWAIT"#
     /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 "#
-    /// );
     /// ```
     pub fn lock_with_tables_behavior<T, I>(
         &mut self,
@@ -2199,6 +2568,11 @@ impl SelectStatement {
 
     /// Union with another SelectStatement that must have the same selected fields.
     ///
+    /// The `ORDER BY` and `LIMIT` of this statement apply to the whole union,
+    /// while those of the other statement only apply to it, as it is parenthesized.
+    /// SQLite doesn't allow parentheses there, so the statement is selected from as a sub query instead.
+    /// To order or limit this statement on its own, select from it as a sub query too.
+    ///
     /// # Examples
     ///
     /// ```
@@ -2228,6 +2602,41 @@ impl SelectStatement {
     ///     query.to_string(SqliteQueryBuilder),
     ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 UNION ALL SELECT "character" FROM "character" WHERE "font_id" = 4"#
     /// );
+    ///
+    /// let newest = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .order_by(Char::CreatedAt, Order::Desc)
+    ///     .limit(5)
+    ///     .to_owned();
+    /// let oldest = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .order_by(Char::CreatedAt, Order::Asc)
+    ///     .limit(5)
+    ///     .to_owned();
+    /// let query = Query::select()
+    ///     .column(Asterisk)
+    ///     .from_subquery(newest, Alias::new("newest"))
+    ///     .union(UnionType::All, oldest)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"SELECT * FROM (SELECT "id" FROM "character" ORDER BY "created_at" DESC LIMIT 5) AS "newest""#,
+    ///         r#"UNION ALL (SELECT "id" FROM "character" ORDER BY "created_at" ASC LIMIT 5)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     [
+    ///         r#"SELECT * FROM (SELECT "id" FROM "character" ORDER BY "created_at" DESC LIMIT 5) AS "newest""#,
+    ///         r#"UNION ALL SELECT * FROM (SELECT "id" FROM "character" ORDER BY "created_at" ASC LIMIT 5)"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
     /// ```
     pub fn union(&mut self, union_type: UnionType, query: SelectStatement) -> &mut Self {
         self.unions.push((union_type, query));
@@ -2346,7 +2755,39 @@ impl SelectStatement {
         clause.query(self)
     }
 
-    /// WINDOW
+    /// Show the execution plan of the select statement, see [`ExplainQuery`].
+    pub fn explain(self) -> ExplainQuery {
+        ExplainQuery::new(self)
+    }
+
+    /// Show the execution plan of the select statement, with the actual run time and row counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .to_owned()
+    ///     .explain_analyze();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"EXPLAIN ANALYZE SELECT `id` FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"EXPLAIN (ANALYZE, FORMAT JSON) SELECT "id" FROM "character""#
+    /// );
+    /// ```
+    pub fn explain_analyze(self) -> ExplainQuery {
+        ExplainQuery::new(self).analyze().to_owned()
+    }
+
+    /// Define a named window for `OVER name`, rendered in the `WINDOW` clause after `HAVING`.
+    /// Call it again to define more windows.
     ///
     /// # Examples:
     ///
@@ -2361,24 +2802,221 @@ impl SelectStatement {
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character` OVER `w` AS `C` FROM `character` WINDOW `w` AS PARTITION BY `font_size`"#
+    ///     r#"SELECT `character` OVER `w` AS `C` FROM `character` WINDOW `w` AS (PARTITION BY `font_size`)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS PARTITION BY "font_size""#
+    ///     r#"SELECT "character" OVER "w" AS "C" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
     /// );
     /// ```
     pub fn window<A>(&mut self, name: A, window: WindowStatement) -> &mut Self
     where
         A: IntoIden,
     {
-        self.window = Some((name.into_iden(), window));
+        self.windows.push((name.into_iden(), window));
+        self
+    }
+
+    /// Append a raw SQL fragment with its own values at the very end of the query,
+    /// for a trailing clause not modelled by this crate yet.
+    ///
+    /// The placeholders in the fragment are written like in [`Expr::cust_with_values`]
+    /// and are numbered into the parameters of the whole query.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).eq(3))
+    ///     .append_raw("LOCK IN SHARE MODE", Vec::<Value>::new())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"SELECT `id` FROM `character` WHERE `size_w` = ? LOCK IN SHARE MODE"#.to_owned(),
+    ///         Values(vec![3.into()])
+    ///     )
+    /// );
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).eq(3))
+    ///     .append_raw("FETCH FIRST $1 ROWS WITH TIES", [10])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "character" WHERE "size_w" = $1 FETCH FIRST $2 ROWS WITH TIES"#
+    ///             .to_owned(),
+    ///         Values(vec![3.into(), 10.into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn append_raw<T, V, I>(&mut self, fragment: T, values: I) -> &mut Self
+    where
+        T: Into<String>,
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        self.raw_tail.push(Expr::cust_with_values(fragment, values));
         self
     }
+
+    /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector,
+    /// returning an error if the `ORDER BY` clause does not start with the `DISTINCT ON` columns,
+    /// if it orders a `SELECT DISTINCT` by an expression missing from the select list,
+    /// or if it fetches rows `WITH TIES` without an `ORDER BY`.
+    ///
+    /// Postgres requires the leading `ORDER BY` expressions to match the `DISTINCT ON` expressions, in any order.
+    /// The columns have to be referenced the same way in both clauses, e.g. both qualified with the table name.
+    /// Ordering by the remaining expressions, including their `NULLS FIRST` / `NULLS LAST`, picks which row is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .distinct_on([Char::FontId])
+    ///     .columns([Char::FontId, Char::Character])
+    ///     .from(Char::Table)
+    ///     .order_by(Char::FontId, Order::Asc)
+    ///     .order_by_with_nulls(Char::CreatedAt, Order::Desc, NullOrdering::Last)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Ok((
+    ///         r#"SELECT DISTINCT ON ("font_id") "font_id", "character" FROM "character" ORDER BY "font_id" ASC, "created_at" DESC NULLS LAST"#
+    ///             .to_owned(),
+    ///         Values(vec![])
+    ///     ))
+    /// );
+    ///
+    /// let query = Query::select()
+    ///     .distinct_on([Char::FontId])
+    ///     .columns([Char::FontId, Char::Character])
+    ///     .from(Char::Table)
+    ///     .order_by(Char::CreatedAt, Order::Desc)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Err(Error::DistinctOnOrderMismatch)
+    /// );
+    /// ```
+    ///
+    /// Postgres and MySQL require the `ORDER BY` expressions of a `SELECT DISTINCT` to be selected,
+    /// either as the same expression or through its alias. SQLite does not.
+    ///
+    /// ```
+    /// use sea_query::{error::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .distinct()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .order_by(Char::CreatedAt, Order::Desc)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Err(Error::DistinctOrderNotSelected)
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(SqliteQueryBuilder),
+    ///     Ok((
+    ///         r#"SELECT DISTINCT "character" FROM "character" ORDER BY "created_at" DESC"#.to_owned(),
+    ///         Values(vec![])
+    ///     ))
+    /// );
+    /// ```
+    ///
+    /// Ties are only defined by an `ORDER BY`.
+    ///
+    /// ```
+    /// use sea_query::{error::*, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .fetch_first(10, true)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Err(Error::FetchWithTiesWithoutOrder)
+    /// );
+    /// ```
+    pub fn try_build<T: QueryBuilder>(&self, query_builder: T) -> Result<(String, Values)> {
+        if let Some(SelectDistinct::DistinctOn(cols)) = &self.distinct {
+            if !self.orders.is_empty() {
+                let leading: Vec<&SimpleExpr> = self
+                    .orders
+                    .iter()
+                    .take(cols.len())
+                    .map(|order| &order.expr)
+                    .collect();
+                let is_distinct_col = |expr: &SimpleExpr| match expr {
+                    SimpleExpr::Column(col) => cols.contains(col),
+                    _ => false,
+                };
+                let leading_match = leading.len() == cols.len()
+                    && leading.iter().all(|expr| is_distinct_col(expr))
+                    && cols.iter().all(|col| {
+                        leading
+                            .iter()
+                            .any(|expr| matches!(expr, SimpleExpr::Column(c) if c == col))
+                    });
+                if !leading_match {
+                    return Err(Error::DistinctOnOrderMismatch);
+                }
+            }
+        }
+        let orders_selected = self
+            .orders
+            .iter()
+            .all(|order| self.is_selected(&order.expr));
+        if matches!(
+            self.distinct,
+            Some(SelectDistinct::Distinct | SelectDistinct::DistinctRow)
+        ) && query_builder.distinct_order_by_must_be_selected()
+            && !orders_selected
+        {
+            return Err(Error::DistinctOrderNotSelected);
+        }
+        if self.fetch == Some(true) && self.orders.is_empty() {
+            return Err(Error::FetchWithTiesWithoutOrder);
+        }
+        Ok(self.build(query_builder))
+    }
+
+    /// Whether the expression is in the select list, as is or through its alias
+    fn is_selected(&self, expr: &SimpleExpr) -> bool {
+        self.selects.iter().any(|select| {
+            // The columns behind an asterisk are unknown, assume they cover the expression
+            let is_asterisk = matches!(
+                select.expr,
+                SimpleExpr::Column(ColumnRef::Asterisk | ColumnRef::TableAsterisk(_))
+            );
+            let is_alias = matches!(
+                (&select.alias, expr),
+                (Some(alias), SimpleExpr::Column(ColumnRef::Column(col))) if alias == col
+            );
+            is_asterisk || is_alias || &select.expr == expr
+        })
+    }
 }
 
 #[inherent]
@@ -2418,6 +3056,9 @@ impl QueryStatementWriter for SelectStatement {
     pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
 }
 
+// `apply` and `apply_if` are inherent methods of `SelectStatement`
+impl StatementApply for SelectStatement {}
+
 #[inherent]
 impl OrderedStatement for SelectStatement {
     pub fn add_order_by(&mut self, order: OrderExpr) -> &mut Self {
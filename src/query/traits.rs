@@ -126,3 +126,80 @@ pub trait QueryStatementWriter: QueryStatementBuilder {
 
     fn build_collect_into<T: QueryBuilder>(&self, query_builder: T, sql: &mut dyn SqlWriter);
 }
+
+/// Compose statements with helper functions, for statements without an inherent `apply`
+pub trait StatementApply: Sized {
+    /// Construct part of the statement in another function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let add_standard_joins = |q: &mut SelectStatement| {
+    ///     q.left_join(
+    ///         Font::Table,
+    ///         Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)),
+    ///     );
+    /// };
+    /// let only_font = |q: &mut UpdateStatement| {
+    ///     q.and_where(Expr::col(Char::FontId).eq(5));
+    /// };
+    ///
+    /// let select = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .apply(add_standard_joins)
+    ///     .to_owned();
+    /// let update = Query::update()
+    ///     .table(Char::Table)
+    ///     .value(Char::Character, "A")
+    ///     .apply(only_font)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     select.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id""#
+    /// );
+    /// assert_eq!(
+    ///     update.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = 'A' WHERE "font_id" = 5"#
+    /// );
+    /// ```
+    fn apply<F>(&mut self, func: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        func(self);
+        self
+    }
+
+    /// Construct part of the statement in another function if the value is `Some`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Char::Table)
+    ///     .apply_if(Some(5), |q, v| {
+    ///         q.and_where(Expr::col(Char::FontId).eq(v));
+    ///     })
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "character" WHERE "font_id" = 5"#
+    /// );
+    /// ```
+    fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self, T),
+    {
+        if let Some(val) = val {
+            if_some(self, val);
+        }
+        self
+    }
+}
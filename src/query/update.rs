@@ -5,8 +5,8 @@ use crate::{
     query::{condition::*, OrderedStatement},
     types::*,
     value::*,
-    QueryStatementBuilder, QueryStatementWriter, ReturningClause, SubQueryStatement, WithClause,
-    WithQuery,
+    QueryStatementBuilder, QueryStatementWriter, ReturningClause, StatementApply,
+    SubQueryStatement, WithClause, WithQuery,
 };
 use inherent::inherent;
 
@@ -39,7 +39,9 @@ use inherent::inherent;
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) from: Vec<TableRef>,
     pub(crate) values: Vec<(DynIden, Box<SimpleExpr>)>,
+    pub(crate) value_tuples: Vec<(Vec<DynIden>, Box<SimpleExpr>)>,
     pub(crate) r#where: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
@@ -66,6 +68,46 @@ impl UpdateStatement {
         self
     }
 
+    /// Update from other tables, which are correlated with the updated table in `WHERE`.
+    ///
+    /// Postgres and SQLite render `UPDATE ... SET ... FROM ...`, while MySQL renders the multi-table
+    /// form `UPDATE t, other SET t.col = ...`. Qualify the columns in `WHERE` with their table,
+    /// as the unqualified names may be ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .from(Font::Table)
+    ///     .value(Char::Character, Expr::col((Font::Table, Font::Name)))
+    ///     .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+    ///     .and_where(Expr::col((Font::Table, Font::Language)).eq("en"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `character`, `font` SET `character`.`character` = `font`.`name` WHERE `character`.`font_id` = `font`.`id` AND `font`.`language` = 'en'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = "font"."name" FROM "font" WHERE "character"."font_id" = "font"."id" AND "font"."language" = 'en'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = "font"."name" FROM "font" WHERE "character"."font_id" = "font"."id" AND "font"."language" = 'en'"#
+    /// );
+    /// ```
+    pub fn from<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from.push(tbl_ref.into_table_ref());
+        self
+    }
+
     /// Update column values. To set multiple column-value pairs at once.
     ///
     /// # Examples
@@ -150,6 +192,28 @@ impl UpdateStatement {
     ///     query.to_string(SqliteQueryBuilder),
     ///     r#"UPDATE "glyph" SET "aspect" = NULL"#
     /// );
+    ///
+    /// // Conditionally update a column, keeping its current value otherwise
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(
+    ///         Glyph::Image,
+    ///         Expr::case(Expr::col(Glyph::Aspect).gt(2), "large").finally(Expr::col(Glyph::Image)),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `image` = (CASE WHEN (`aspect` > 2) THEN 'large' ELSE `image` END)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = (CASE WHEN ("aspect" > 2) THEN 'large' ELSE "image" END)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = (CASE WHEN ("aspect" > 2) THEN 'large' ELSE "image" END)"#
+    /// );
     /// ```
     pub fn value<C, T>(&mut self, col: C, value: T) -> &mut Self
     where
@@ -160,7 +224,88 @@ impl UpdateStatement {
         self
     }
 
-    /// Limit number of updated rows.
+    /// Update several columns at once from a row or a subquery, i.e. `SET (a, b) = (SELECT ...)`.
+    /// These are rendered after the columns set individually.
+    ///
+    /// MySQL doesn't support assigning a row, building the statement for it panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .value_tuple(
+    ///         [Char::SizeW, Char::SizeH],
+    ///         Expr::subquery(
+    ///             Query::select()
+    ///                 .columns([Font::Id, Font::Id])
+    ///                 .from(Font::Table)
+    ///                 .and_where(Expr::col((Font::Table, Font::Id)).equals((Char::Table, Char::FontId)))
+    ///                 .to_owned(),
+    ///         ),
+    ///     )
+    ///     .and_where(Expr::col(Char::Id).eq(1))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET ("size_w", "size_h") = (SELECT "id", "id" FROM "font" WHERE "font"."id" = "character"."font_id") WHERE "id" = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE "character" SET ("size_w", "size_h") = (SELECT "id", "id" FROM "font" WHERE "font"."id" = "character"."font_id") WHERE "id" = 1"#
+    /// );
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .value(Char::Character, "A")
+    ///     .value_tuple([Char::SizeW, Char::SizeH], Expr::tuple([10.into(), 20.into()]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = 'A', ("size_w", "size_h") = (10, 20)"#
+    /// );
+    /// ```
+    pub fn value_tuple<C, I, T>(&mut self, cols: I, value: T) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+        T: Into<SimpleExpr>,
+    {
+        self.value_tuples.push((
+            cols.into_iter().map(|c| c.into_iden()).collect(),
+            Box::new(value.into()),
+        ));
+        self
+    }
+
+    /// Limit number of updated rows, usually together with `ORDER BY` to update in batches.
+    ///
+    /// Every query builder writes the `LIMIT` clause, but only MySQL accepts it on `UPDATE`,
+    /// and SQLite when compiled with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`.
+    /// Postgres rejects the statement; select the keys to update in a sub query instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Aspect, 0)
+    ///     .and_where(Expr::col(Glyph::Aspect).lt(0))
+    ///     .order_by(Glyph::Id, Order::Asc)
+    ///     .limit(100)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 0 WHERE `aspect` < 0 ORDER BY `id` ASC LIMIT 100"#
+    /// );
+    /// ```
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(limit.into());
         self
@@ -265,6 +410,69 @@ impl UpdateStatement {
         self.returning(ReturningClause::All)
     }
 
+    /// Append an expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Image, "12A")
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr(Expr::col(Glyph::Aspect).is_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = '12A' RETURNING "id", "aspect" IS NULL"#
+    /// );
+    /// ```
+    pub fn returning_expr<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            None,
+        ));
+        self
+    }
+
+    /// Append an aliased expression to the RETURNING expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Image, "12A")
+    ///     .returning_col(Glyph::Id)
+    ///     .returning_expr_as(Func::cust(Alias::new("now")), Alias::new("ts"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = '12A' RETURNING "id", now() AS "ts""#
+    /// );
+    /// ```
+    pub fn returning_expr_as<T, A>(&mut self, expr: T, alias: A) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.returning = Some(ReturningClause::append_expr(
+            self.returning.take(),
+            expr.into(),
+            Some(alias.into_iden()),
+        ));
+        self
+    }
+
     /// Create a [WithQuery] by specifying a [WithClause] to execute this query with.
     ///
     /// # Examples
@@ -350,6 +558,16 @@ impl QueryStatementWriter for UpdateStatement {
     pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
 }
 
+#[inherent]
+impl StatementApply for UpdateStatement {
+    pub fn apply<F>(&mut self, func: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self);
+    pub fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self, T);
+}
+
 #[inherent]
 impl OrderedStatement for UpdateStatement {
     pub fn add_order_by(&mut self, order: OrderExpr) -> &mut Self {
@@ -54,6 +54,8 @@ pub enum Frame {
 pub enum FrameType {
     Range,
     Rows,
+    /// Not supported by MySQL
+    Groups,
 }
 
 /// Frame clause
@@ -92,6 +94,11 @@ impl WindowStatement {
         }
     }
 
+    /// Whether the window spans the whole result, i.e. `OVER ()`
+    pub(crate) fn is_empty(&self) -> bool {
+        self.partition_by.is_empty() && self.order_by.is_empty() && self.frame.is_none()
+    }
+
     /// Construct a new [`WindowStatement`] with PARTITION BY column
     pub fn partition_by<T>(col: T) -> Self
     where
@@ -1,6 +1,6 @@
 use crate::{
-    ColumnRef, DynIden, IntoIden, QueryStatementBuilder, QueryStatementWriter, SelectExpr,
-    SelectStatement, SimpleExpr, SqlWriter, SubQueryStatement, TableRef, Values,
+    ColumnRef, DynIden, IntoColumnRef, IntoIden, QueryStatementBuilder, QueryStatementWriter,
+    SelectExpr, SelectStatement, SimpleExpr, SqlWriter, SubQueryStatement, TableRef, Values,
     {Alias, QueryBuilder},
 };
 use inherent::inherent;
@@ -52,6 +52,8 @@ pub struct CommonTableExpression {
     pub(crate) cols: Vec<DynIden>,
     pub(crate) query: Option<Box<SubQueryStatement>>,
     pub(crate) materialized: Option<bool>,
+    pub(crate) search: Option<Search>,
+    pub(crate) cycle: Option<Cycle>,
 }
 
 impl CommonTableExpression {
@@ -98,6 +100,126 @@ impl CommonTableExpression {
         self
     }
 
+    /// Order the rows of a recursive CTE depth first by the given columns, numbering them in the
+    /// `set` column. A shorthand for [Self::search].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let mut base = Query::select()
+    ///     .columns([Alias::new("id"), Alias::new("parent_id")])
+    ///     .from(Alias::new("category"))
+    ///     .and_where(Expr::col(Alias::new("parent_id")).is_null())
+    ///     .to_owned();
+    /// let step = Query::select()
+    ///     .columns([
+    ///         (Alias::new("c"), Alias::new("id")),
+    ///         (Alias::new("c"), Alias::new("parent_id")),
+    ///     ])
+    ///     .from_as(Alias::new("category"), Alias::new("c"))
+    ///     .inner_join(
+    ///         Alias::new("tree"),
+    ///         Expr::col((Alias::new("c"), Alias::new("parent_id")))
+    ///             .equals((Alias::new("tree"), Alias::new("id"))),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// let cte = CommonTableExpression::new()
+    ///     .query(base.union(UnionType::All, step).to_owned())
+    ///     .columns([Alias::new("id"), Alias::new("parent_id")])
+    ///     .table_name(Alias::new("tree"))
+    ///     .search_depth_first([Alias::new("id")], Alias::new("ord"))
+    ///     .to_owned();
+    ///
+    /// let query = Query::select()
+    ///     .column(Alias::new("id"))
+    ///     .from(Alias::new("tree"))
+    ///     .order_by(Alias::new("ord"), Order::Asc)
+    ///     .to_owned()
+    ///     .with(WithClause::new().recursive(true).cte(cte).to_owned());
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"WITH RECURSIVE "tree" ("id", "parent_id") AS"#,
+    ///         r#"(SELECT "id", "parent_id" FROM "category" WHERE "parent_id" IS NULL"#,
+    ///         r#"UNION ALL (SELECT "c"."id", "c"."parent_id" FROM "category" AS "c""#,
+    ///         r#"INNER JOIN "tree" ON "c"."parent_id" = "tree"."id"))"#,
+    ///         r#"SEARCH DEPTH FIRST BY "id" SET "ord""#,
+    ///         r#"SELECT "id" FROM "tree" ORDER BY "ord" ASC"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn search_depth_first<C, I, S>(&mut self, cols: I, set: S) -> &mut Self
+    where
+        C: IntoColumnRef,
+        I: IntoIterator<Item = C>,
+        S: IntoIden,
+    {
+        self.search(Search::new_from_order_and_expr(
+            SearchOrder::DEPTH,
+            SelectExpr {
+                expr: column_list(cols),
+                alias: Some(set.into_iden()),
+                window: None,
+            },
+        ))
+    }
+
+    /// Order the rows of a recursive CTE breadth first by the given columns, numbering them in the
+    /// `set` column. A shorthand for [Self::search].
+    pub fn search_breadth_first<C, I, S>(&mut self, cols: I, set: S) -> &mut Self
+    where
+        C: IntoColumnRef,
+        I: IntoIterator<Item = C>,
+        S: IntoIden,
+    {
+        self.search(Search::new_from_order_and_expr(
+            SearchOrder::BREADTH,
+            SelectExpr {
+                expr: column_list(cols),
+                alias: Some(set.into_iden()),
+                window: None,
+            },
+        ))
+    }
+
+    /// The [Search] clause of this recursive CTE, rendered right after its query.
+    ///
+    /// # Panics
+    ///
+    /// Only Postgres supports this clause, other backends panic on it.
+    /// Building also panics if the [WithClause] has its own [WithClause::search] or [WithClause::cycle].
+    pub fn search(&mut self, search: Search) -> &mut Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Detect cycles of a recursive CTE by the given columns. The `set` column is true for the row
+    /// closing a cycle, where the recursion stops, and the `using` column holds the path of visited rows.
+    ///
+    /// # Panics
+    ///
+    /// Only Postgres supports this clause, other backends panic on it.
+    /// Building also panics if the [WithClause] has its own [WithClause::search] or [WithClause::cycle].
+    pub fn cycle<C, I, S, U>(&mut self, cols: I, set: S, using: U) -> &mut Self
+    where
+        C: IntoColumnRef,
+        I: IntoIterator<Item = C>,
+        S: IntoIden,
+        U: IntoIden,
+    {
+        self.cycle = Some(Cycle::new_from_expr_set_using(
+            column_list(cols),
+            set,
+            using,
+        ));
+        self
+    }
+
     /// Set the query generating the CTE content. The query's result must match the defined
     /// columns.
     pub fn query<Q>(&mut self, query: Q) -> &mut Self
@@ -602,3 +724,20 @@ impl QueryStatementWriter for WithQuery {
     pub fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values);
     pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
 }
+
+/// The columns of a `SEARCH` or `CYCLE` clause, as a tuple if there is more than one
+fn column_list<C, I>(cols: I) -> SimpleExpr
+where
+    C: IntoColumnRef,
+    I: IntoIterator<Item = C>,
+{
+    let mut cols: Vec<SimpleExpr> = cols
+        .into_iter()
+        .map(|c| SimpleExpr::Column(c.into_column_ref()))
+        .collect();
+    if cols.len() == 1 {
+        cols.pop().unwrap()
+    } else {
+        SimpleExpr::Tuple(cols)
+    }
+}
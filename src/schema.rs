@@ -1,6 +1,8 @@
 //! Schema definition & alternations statements
 
-use crate::{backend::SchemaBuilder, ForeignKeyStatement, IndexStatement, TableStatement};
+use crate::{
+    backend::SchemaBuilder, ForeignKeyStatement, IndexStatement, SqlWriter, TableStatement,
+};
 
 #[derive(Debug, Clone)]
 pub enum SchemaStatement {
@@ -16,6 +18,16 @@ pub trait SchemaStatementBuilder {
     /// Build corresponding SQL statement for certain database backend and return SQL string
     fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String;
 
+    /// Build corresponding SQL statement into the SqlWriter for certain database backend
+    fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter) {
+        self.build_collect_any_into(&schema_builder, sql)
+    }
+
+    /// Build corresponding SQL statement into the SqlWriter for certain database backend
+    fn build_collect_any_into(&self, schema_builder: &dyn SchemaBuilder, sql: &mut dyn SqlWriter) {
+        sql.write_str(&self.build_any(schema_builder)).unwrap();
+    }
+
     /// Build corresponding SQL statement for certain database backend and return SQL string
     fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
         self.build(schema_builder)
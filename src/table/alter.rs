@@ -1,6 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, types::*, ColumnDef, IntoColumnDef, SchemaStatementBuilder,
-    TableForeignKey,
+    backend::SchemaBuilder, types::*, ColumnDef, IntoColumnDef, SchemaStatementBuilder, SimpleExpr,
+    SqlWriter, TableForeignKey,
 };
 use inherent::inherent;
 
@@ -54,6 +54,8 @@ pub enum TableAlterOption {
     ModifyColumn(ColumnDef),
     RenameColumn(DynIden, DynIden),
     DropColumn(DynIden),
+    SetDefault(DynIden, SimpleExpr),
+    DropDefault(DynIden),
     AddForeignKey(TableForeignKey),
     DropForeignKey(DynIden),
 }
@@ -254,6 +256,72 @@ impl TableAlterStatement {
         self.add_alter_option(TableAlterOption::DropColumn(col_name.into_iden()))
     }
 
+    /// Set the default value of a column, without restating the rest of its definition
+    ///
+    /// Values in the default expression are inlined, as DDL statements do not take parameters.
+    /// MySQL requires non-literal defaults to be parenthesized, which is done automatically.
+    /// SQLite cannot alter a column default; the table has to be rebuilt instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .set_default(Font::Variant, "regular")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` ALTER COLUMN `variant` SET DEFAULT 'regular'"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "variant" SET DEFAULT 'regular'"#
+    /// );
+    /// ```
+    pub fn set_default<T, E>(&mut self, col_name: T, expr: E) -> &mut Self
+    where
+        T: IntoIden,
+        E: Into<SimpleExpr>,
+    {
+        self.add_alter_option(TableAlterOption::SetDefault(
+            col_name.into_iden(),
+            expr.into(),
+        ))
+    }
+
+    /// Drop the default value of a column
+    ///
+    /// SQLite cannot alter a column default; the table has to be rebuilt instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_default(Font::Variant)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` ALTER COLUMN `variant` DROP DEFAULT"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "variant" DROP DEFAULT"#
+    /// );
+    /// ```
+    pub fn drop_default<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::DropDefault(col_name.into_iden()))
+    }
+
     /// Add a foreign key to existing table
     ///
     /// # Examples
@@ -392,5 +460,15 @@ impl SchemaStatementBuilder for TableAlterStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_table_alter_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
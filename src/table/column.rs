@@ -74,7 +74,8 @@ pub enum ColumnType {
     Double,
     Decimal(Option<(u32, u32)>),
     DateTime,
-    Timestamp,
+    /// Timestamp with the precision of fractional seconds, if any
+    Timestamp(Option<u32>),
     TimestampWithTimeZone,
     Time,
     Date,
@@ -118,6 +119,7 @@ impl PartialEq for ColumnType {
             (Self::Char(l0), Self::Char(r0)) => l0 == r0,
             (Self::String(l0), Self::String(r0)) => l0 == r0,
             (Self::Decimal(l0), Self::Decimal(r0)) => l0 == r0,
+            (Self::Timestamp(l0), Self::Timestamp(r0)) => l0 == r0,
             (Self::Interval(l0, l1), Self::Interval(r0, r1)) => l0 == r0 && l1 == r1,
             (Self::Binary(l0), Self::Binary(r0)) => l0 == r0,
             (Self::VarBinary(l0), Self::VarBinary(r0)) => l0 == r0,
@@ -464,7 +466,44 @@ impl ColumnDef {
 
     /// Set column type as timestamp
     pub fn timestamp(&mut self) -> &mut Self {
-        self.types = Some(ColumnType::Timestamp);
+        self.types = Some(ColumnType::Timestamp(None));
+        self
+    }
+
+    /// Set column type as timestamp with the precision of fractional seconds, e.g. 6 for microseconds.
+    /// Use [`Expr::current_timestamp_with_precision`](crate::Expr::current_timestamp_with_precision)
+    /// for a default of matching precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("created_at"))
+    ///             .timestamp_len(6)
+    ///             .not_null()
+    ///             .default(Expr::current_timestamp_with_precision(6)),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `created_at` timestamp(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "created_at" timestamp(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "created_at" timestamp_text NOT NULL DEFAULT CURRENT_TIMESTAMP )"#
+    /// );
+    /// ```
+    pub fn timestamp_len(&mut self, precision: u32) -> &mut Self {
+        self.types = Some(ColumnType::Timestamp(Some(precision)));
         self
     }
 
@@ -574,6 +613,51 @@ impl ColumnDef {
     }
 
     /// Set column type as enum.
+    ///
+    /// MySQL declares the variants inline with `ENUM(...)`. Postgres references the enum type by `name`,
+    /// which has to be created beforehand, e.g. with `Type::create().as_enum(name)`.
+    /// SQLite has no enum type, so the column is declared as `enum_text`, which has `TEXT` affinity;
+    /// add a [`ColumnDef::check`] constraint to restrict the column to the variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Font::Table)
+    ///     .col(ColumnDef::new(Font::Variant).enumeration(
+    ///         Alias::new("font_variant"),
+    ///         [Alias::new("regular"), Alias::new("bold")],
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `font` ( `variant` ENUM('regular', 'bold') )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "font" ( "variant" "font_variant" )"#
+    /// );
+    ///
+    /// let table = Table::create()
+    ///     .table(Font::Table)
+    ///     .col(
+    ///         ColumnDef::new(Font::Variant)
+    ///             .enumeration(
+    ///                 Alias::new("font_variant"),
+    ///                 [Alias::new("regular"), Alias::new("bold")],
+    ///             )
+    ///             .check(Expr::col(Font::Variant).is_in(["regular", "bold"])),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE "font" ( "variant" enum_text CHECK ("variant" IN ('regular', 'bold')) )"#
+    /// );
+    /// ```
     pub fn enumeration<N, S, V>(&mut self, name: N, variants: V) -> &mut Self
     where
         N: IntoIden,
@@ -2,7 +2,7 @@ use inherent::inherent;
 
 use crate::{
     backend::SchemaBuilder, foreign_key::*, index::*, types::*, ColumnDef, IntoColumnDef,
-    SchemaStatementBuilder, SimpleExpr,
+    SchemaStatementBuilder, SimpleExpr, SqlWriter,
 };
 
 /// Create a table
@@ -89,6 +89,7 @@ pub struct TableCreateStatement {
     pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
     pub(crate) if_not_exists: bool,
     pub(crate) check: Vec<SimpleExpr>,
+    pub(crate) exclude: Vec<ExcludeConstraint>,
     pub(crate) comment: Option<String>,
     pub(crate) extra: Option<String>,
 }
@@ -101,6 +102,14 @@ pub enum TableOpt {
     CharacterSet(String),
 }
 
+/// An exclusion constraint, rejecting any two rows for which all the operators return true. Postgres only.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "backend-postgres"), allow(dead_code))]
+pub struct ExcludeConstraint {
+    pub(crate) index_type: IndexType,
+    pub(crate) elements: Vec<(DynIden, BinOper)>,
+}
+
 /// All available table partition options
 #[derive(Debug, Clone)]
 pub enum TablePartition {}
@@ -148,6 +157,53 @@ impl TableCreateStatement {
         self
     }
 
+    /// Add an exclusion constraint of (column, operator) pairs. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::PgBinOper, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("booking"))
+    ///     .col(ColumnDef::new(Alias::new("room")).integer().not_null())
+    ///     .col(ColumnDef::new(Alias::new("during")).custom(Alias::new("tsrange")).not_null())
+    ///     .exclude_constraint(
+    ///         IndexType::Custom(Alias::new("gist").into_iden()),
+    ///         [
+    ///             (Alias::new("room"), BinOper::Equal),
+    ///             (Alias::new("during"), PgBinOper::Overlap.into()),
+    ///         ],
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"CREATE TABLE "booking" ("#,
+    ///         r#""room" integer NOT NULL,"#,
+    ///         r#""during" tsrange NOT NULL,"#,
+    ///         r#"EXCLUDE USING gist ("room" WITH =, "during" WITH &&)"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn exclude_constraint<I, C>(&mut self, index_type: IndexType, elements: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (C, BinOper)>,
+        C: IntoIden,
+    {
+        self.exclude.push(ExcludeConstraint {
+            index_type,
+            elements: elements
+                .into_iter()
+                .map(|(col, oper)| (col.into_iden(), oper))
+                .collect(),
+        });
+        self
+    }
+
     /// Add an index. MySQL only.
     ///
     /// # Examples
@@ -349,6 +405,7 @@ impl TableCreateStatement {
             foreign_keys: std::mem::take(&mut self.foreign_keys),
             if_not_exists: self.if_not_exists,
             check: std::mem::take(&mut self.check),
+            exclude: std::mem::take(&mut self.exclude),
             comment: std::mem::take(&mut self.comment),
             extra: std::mem::take(&mut self.extra),
         }
@@ -369,5 +426,15 @@ impl SchemaStatementBuilder for TableCreateStatement {
         sql
     }
 
+    pub fn build_collect_into<T: SchemaBuilder>(&self, schema_builder: T, sql: &mut dyn SqlWriter);
+
+    pub fn build_collect_any_into(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        schema_builder.prepare_table_create_statement(self, sql);
+    }
+
     pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String;
 }
@@ -8,7 +8,7 @@
 //! - Table Rename, see [`TableRenameStatement`]
 //! - Table Truncate, see [`TableTruncateStatement`]
 
-use crate::SchemaBuilder;
+use crate::{SchemaBuilder, SqlWriter};
 
 mod alter;
 mod column;
@@ -88,6 +88,21 @@ impl TableStatement {
         }
     }
 
+    /// Build corresponding SQL statement into the SqlWriter for certain database backend
+    pub fn build_collect_any_into(
+        &self,
+        table_builder: &dyn SchemaBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        match self {
+            Self::Create(stat) => stat.build_collect_any_into(table_builder, sql),
+            Self::Alter(stat) => stat.build_collect_any_into(table_builder, sql),
+            Self::Drop(stat) => stat.build_collect_any_into(table_builder, sql),
+            Self::Rename(stat) => stat.build_collect_any_into(table_builder, sql),
+            Self::Truncate(stat) => stat.build_collect_any_into(table_builder, sql),
+        }
+    }
+
     /// Build corresponding SQL statement for certain database backend and return SQL string
     pub fn to_string<T: SchemaBuilder>(&self, table_builder: T) -> String {
         match self {
@@ -20,7 +20,10 @@ macro_rules! iden_trait {
         /// Identifier
         pub trait Iden where $(Self: $bounds),* {
             fn prepare(&self, s: &mut dyn fmt::Write, q: Quote) {
-                write!(s, "{}{}{}", q.left(), self.quoted(q), q.right()).unwrap();
+                // Escape while writing, without rendering into an intermediate `String`
+                write!(s, "{}", q.left()).unwrap();
+                self.unquoted(&mut QuoteEscaper { inner: s, quote: q.right() });
+                write!(s, "{}", q.right()).unwrap();
             }
 
             fn quoted(&self, q: Quote) -> String {
@@ -36,6 +39,13 @@ macro_rules! iden_trait {
             }
 
             fn unquoted(&self, s: &mut dyn fmt::Write);
+
+            #[doc(hidden)]
+            /// The same value in static memory, as derived for [`IdenStatic`] enums.
+            /// [`DynIden`] then refers to it instead of allocating.
+            fn static_iden(&self) -> Option<&'static dyn Iden> {
+                None
+            }
         }
 
         /// Identifier
@@ -45,6 +55,31 @@ macro_rules! iden_trait {
     };
 }
 
+/// Writes an identifier with the closing quote doubled, as [`Iden::quoted`] does
+struct QuoteEscaper<'a> {
+    inner: &'a mut dyn fmt::Write,
+    quote: char,
+}
+
+impl fmt::Write for QuoteEscaper<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !s.contains(self.quote) {
+            return self.inner.write_str(s);
+        }
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if c == self.quote {
+            self.inner.write_char(c)?;
+        }
+        self.inner.write_char(c)
+    }
+}
+
 #[cfg(feature = "thread-safe")]
 iden_trait!(Send, Sync);
 #[cfg(not(feature = "thread-safe"))]
@@ -54,29 +89,44 @@ pub type DynIden = SeaRc<dyn Iden>;
 
 #[derive(Debug)]
 #[repr(transparent)]
-pub struct SeaRc<I>(pub(crate) RcOrArc<I>)
+pub struct SeaRc<I>(pub(crate) SeaRcInner<I>)
+where
+    I: ?Sized + 'static;
+
+#[derive(Debug)]
+pub(crate) enum SeaRcInner<I>
 where
-    I: ?Sized;
+    I: ?Sized + 'static,
+{
+    Rc(RcOrArc<I>),
+    Static(&'static I),
+}
 
 impl ops::Deref for SeaRc<dyn Iden> {
     type Target = dyn Iden;
 
     fn deref(&self) -> &Self::Target {
-        ops::Deref::deref(&self.0)
+        match &self.0 {
+            SeaRcInner::Rc(rc) => ops::Deref::deref(rc),
+            SeaRcInner::Static(iden) => *iden,
+        }
     }
 }
 
 impl Clone for SeaRc<dyn Iden> {
     fn clone(&self) -> SeaRc<dyn Iden> {
-        SeaRc(RcOrArc::clone(&self.0))
+        SeaRc(match &self.0 {
+            SeaRcInner::Rc(rc) => SeaRcInner::Rc(RcOrArc::clone(rc)),
+            SeaRcInner::Static(iden) => SeaRcInner::Static(*iden),
+        })
     }
 }
 
 impl PartialEq for SeaRc<dyn Iden> {
     fn eq(&self, other: &Self) -> bool {
         let (self_vtable, other_vtable) = unsafe {
-            let (_, self_vtable) = mem::transmute::<&dyn Iden, (usize, usize)>(&*self.0);
-            let (_, other_vtable) = mem::transmute::<&dyn Iden, (usize, usize)>(&*other.0);
+            let (_, self_vtable) = mem::transmute::<&dyn Iden, (usize, usize)>(&**self);
+            let (_, other_vtable) = mem::transmute::<&dyn Iden, (usize, usize)>(&**other);
             (self_vtable, other_vtable)
         };
         self_vtable == other_vtable && self.to_string() == other.to_string()
@@ -88,7 +138,10 @@ impl SeaRc<dyn Iden> {
     where
         I: Iden + 'static,
     {
-        SeaRc(RcOrArc::new(i))
+        match i.static_iden() {
+            Some(iden) => SeaRc(SeaRcInner::Static(iden)),
+            None => SeaRc(SeaRcInner::Rc(RcOrArc::new(i))),
+        }
     }
 }
 
@@ -143,8 +196,16 @@ pub enum TableRef {
     SubQuery(SelectStatement, DynIden),
     /// Values list with alias
     ValuesList(Vec<ValueTuple>, DynIden),
+    /// Values list with alias and column names
+    ValuesListColumns(Vec<ValueTuple>, DynIden, Vec<DynIden>),
     /// Function call with alias
     FunctionCall(FunctionCall, DynIden),
+    /// Table selected with `ONLY`, excluding the tables inheriting from it
+    #[cfg(feature = "backend-postgres")]
+    Only(Box<TableRef>),
+    /// Table sampled with `TABLESAMPLE`
+    #[cfg(feature = "backend-postgres")]
+    TableSample(Box<TableRef>, crate::extension::postgres::TableSample),
 }
 
 pub trait IntoTableRef {
@@ -164,6 +225,10 @@ pub enum BinOper {
     Or,
     Like,
     NotLike,
+    /// Case-insensitive `LIKE`, see [`ExprTrait::ilike`]
+    ILike,
+    /// Case-insensitive `NOT LIKE`, see [`ExprTrait::not_ilike`]
+    NotILike,
     Is,
     IsNot,
     In,
@@ -187,6 +252,12 @@ pub enum BinOper {
     RShift,
     As,
     Escape,
+    /// `OVERLAPS` between two `(start, end)` pairs, see [`Expr::overlaps`]
+    Overlaps,
+    /// `SIMILAR TO`, see [`ExprTrait::similar_to`]
+    SimilarTo,
+    /// `NOT SIMILAR TO`, see [`ExprTrait::not_similar_to`]
+    NotSimilarTo,
     Custom(&'static str),
     #[cfg(feature = "backend-postgres")]
     PgOperator(PgBinOper),
@@ -313,6 +384,12 @@ pub enum Keyword {
     CurrentDate,
     CurrentTime,
     CurrentTimestamp,
+    /// `CURRENT_TIMESTAMP` with the precision of fractional seconds
+    CurrentTimestampWithPrecision(u32),
+    /// `DEFAULT`, e.g. to reset a column in [`OnConflict::value`]
+    Default,
+    /// Physical row id pseudo column, optionally qualified by a table: `ctid` on Postgres, `rowid` on SQLite
+    RowId(Option<DynIden>),
     Custom(DynIden),
 }
 
@@ -537,7 +614,34 @@ impl TableRef {
             }
             Self::SubQuery(statement, _) => Self::SubQuery(statement, alias.into_iden()),
             Self::ValuesList(values, _) => Self::ValuesList(values, alias.into_iden()),
+            Self::ValuesListColumns(values, _, columns) => {
+                Self::ValuesListColumns(values, alias.into_iden(), columns)
+            }
             Self::FunctionCall(func, _) => Self::FunctionCall(func, alias.into_iden()),
+            #[cfg(feature = "backend-postgres")]
+            Self::Only(table_ref) => Self::Only(Box::new(table_ref.alias(alias))),
+            #[cfg(feature = "backend-postgres")]
+            Self::TableSample(table_ref, sample) => {
+                Self::TableSample(Box::new(table_ref.alias(alias)), sample)
+            }
+        }
+    }
+
+    /// The name other parts of the statement refer to the table by, i.e. the alias if any or the table name
+    pub(crate) fn alias_or_name(&self) -> &DynIden {
+        match self {
+            Self::Table(table)
+            | Self::SchemaTable(_, table)
+            | Self::DatabaseSchemaTable(_, _, table) => table,
+            Self::TableAlias(_, alias)
+            | Self::SchemaTableAlias(_, _, alias)
+            | Self::DatabaseSchemaTableAlias(_, _, _, alias)
+            | Self::SubQuery(_, alias)
+            | Self::ValuesList(_, alias)
+            | Self::ValuesListColumns(_, alias, _)
+            | Self::FunctionCall(_, alias) => alias,
+            #[cfg(feature = "backend-postgres")]
+            Self::Only(table_ref) | Self::TableSample(table_ref, _) => table_ref.alias_or_name(),
         }
     }
 }
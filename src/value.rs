@@ -1,6 +1,7 @@
 //! Container for all SQL value types.
 
 use std::borrow::Cow;
+use std::sync::Arc;
 
 #[cfg(feature = "with-json")]
 use serde_json::Value as Json;
@@ -31,6 +32,9 @@ use std::net::IpAddr;
 #[cfg(feature = "with-mac_address")]
 use mac_address::MacAddress;
 
+#[cfg(feature = "with-bit-vec")]
+use bit_vec::BitVec;
+
 use crate::{ColumnType, CommonSqlQueryBuilder, QueryBuilder, StringLen};
 
 /// [`Value`] types variant for Postgres array
@@ -114,11 +118,20 @@ pub enum ArrayType {
     #[cfg(feature = "with-mac_address")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-mac_address")))]
     MacAddress,
+
+    #[cfg(feature = "with-bit-vec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-bit-vec")))]
+    BitVec,
+
+    #[cfg(feature = "postgres-interval")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres-interval")))]
+    Interval,
 }
 
 /// Value variants
 ///
-/// We want the inner Value to be exactly 1 pointer sized, so anything larger should be boxed.
+/// We want the inner Value to be at most 1 pointer sized, so anything larger should be boxed.
+/// This keeps [`Value::size_of`] the same whichever `with-*` features are enabled.
 ///
 /// If the `hashable-value` feature is enabled, NaN == NaN, which contradicts Rust's built-in
 /// implementation of NaN != NaN.
@@ -213,6 +226,41 @@ pub enum Value {
     #[cfg(feature = "with-mac_address")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-mac_address")))]
     MacAddress(Option<Box<MacAddress>>),
+
+    #[cfg(feature = "with-bit-vec")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-bit-vec")))]
+    BitVec(Option<Box<BitVec>>),
+
+    #[cfg(feature = "postgres-interval")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "postgres-interval")))]
+    Interval(Option<Box<Interval>>),
+}
+
+/// A Postgres `interval`, in the same units as its binary representation
+///
+/// `chrono::Duration` and `time::Duration` convert into an interval of microseconds only,
+/// as Postgres treats days and months as calendar units of varying length.
+/// Date-only intervals set `months` and `days` instead:
+///
+/// ```
+/// use sea_query::{Interval, Value};
+///
+/// let week = Interval {
+///     days: 7,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     Value::from(week).to_string(),
+///     "INTERVAL '0 months 7 days 0.000000 seconds'"
+/// );
+/// ```
+#[cfg(feature = "postgres-interval")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres-interval")))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
 }
 
 impl std::fmt::Display for Value {
@@ -281,6 +329,17 @@ pub trait Nullable {
 }
 
 impl Value {
+    /// Size of a [`Value`] in bytes, 24 on 64-bit targets.
+    ///
+    /// ```
+    /// use sea_query::Value;
+    ///
+    /// assert_eq!(Value::size_of(), std::mem::size_of::<Value>());
+    /// ```
+    pub const fn size_of() -> usize {
+        std::mem::size_of::<Self>()
+    }
+
     pub fn unwrap<T>(self) -> T
     where
         T: ValueType,
@@ -396,6 +455,14 @@ impl Value {
             #[cfg(feature = "with-mac_address")]
             #[cfg_attr(docsrs, doc(cfg(feature = "with-mac_address")))]
             Self::MacAddress(_) => Self::MacAddress(None),
+
+            #[cfg(feature = "with-bit-vec")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "with-bit-vec")))]
+            Self::BitVec(_) => Self::BitVec(None),
+
+            #[cfg(feature = "postgres-interval")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "postgres-interval")))]
+            Self::Interval(_) => Self::Interval(None),
         }
     }
 }
@@ -558,6 +625,20 @@ impl From<Cow<'_, str>> for Value {
     }
 }
 
+/// The string is copied once into the [`Value::String`], as it can't be moved out of the `Arc`
+impl From<Arc<str>> for Value {
+    fn from(x: Arc<str>) -> Value {
+        let string: String = (*x).into();
+        Value::String(Some(Box::new(string)))
+    }
+}
+
+impl Nullable for Arc<str> {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
 impl ValueType for Cow<'_, str> {
     fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
         match v {
@@ -843,6 +924,79 @@ mod with_mac_address {
     type_to_box_value!(MacAddress, MacAddress, MacAddr);
 }
 
+#[cfg(feature = "with-bit-vec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-bit-vec")))]
+mod with_bit_vec {
+    use super::*;
+
+    // Maximum length of `bit varying` in Postgres
+    type_to_box_value!(BitVec, BitVec, VarBit(83_886_080));
+}
+
+#[cfg(feature = "postgres-interval")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres-interval")))]
+mod with_interval {
+    use super::*;
+
+    type_to_box_value!(Interval, Interval, Interval(None, None));
+
+    impl std::fmt::Display for Interval {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let sign = if self.microseconds < 0 { "-" } else { "" };
+            let microseconds = self.microseconds.unsigned_abs();
+            write!(
+                f,
+                "{} months {} days {sign}{}.{:06} seconds",
+                self.months,
+                self.days,
+                microseconds / 1_000_000,
+                microseconds % 1_000_000
+            )
+        }
+    }
+
+    #[cfg(feature = "with-chrono")]
+    impl From<chrono::Duration> for Interval {
+        fn from(duration: chrono::Duration) -> Self {
+            Self {
+                months: 0,
+                days: 0,
+                microseconds: duration
+                    .num_microseconds()
+                    .expect("Duration is too large for an interval"),
+            }
+        }
+    }
+
+    #[cfg(feature = "with-chrono")]
+    impl From<chrono::Duration> for Value {
+        fn from(duration: chrono::Duration) -> Self {
+            Interval::from(duration).into()
+        }
+    }
+
+    #[cfg(feature = "with-time")]
+    impl From<time::Duration> for Interval {
+        fn from(duration: time::Duration) -> Self {
+            Self {
+                months: 0,
+                days: 0,
+                microseconds: duration
+                    .whole_microseconds()
+                    .try_into()
+                    .expect("Duration is too large for an interval"),
+            }
+        }
+    }
+
+    #[cfg(feature = "with-time")]
+    impl From<time::Duration> for Value {
+        fn from(duration: time::Duration) -> Self {
+            Interval::from(duration).into()
+        }
+    }
+}
+
 #[cfg(feature = "postgres-array")]
 #[cfg_attr(docsrs, doc(cfg(feature = "postgres-array")))]
 pub mod with_array {
@@ -868,7 +1022,7 @@ pub mod with_array {
     impl NotU8 for String {}
     impl NotU8 for Vec<u8> {}
 
-    // TODO impl<T: NotU8> NotU8 for Option<T> {}
+    impl<T: NotU8> NotU8 for Option<T> {}
 
     #[cfg(feature = "with-json")]
     impl NotU8 for Json {}
@@ -924,6 +1078,12 @@ pub mod with_array {
     #[cfg(feature = "with-mac_address")]
     impl NotU8 for MacAddress {}
 
+    #[cfg(feature = "with-bit-vec")]
+    impl NotU8 for BitVec {}
+
+    #[cfg(feature = "postgres-interval")]
+    impl NotU8 for Interval {}
+
     impl<T> From<Vec<T>> for Value
     where
         T: Into<Value> + NotU8 + ValueType,
@@ -1315,6 +1475,34 @@ impl Value {
     }
 }
 
+#[cfg(feature = "with-bit-vec")]
+impl Value {
+    pub fn is_bit_vec(&self) -> bool {
+        matches!(self, Self::BitVec(_))
+    }
+
+    pub fn as_ref_bit_vec(&self) -> Option<&BitVec> {
+        match self {
+            Self::BitVec(v) => box_to_opt_ref!(v),
+            _ => panic!("not Value::BitVec"),
+        }
+    }
+}
+
+#[cfg(feature = "postgres-interval")]
+impl Value {
+    pub fn is_interval(&self) -> bool {
+        matches!(self, Self::Interval(_))
+    }
+
+    pub fn as_ref_interval(&self) -> Option<&Interval> {
+        match self {
+            Self::Interval(v) => box_to_opt_ref!(v),
+            _ => panic!("not Value::Interval"),
+        }
+    }
+}
+
 impl IntoIterator for ValueTuple {
     type Item = Value;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -1517,6 +1705,10 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         Value::IpNetwork(None) => Json::Null,
         #[cfg(feature = "with-mac_address")]
         Value::MacAddress(None) => Json::Null,
+        #[cfg(feature = "with-bit-vec")]
+        Value::BitVec(None) => Json::Null,
+        #[cfg(feature = "postgres-interval")]
+        Value::Interval(None) => Json::Null,
         Value::Bool(Some(b)) => Json::Bool(*b),
         Value::TinyInt(Some(v)) => (*v).into(),
         Value::SmallInt(Some(v)) => (*v).into(),
@@ -1574,6 +1766,12 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         Value::IpNetwork(Some(_)) => CommonSqlQueryBuilder.value_to_string(value).into(),
         #[cfg(feature = "with-mac_address")]
         Value::MacAddress(Some(_)) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-bit-vec")]
+        Value::BitVec(Some(v)) => {
+            Json::String(v.iter().map(|b| if b { '1' } else { '0' }).collect())
+        }
+        #[cfg(feature = "postgres-interval")]
+        Value::Interval(Some(v)) => Json::String(v.to_string()),
     }
 }
 
@@ -1581,6 +1779,11 @@ impl Values {
     pub fn iter(&self) -> impl Iterator<Item = &Value> {
         self.0.iter()
     }
+
+    /// Borrow the values as a slice, without cloning them
+    pub fn as_slice(&self) -> &[Value] {
+        &self.0
+    }
 }
 
 impl IntoIterator for Values {
@@ -1592,6 +1795,15 @@ impl IntoIterator for Values {
     }
 }
 
+impl<'a> IntoIterator for &'a Values {
+    type Item = &'a Value;
+    type IntoIter = std::slice::Iter<'a, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1659,6 +1871,28 @@ mod tests {
         assert_eq!(out, val2);
     }
 
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_value_size() {
+        // A payload over 1 pointer in size must be boxed, whichever features are enabled
+        assert_eq!(Value::size_of(), 24);
+    }
+
+    #[test]
+    fn test_arc_str_value() {
+        assert_eq!(
+            Value::from(Arc::<str>::from("x")),
+            Value::String(Some(Box::new("x".into())))
+        );
+        assert_eq!(Value::from(None::<Arc<str>>), Value::String(None));
+
+        let owned: Cow<'static, str> = Cow::Owned("y".to_owned());
+        assert_eq!(
+            Value::from(owned),
+            Value::String(Some(Box::new("y".into())))
+        );
+    }
+
     #[test]
     fn test_box_value() {
         let val: String = "hello".to_owned();
@@ -2019,6 +2253,54 @@ mod tests {
         assert_eq!(out.to_string(), num);
     }
 
+    #[test]
+    #[cfg(feature = "with-bit-vec")]
+    fn test_bit_vec_value() {
+        let bits = BitVec::from_bytes(&[0b1010_0000]);
+        let v: Value = bits.clone().into();
+        assert_eq!(v.to_string(), "B'10100000'");
+        let out: BitVec = v.unwrap();
+        assert_eq!(out, bits);
+    }
+
+    #[test]
+    #[cfg(all(feature = "postgres-interval", feature = "with-chrono"))]
+    fn test_interval_value() {
+        let v: Value = chrono::Duration::milliseconds(-90_500).into();
+        assert_eq!(
+            v.to_string(),
+            "INTERVAL '0 months 0 days -90.500000 seconds'"
+        );
+        let out: Interval = v.unwrap();
+        assert_eq!(
+            out,
+            Interval {
+                months: 0,
+                days: 0,
+                microseconds: -90_500_000,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "postgres-interval", feature = "with-time"))]
+    fn test_interval_value_time() {
+        let v: Value = time::Duration::days(2).into();
+        assert_eq!(
+            v.to_string(),
+            "INTERVAL '0 months 0 days 172800.000000 seconds'"
+        );
+        assert_eq!(
+            Value::from(Interval {
+                months: 14,
+                days: -3,
+                microseconds: 0,
+            })
+            .to_string(),
+            "INTERVAL '14 months -3 days 0.000000 seconds'"
+        );
+    }
+
     #[test]
     #[cfg(feature = "postgres-array")]
     fn test_array_value() {
@@ -2035,6 +2317,44 @@ mod tests {
         let out: Option<Vec<i32>> = v.unwrap();
         assert_eq!(out, None);
     }
+
+    #[test]
+    #[cfg(feature = "postgres-array")]
+    fn test_array_value_with_null() {
+        let value: Value = vec![Some(1i32), None, Some(3)].into();
+        assert_eq!(
+            value,
+            Value::Array(
+                ArrayType::Int,
+                Some(Box::new(vec![
+                    Value::Int(Some(1)),
+                    Value::Int(None),
+                    Value::Int(Some(3))
+                ]))
+            )
+        );
+        assert_eq!(
+            value.unwrap::<Vec<Option<i32>>>(),
+            vec![Some(1), None, Some(3)]
+        );
+    }
+
+    #[test]
+    fn test_values_borrowed_iter() {
+        let values = Values(vec![
+            Value::Int(Some(1)),
+            Value::Bytes(Some(Box::new(vec![0u8; 1024]))),
+        ]);
+
+        let borrowed: Vec<&Value> = (&values).into_iter().collect();
+        assert_eq!(borrowed.len(), 2);
+        assert!(std::ptr::eq(borrowed[1], &values.as_slice()[1]));
+
+        for (value, owned) in (&values).into_iter().zip(values.0.iter()) {
+            assert!(std::ptr::eq(value, owned));
+        }
+        assert_eq!(values.as_slice(), values.0.as_slice());
+    }
 }
 
 #[cfg(feature = "hashable-value")]
@@ -2114,6 +2434,12 @@ mod hashable_value {
                 #[cfg(feature = "with-mac_address")]
                 (Self::MacAddress(l), Self::MacAddress(r)) => l == r,
 
+                #[cfg(feature = "with-bit-vec")]
+                (Self::BitVec(l), Self::BitVec(r)) => l == r,
+
+                #[cfg(feature = "postgres-interval")]
+                (Self::Interval(l), Self::Interval(r)) => l == r,
+
                 _ => false,
             }
         }
@@ -2188,6 +2514,12 @@ mod hashable_value {
 
                 #[cfg(feature = "with-mac_address")]
                 Value::MacAddress(mac_address) => mac_address.hash(state),
+
+                #[cfg(feature = "with-bit-vec")]
+                Value::BitVec(bit_vec) => bit_vec.hash(state),
+
+                #[cfg(feature = "postgres-interval")]
+                Value::Interval(interval) => interval.hash(state),
             }
         }
     }
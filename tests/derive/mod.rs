@@ -77,3 +77,32 @@ fn derive_4() {
     assert_eq!(Iden::to_string(&SomeType), "some_type");
     assert_eq!(Iden::to_string(&CustomName), "another_name");
 }
+
+#[test]
+fn derive_static_into_iden() {
+    #[derive(Debug, Clone, Copy, IdenStatic)]
+    enum User {
+        Table,
+        Id,
+    }
+
+    #[derive(Debug, Clone, Copy, IdenStatic)]
+    enum Post {
+        Table,
+        Id,
+    }
+
+    fn addr(iden: &DynIden) -> *const () {
+        &**iden as *const dyn Iden as *const ()
+    }
+
+    // Static idens refer to the same constant
+    let id = User::Id.into_iden();
+    assert_eq!(addr(&id), addr(&User::Id.into_iden()));
+    assert_eq!(addr(&id), addr(&SeaRc::new(User::Id)));
+    assert_eq!(id.to_string(), "id");
+    assert_eq!(id, User::Id.into_iden());
+    assert_eq!(id, SeaRc::new(User::Id));
+    assert_ne!(id, User::Table.into_iden());
+    assert_ne!(id, Post::Id.into_iden());
+}
@@ -17,3 +17,128 @@ fn insert_values_1() {
         }
     );
 }
+
+#[test]
+fn insert_values_from_vec() {
+    let mut insert = Query::insert();
+    insert
+        .into_table(Glyph::Table)
+        .columns([Glyph::Image, Glyph::Aspect]);
+
+    assert!(insert.values_from_vec(vec!["A".into(), 1.5.into()]).is_ok());
+    assert_eq!(
+        insert
+            .values_from_vec(vec!["B".into(), 2.5.into(), 3.into()])
+            .unwrap_err(),
+        Error::ColValNumMismatch {
+            col_len: 2,
+            val_len: 3,
+        }
+    );
+    assert_eq!(
+        Error::ColValNumMismatch {
+            col_len: 2,
+            val_len: 3,
+        }
+        .to_string(),
+        "Columns and values length mismatch: 2 != 3"
+    );
+}
+
+#[test]
+fn insert_select_column_mismatch() {
+    let result = Query::insert()
+        .into_table(Glyph::Table)
+        .columns([Glyph::Image, Glyph::Aspect])
+        .select_from(
+            Query::select()
+                .column(Glyph::Image)
+                .from(Glyph::Table)
+                .to_owned(),
+        )
+        .map(|_| ());
+
+    assert_eq!(
+        result.unwrap_err(),
+        Error::ColValNumMismatch {
+            col_len: 2,
+            val_len: 1,
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "backend-mysql")]
+fn insert_returning_unsupported() {
+    let insert = Query::insert()
+        .into_table(Glyph::Table)
+        .columns([Glyph::Image])
+        .values_panic(["12A".into()])
+        .returning_col(Glyph::Id)
+        .to_owned();
+
+    assert_eq!(
+        insert.try_build(MysqlQueryBuilder).unwrap_err(),
+        Error::ReturningUnsupported
+    );
+    assert_eq!(
+        Error::ReturningUnsupported.to_string(),
+        "RETURNING is not supported by the backend; use last_insert_id instead"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "backend-mysql", feature = "backend-postgres"))]
+fn select_distinct_order_not_selected() {
+    let select = Query::select()
+        .distinct()
+        .expr_as(Expr::col(Char::SizeW).add(1), Alias::new("width"))
+        .column(Char::Character)
+        .from(Char::Table)
+        .order_by(Alias::new("width"), Order::Asc)
+        .order_by(Char::Character, Order::Asc)
+        .to_owned();
+
+    assert!(select.try_build(PostgresQueryBuilder).is_ok());
+    assert_eq!(
+        select
+            .to_owned()
+            .order_by(Char::FontId, Order::Asc)
+            .try_build(MysqlQueryBuilder)
+            .unwrap_err(),
+        Error::DistinctOrderNotSelected
+    );
+    assert_eq!(
+        Error::DistinctOrderNotSelected.to_string(),
+        "ORDER BY expressions of SELECT DISTINCT must appear in the select list"
+    );
+}
+
+#[test]
+#[cfg(feature = "backend-postgres")]
+fn select_fetch_with_ties_without_order() {
+    let select = Query::select()
+        .column(Glyph::Aspect)
+        .from(Glyph::Table)
+        .fetch_first(10, true)
+        .to_owned();
+
+    assert_eq!(
+        select.try_build(PostgresQueryBuilder).unwrap_err(),
+        Error::FetchWithTiesWithoutOrder
+    );
+    assert!(select
+        .to_owned()
+        .fetch_first(10, false)
+        .try_build(PostgresQueryBuilder)
+        .is_ok());
+    assert!(select
+        .to_owned()
+        .order_by(Glyph::Aspect, Order::Desc)
+        .try_build(PostgresQueryBuilder)
+        .is_ok());
+    assert_eq!(
+        Error::FetchWithTiesWithoutOrder.to_string(),
+        "FETCH FIRST ... WITH TIES requires an ORDER BY clause"
+    );
+}
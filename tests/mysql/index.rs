@@ -53,6 +53,30 @@ fn create_4() {
     );
 }
 
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name-language")
+            .table(Font::Table)
+            .col_expr(Func::lower(Expr::col(Font::Name)))
+            .col_expr(Func::coalesce([Expr::col(Font::Language).into(), "en".into()]))
+            .to_string(MysqlQueryBuilder),
+        "CREATE INDEX `idx-font-name-language` ON `font` ((LOWER(`name`)), (COALESCE(`language`, 'en')))"
+    );
+}
+
+#[test]
+#[should_panic(expected = "INCLUDE columns are only supported by Postgres")]
+fn create_include() {
+    let _ = Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .include([Glyph::Image])
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
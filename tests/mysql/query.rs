@@ -1071,6 +1071,685 @@ fn md5_fn() {
     );
 }
 
+#[test]
+fn select_group_by_rollup() {
+    assert_eq!(
+        Query::select()
+            .columns([Char::FontId, Char::SizeW])
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeH).gt(2))
+            .group_by_rollup([Char::FontId, Char::SizeW])
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT `font_id`, `size_w`, COUNT(`id`) FROM `character` WHERE `size_h` > ? GROUP BY `font_id`, `size_w` WITH ROLLUP".to_owned(),
+            Values(vec![2.into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql only supports ROLLUP of the whole GROUP BY list")]
+fn select_group_by_cube() {
+    Query::select()
+        .columns([Char::FontId, Char::SizeW])
+        .from(Char::Table)
+        .group_by_cube([Char::FontId, Char::SizeW])
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_explain() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeW).gt(10))
+            .to_owned()
+            .explain()
+            .build(MysqlQueryBuilder),
+        (
+            "EXPLAIN FORMAT=JSON SELECT `id` FROM `character` WHERE `size_w` > ?".to_owned(),
+            Values(vec![10.into()])
+        )
+    );
+}
+
+#[test]
+fn select_hash_mod_bucket() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Func::hash_mod(Expr::col(Char::Id), 100).lt(5))
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT `id` FROM `character` WHERE CRC32(`id`) % ? < ?".to_owned(),
+            Values(vec![100u32.into(), 5.into()])
+        )
+    );
+}
+
+#[test]
+fn select_aggregate_filter() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::sum(Expr::col(Char::SizeW).mul(2)).filter(Expr::col(Char::SizeH).gt(3)))
+            .from(Char::Table)
+            .group_by_col(Char::FontId)
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT `font_id`, SUM((CASE WHEN (`size_h` > ?) THEN `size_w` * ? END)) FROM `character` GROUP BY `font_id`".to_owned(),
+            Values(vec![3.into(), 2.into()])
+        )
+    );
+}
+
+#[test]
+fn select_format_date() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::format_date(
+                Expr::col(Char::CreatedAt),
+                "%d/%m/%Y at %H:%M:%S (100%%)"
+            ))
+            .from(Char::Table)
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT DATE_FORMAT(`created_at`, ?) FROM `character`".to_owned(),
+            Values(vec!["%d/%m/%Y at %H:%i:%s (100%%)".into()])
+        )
+    );
+}
+
+#[test]
+fn select_join_values_list() {
+    assert_eq!(
+        Query::select()
+            .columns([(Char::Table, Char::Character)])
+            .column((Alias::new("t"), Alias::new("label")))
+            .from(Char::Table)
+            .inner_join(
+                Query::values_list(
+                    [(1i32, "small"), (2, "large")],
+                    Alias::new("t"),
+                    [Alias::new("id"), Alias::new("label")],
+                ),
+                Expr::col((Char::Table, Char::Id)).equals((Alias::new("t"), Alias::new("id"))),
+            )
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `character`.`character`, `t`.`label` FROM `character`",
+                "INNER JOIN (VALUES ROW(?, ?), ROW(?, ?)) AS `t`(`id`, `label`)",
+                "ON `character`.`id` = `t`.`id`",
+            ]
+            .join(" "),
+            Values(vec![
+                1i32.into(),
+                "small".into(),
+                2i32.into(),
+                "large".into()
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_append_raw() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(5))
+            .limit(10)
+            .append_raw("LOCK IN SHARE MODE", Vec::<Value>::new())
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT `character` FROM `character` WHERE `font_id` = ? LIMIT ? LOCK IN SHARE MODE"
+                .to_owned(),
+            Values(vec![5.into(), 10u64.into()])
+        )
+    );
+}
+
+#[test]
+fn select_lock_skip_locked() {
+    assert_eq!(
+        Query::select()
+            .column((Glyph::Table, Glyph::Id))
+            .from(Glyph::Table)
+            .inner_join(
+                Font::Table,
+                Expr::col((Glyph::Table, Glyph::Id)).equals((Font::Table, Font::Id)),
+            )
+            .and_where(Expr::col((Glyph::Table, Glyph::Aspect)).lt(1))
+            .limit(1)
+            .lock_with_tables_behavior(
+                LockType::Update,
+                [Glyph::Table.into_table_ref(), Font::Table.into_table_ref()],
+                LockBehavior::SkipLocked,
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT `glyph`.`id` FROM `glyph`",
+            "INNER JOIN `font` ON `glyph`.`id` = `font`.`id`",
+            "WHERE `glyph`.`aspect` < 1 LIMIT 1",
+            "FOR UPDATE OF `glyph`, `font` SKIP LOCKED",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_intersect_all() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Id, Glyph::Aspect])
+            .from(Alias::new("glyph_new"))
+            .union(
+                UnionType::IntersectAll,
+                Query::select()
+                    .columns([Glyph::Id, Glyph::Aspect])
+                    .from(Alias::new("glyph_old"))
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT `id`, `aspect` FROM `glyph_new`",
+            "INTERSECT ALL (SELECT `id`, `aspect` FROM `glyph_old`)",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "SEARCH and CYCLE clauses of a common table expression are only supported by Postgres"
+)]
+fn with_recursive_search_cycle() {
+    let mut base = Query::select()
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .from(Alias::new("node"))
+        .and_where(Expr::col(Alias::new("id")).eq(1))
+        .to_owned();
+    let step = Query::select()
+        .columns([
+            (Alias::new("n"), Alias::new("id")),
+            (Alias::new("n"), Alias::new("parent_id")),
+        ])
+        .from_as(Alias::new("node"), Alias::new("n"))
+        .inner_join(
+            Alias::new("tree"),
+            Expr::col((Alias::new("n"), Alias::new("parent_id")))
+                .equals((Alias::new("tree"), Alias::new("id"))),
+        )
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .query(base.union(UnionType::All, step).to_owned())
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .table_name(Alias::new("tree"))
+        .search_breadth_first([Alias::new("id")], Alias::new("ord"))
+        .cycle(
+            [Alias::new("id")],
+            Alias::new("is_cycle"),
+            Alias::new("path"),
+        )
+        .to_owned();
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .and_where(Expr::col(Alias::new("is_cycle")).not())
+        .order_by(Alias::new("ord"), Order::Asc)
+        .to_owned()
+        .with(WithClause::new().recursive(true).cte(cte).to_owned());
+
+    query.to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_match_against_boolean_mode() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Id)
+            .from(Font::Table)
+            .and_where(
+                MySqlFunc::match_against(
+                    [Font::Name, Font::Variant],
+                    "+serif -mono",
+                    MatchAgainstMode::Boolean
+                )
+                .into()
+            )
+            .and_where(Expr::col(Font::Language).eq("en"))
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `id` FROM `font`",
+                "WHERE MATCH (`name`, `variant`) AGAINST (? IN BOOLEAN MODE) AND `language` = ?",
+            ]
+            .join(" "),
+            Values(vec!["+serif -mono".into(), "en".into()])
+        )
+    );
+}
+
+#[test]
+fn select_match_against_natural_language_mode() {
+    let relevance = || {
+        MySqlFunc::match_against(
+            [(Font::Table, Font::Name)],
+            "sans serif",
+            MatchAgainstMode::NaturalLanguage,
+        )
+    };
+    assert_eq!(
+        Query::select()
+            .column(Font::Id)
+            .expr_as(relevance(), Alias::new("score"))
+            .from(Font::Table)
+            .and_where(Expr::expr(relevance()).gt(0))
+            .order_by(Alias::new("score"), Order::Desc)
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `id`, MATCH (`font`.`name`) AGAINST (? IN NATURAL LANGUAGE MODE) AS `score`",
+                "FROM `font`",
+                "WHERE MATCH (`font`.`name`) AGAINST (? IN NATURAL LANGUAGE MODE) > ?",
+                "ORDER BY `score` DESC",
+            ]
+            .join(" "),
+            Values(vec!["sans serif".into(), "sans serif".into(), 0.into()])
+        )
+    );
+}
+
+#[test]
+fn select_match_against_with_query_expansion() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Id)
+            .from(Font::Table)
+            .and_where(
+                MySqlFunc::match_against(
+                    [Font::Name],
+                    "serif",
+                    MatchAgainstMode::WithQueryExpansion
+                )
+                .into()
+            )
+            .build(MysqlQueryBuilder),
+        (
+            r#"SELECT `id` FROM `font` WHERE MATCH (`name`) AGAINST (? WITH QUERY EXPANSION)"#
+                .to_owned(),
+            Values(vec!["serif".into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "TABLESAMPLE is only supported by Postgres")]
+fn select_from_sampled() {
+    use sea_query::extension::postgres::{PostgresSelectStatementExt, TableSampleMethod};
+
+    Query::select()
+        .column(Char::Character)
+        .from_sampled(Char::Table, TableSampleMethod::System, 25.0, None)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_ilike() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Name).ilike("sans%"))
+            .and_where(Expr::col(Font::Variant).not_ilike(LikeExpr::new("%|_mono").escape('|')))
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `name` FROM `font` WHERE LOWER(`name`) LIKE LOWER(?)",
+                "AND LOWER(`variant`) NOT LIKE LOWER(?) ESCAPE '|'",
+            ]
+            .join(" "),
+            Values(vec!["sans%".into(), "%|_mono".into()])
+        )
+    );
+}
+
+#[test]
+fn select_page_with_total_count() {
+    assert_eq!(
+        Query::select()
+            .columns([Char::Id, Char::Character])
+            .expr_window_as(
+                Func::count_star(),
+                WindowStatement::new(),
+                Alias::new("total")
+            )
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(5))
+            .order_by(Char::Id, Order::Asc)
+            .limit(10)
+            .offset(20)
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `id`, `character`, COUNT(*) OVER () AS `total`",
+                "FROM `character`",
+                "WHERE `font_id` = ?",
+                "ORDER BY `id` ASC LIMIT ? OFFSET ?",
+            ]
+            .join(" "),
+            Values(vec![5.into(), 10u64.into(), 20u64.into()])
+        )
+    );
+}
+
+#[test]
+fn select_pg_cast() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Char::FontId).pg_cast(ColumnType::Text))
+            .from(Char::Table)
+            .and_where(
+                Expr::col(Char::CreatedAt).gt(Expr::val("2024-01-01").pg_cast(ColumnType::Date))
+            )
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT CAST(`font_id` AS char) FROM `character`",
+                "WHERE `created_at` > CAST(? AS date)",
+            ]
+            .join(" "),
+            Values(vec!["2024-01-01".into()])
+        )
+    );
+}
+
+#[test]
+fn select_pg_cast_mapped_types() {
+    assert_eq!(
+        Query::select()
+            .exprs([
+                Expr::col(Char::SizeW).pg_cast(ColumnType::Boolean),
+                Expr::col(Char::Character).pg_cast(ColumnType::Blob),
+                Expr::col(Char::Character).pg_cast(ColumnType::VarBinary(StringLen::N(8))),
+                Expr::col(Char::CreatedAt).pg_cast(ColumnType::Timestamp(Some(3))),
+                Expr::col(Char::SizeH).pg_cast(ColumnType::Money(Some((10, 2)))),
+                Expr::col(Char::Id).pg_cast(ColumnType::Uuid),
+            ])
+            .from(Char::Table)
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT CAST(`size_w` AS signed), CAST(`character` AS binary),",
+            "CAST(`character` AS binary(8)), CAST(`created_at` AS datetime(3)),",
+            "CAST(`size_h` AS decimal(10, 2)), CAST(`id` AS char(36)) FROM `character`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support CAST AS Bit(Some(1))")]
+fn select_pg_cast_bit() {
+    Query::select()
+        .expr(Expr::col(Char::SizeW).pg_cast(ColumnType::Bit(Some(1))))
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_string_agg() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Language)
+            .expr(
+                Func::string_agg(Expr::col(Font::Name), ", ")
+                    .distinct()
+                    .order_by(Font::Name, Order::Asc),
+            )
+            .from(Font::Table)
+            .group_by_col(Font::Language)
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT `language`, GROUP_CONCAT(DISTINCT `name` ORDER BY `name` ASC SEPARATOR ', ') FROM `font` GROUP BY `language`"#
+    );
+}
+
+#[test]
+fn select_string_agg_filter() {
+    assert_eq!(
+        Query::select()
+            .expr(
+                Func::string_agg(Expr::col(Font::Name), ", ")
+                    .filter(Expr::col(Font::Variant).eq("Mono")),
+            )
+            .from(Font::Table)
+            .to_string(MysqlQueryBuilder),
+        r#"SELECT GROUP_CONCAT((CASE WHEN (`variant` = 'Mono') THEN `name` END) SEPARATOR ', ') FROM `font`"#
+    );
+}
+
+#[test]
+fn select_coalesce_subquery() {
+    let latest_size = Query::select()
+        .column(Char::FontSize)
+        .from(Char::Table)
+        .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+        .and_where(Expr::col(Char::SizeW).gt(10))
+        .order_by(Char::CreatedAt, Order::Desc)
+        .limit(1)
+        .to_owned();
+
+    assert_eq!(
+        Query::select()
+            .column(Font::Id)
+            .expr_as(
+                Func::coalesce([Expr::subquery(latest_size), Expr::val(0).into()]),
+                Alias::new("latest_size")
+            )
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Language).eq("en"))
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `id`, COALESCE((SELECT `font_size` FROM `character`",
+                "WHERE `character`.`font_id` = `font`.`id` AND `size_w` > ?",
+                "ORDER BY `created_at` DESC LIMIT ?), ?) AS `latest_size`",
+                "FROM `font` WHERE `language` = ?",
+            ]
+            .join(" "),
+            Values(vec![10.into(), 1u64.into(), 0.into(), "en".into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support OVERLAPS")]
+fn select_overlaps() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::overlaps(
+            ("2020-01-01", "2020-02-01"),
+            ("2020-01-15", "2020-03-01"),
+        ))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support SIMILAR TO, use REGEXP instead")]
+fn select_similar_to() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::Character).similar_to("%(b|d)%"))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_cust_with_exprs() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::cust_with_exprs(
+                "? < COALESCE(?, ?)",
+                [
+                    Expr::val(10).into(),
+                    Expr::col(Char::FontSize).into(),
+                    Expr::val(12).into()
+                ],
+            ))
+            .build(MysqlQueryBuilder),
+        (
+            r"SELECT `id` FROM `character` WHERE ? < COALESCE(`font_size`, ?)".to_owned(),
+            Values(vec![10.into(), 12.into()])
+        )
+    );
+}
+
+#[test]
+fn select_group_by_case() {
+    let bucket = Expr::case(Expr::col(Char::SizeW).lt(18), "minor").finally("adult");
+    assert_eq!(
+        Query::select()
+            .expr_as(bucket.clone(), Alias::new("bucket"))
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .group_by_expr(bucket)
+            .to_string(MysqlQueryBuilder),
+        [
+            r"SELECT (CASE WHEN (`size_w` < 18) THEN 'minor' ELSE 'adult' END) AS `bucket`, COUNT(`id`)",
+            r"FROM `character` GROUP BY (CASE WHEN (`size_w` < 18) THEN 'minor' ELSE 'adult' END)",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_window_frame() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                WindowStatement::new()
+                    .order_by(Char::Id, Order::Asc)
+                    .frame_between(FrameType::Rows, Frame::Preceding(2), Frame::CurrentRow)
+                    .take(),
+                Alias::new("moving_avg"),
+            )
+            .from(Char::Table)
+            .build(MysqlQueryBuilder),
+        (
+            [
+                r"SELECT `id`, AVG(`size_w`) OVER ( ORDER BY `id` ASC ROWS BETWEEN 2 PRECEDING AND CURRENT ROW )",
+                r"AS `moving_avg` FROM `character`",
+            ]
+            .join(" "),
+            Values(vec![])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support GROUPS frames")]
+fn select_window_frame_groups() {
+    Query::select()
+        .column(Char::Id)
+        .expr_window_as(
+            Func::avg(Expr::col(Char::SizeW)),
+            WindowStatement::new()
+                .order_by(Char::Id, Order::Asc)
+                .frame_between(FrameType::Groups, Frame::Preceding(2), Frame::CurrentRow)
+                .take(),
+            Alias::new("moving_avg"),
+        )
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_named_window() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_name_as(
+                Func::sum(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("total")
+            )
+            .expr_window_name_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("average")
+            )
+            .from(Char::Table)
+            .and_having(Expr::col(Char::Id).count().gt(1))
+            .group_by_col(Char::Id)
+            .window(
+                Alias::new("w"),
+                WindowStatement::partition_by(Char::FontId)
+                    .order_by(Char::Id, Order::Asc)
+                    .to_owned(),
+            )
+            .order_by(Char::Id, Order::Desc)
+            .to_string(MysqlQueryBuilder),
+        [
+            r"SELECT `id`, SUM(`size_w`) OVER `w` AS `total`, AVG(`size_w`) OVER `w` AS `average`",
+            r"FROM `character` GROUP BY `id` HAVING COUNT(`id`) > 1",
+            r"WINDOW `w` AS (PARTITION BY `font_id` ORDER BY `id` ASC)",
+            r"ORDER BY `id` DESC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support FETCH FIRST, use LIMIT instead")]
+fn select_fetch_first() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .order_by(Glyph::Aspect, Order::Desc)
+        .fetch_first(10, true)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_greatest_ignore_nulls() {
+    let query = Query::select()
+        .expr(Func::greatest_ignore_nulls([
+            Expr::col(Char::SizeW).into(),
+            Expr::col(Char::SizeH).into(),
+            Expr::val(0).into(),
+        ]))
+        .expr(Func::least_ignore_nulls([Expr::col(Char::SizeW).into()]))
+        .from(Char::Table)
+        .to_owned();
+
+    assert_eq!(
+        query.build(MysqlQueryBuilder),
+        (
+            r#"SELECT GREATEST(COALESCE(`size_w`, `size_h`, ?), COALESCE(`size_h`, `size_w`, ?), COALESCE(?, `size_w`, `size_h`)), `size_w` FROM `character`"#
+                .to_owned(),
+            Values(vec![0i32.into(), 0i32.into(), 0i32.into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't have a physical row id")]
+fn select_row_id() {
+    Query::select()
+        .expr(Expr::row_id())
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 fn insert_2() {
@@ -1417,6 +2096,42 @@ fn insert_on_conflict_do_nothing_on() {
     );
 }
 
+#[test]
+fn insert_on_conflict_default() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .values_panic(["abcd".into(), 3.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .value(Glyph::Image, Keyword::Default)
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            r"INSERT INTO `glyph` (`aspect`, `image`) VALUES ('abcd', 3)",
+            r"ON DUPLICATE KEY UPDATE `image` = DEFAULT",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_random_sample() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::expr(Func::random()).lt(0.05))
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT `id` FROM `glyph` WHERE RAND() < ?".to_owned(),
+            Values(vec![0.05.into()])
+        )
+    );
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -1468,6 +2183,57 @@ fn update_4() {
     );
 }
 
+#[test]
+fn update_case_value() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(
+                Glyph::Image,
+                Expr::case(Expr::col(Glyph::Aspect).gt(2), "large").finally(Expr::col(Glyph::Image))
+            )
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(MysqlQueryBuilder),
+        "UPDATE `glyph` SET `image` = (CASE WHEN (`aspect` > 2) THEN 'large' ELSE `image` END) WHERE `id` = 1"
+    );
+}
+
+#[test]
+fn update_from_sub_query() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .from(TableRef::SubQuery(
+                Query::select()
+                    .column(Char::Id)
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::FontId).eq(5))
+                    .to_owned(),
+                Alias::new("sub").into_iden(),
+            ))
+            .value(Glyph::Aspect, 2)
+            .and_where(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("sub"), Glyph::Id)))
+            .and_where(Expr::col((Glyph::Table, Glyph::Image)).like("A%"))
+            .build(MysqlQueryBuilder),
+        (
+            "UPDATE `glyph`, (SELECT `id` FROM `character` WHERE `font_id` = ?) AS `sub` SET `glyph`.`aspect` = ? WHERE `glyph`.`id` = `sub`.`id` AND `glyph`.`image` LIKE ?".to_owned(),
+            Values(vec![5.into(), 2.into(), "A%".into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql doesn't support assigning a row of columns in UPDATE")]
+fn update_value_tuple() {
+    Query::update()
+        .table(Glyph::Table)
+        .value_tuple(
+            [Glyph::Image, Glyph::Tokens],
+            Expr::tuple(["A".into(), "B".into()]),
+        )
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -1481,6 +2247,24 @@ fn delete_1() {
     );
 }
 
+#[test]
+fn delete_using() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Char::Table)
+            .using(Font::Table)
+            .cond_where(all![
+                Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)),
+                Expr::col((Font::Table, Font::Language)).eq("fr"),
+            ])
+            .build(MysqlQueryBuilder),
+        (
+            "DELETE FROM `character` USING `character`, `font` WHERE `character`.`font_id` = `font`.`id` AND `font`.`language` = ?".to_owned(),
+            Values(vec!["fr".into()])
+        )
+    );
+}
+
 #[test]
 fn escape_1() {
     let test = r#" "abc" "#;
@@ -1577,3 +2361,122 @@ fn sub_query_with_fn() {
         "SELECT ARRAY((SELECT * FROM `character`))"
     );
 }
+
+#[test]
+fn select_extract_epoch_duration() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                Func::timestamp_diff(
+                    Expr::col(Alias::new("started_at")),
+                    Expr::col(Alias::new("ended_at"))
+                ),
+                Alias::new("duration")
+            )
+            .from(Alias::new("session"))
+            .and_where(
+                Expr::expr(Func::extract(DatePart::Epoch, Expr::col(Char::CreatedAt))).gt(1000)
+            )
+            .build(MysqlQueryBuilder),
+        (
+            "SELECT TIMESTAMPDIFF(SECOND, `started_at`, `ended_at`) AS `duration` FROM `session` WHERE UNIX_TIMESTAMP(`created_at`) > ?"
+                .to_owned(),
+            Values(vec![1000i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_extract_epoch_subtraction() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::extract(
+                DatePart::Epoch,
+                Expr::col(Char::CreatedAt).sub(Expr::col(Char::SizeW))
+            ))
+            .from(Char::Table)
+            .to_string(MysqlQueryBuilder),
+        "SELECT UNIX_TIMESTAMP(`created_at` - `size_w`) FROM `character`"
+    );
+}
+
+#[test]
+fn select_first_row_per_group() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Id, Glyph::Image])
+            .from_subquery(
+                Query::select()
+                    .columns([Glyph::Id, Glyph::Image])
+                    .expr_window_as(
+                        Func::row_number(),
+                        WindowStatement::partition_by(Glyph::Image)
+                            .order_by(Glyph::Aspect, Order::Desc)
+                            .order_by(Glyph::Id, Order::Asc)
+                            .to_owned(),
+                        Alias::new("rn")
+                    )
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned(),
+                Alias::new("ranked")
+            )
+            .and_where(Expr::col(Alias::new("rn")).eq(1))
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `id`, `image` FROM",
+                "(SELECT `id`, `image`, ROW_NUMBER() OVER ( PARTITION BY `image` ORDER BY `aspect` DESC, `id` ASC ) AS `rn` FROM `glyph` WHERE `aspect` > ?) AS `ranked`",
+                "WHERE `rn` = ?",
+            ]
+            .join(" "),
+            Values(vec![2i32.into(), 1i32.into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql does not support FULL OUTER JOIN")]
+fn select_full_outer_join() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .full_outer_join(
+            Font::Table,
+            Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)),
+        )
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[cfg(feature = "backend-postgres")]
+#[should_panic(expected = "not implemented")]
+fn select_width_bucket_unsupported() {
+    Query::select()
+        .expr(PgFunc::width_bucket(Expr::col(Char::SizeW), 0, 100, 10))
+        .from(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn insert_from_select_with_values() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .select_from(
+                Query::select()
+                    .expr(Expr::col(Glyph::Aspect).mul(2))
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Image).like("0%"))
+                    .to_owned()
+            )
+            .unwrap()
+            .build(MysqlQueryBuilder),
+        (
+            "INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect` * ?, `image` FROM `glyph` WHERE `image` LIKE ?".to_owned(),
+            Values(vec![2i32.into(), "0%".into()])
+        )
+    );
+}
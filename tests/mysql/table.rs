@@ -242,6 +242,82 @@ fn create_10() {
     );
 }
 
+#[test]
+fn create_boolean_default() {
+    assert_eq!(
+        Table::create()
+            .table(Task::Table)
+            .col(ColumnDef::new(Task::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Task::IsDone)
+                    .boolean()
+                    .not_null()
+                    .default(false)
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `task` (",
+            "`id` int NOT NULL PRIMARY KEY,",
+            "`is_done` bool NOT NULL DEFAULT FALSE",
+            ")",
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        Table::alter()
+            .table(Task::Table)
+            .add_column(
+                ColumnDef::new(Alias::new("is_public"))
+                    .boolean()
+                    .default(true)
+            )
+            .to_string(MysqlQueryBuilder),
+        "ALTER TABLE `task` ADD COLUMN `is_public` bool DEFAULT TRUE"
+    );
+}
+
+#[test]
+fn create_timestamp_precision() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Char::CreatedAt)
+                    .timestamp_len(6)
+                    .not_null()
+                    .default(Expr::current_timestamp_with_precision(6))
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `character` (",
+            "`id` int NOT NULL PRIMARY KEY,",
+            "`created_at` timestamp(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6)",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_set_drop_default() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .set_default(Char::FontSize, 12)
+            .set_default(Char::CreatedAt, Expr::current_timestamp())
+            .drop_default(Char::Character)
+            .to_string(MysqlQueryBuilder),
+        [
+            "ALTER TABLE `character`",
+            "ALTER COLUMN `font_size` SET DEFAULT 12,",
+            "ALTER COLUMN `created_at` SET DEFAULT (CURRENT_TIMESTAMP),",
+            "ALTER COLUMN `character` DROP DEFAULT",
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -379,3 +455,87 @@ fn alter_with_check_constraint() {
         r"ALTER TABLE `glyph` ADD COLUMN `aspect` int NOT NULL DEFAULT 101 CHECK (`aspect` > 100)",
     );
 }
+
+#[test]
+fn create_keyword_case_lower() {
+    let mut writer = KeywordCaseWriter::new(String::new(), KeywordCase::Lower);
+    Table::create()
+        .table(Glyph::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key()
+                .comment("Primary KEY Of Glyph"),
+        )
+        .col(
+            ColumnDef::new(Glyph::Image)
+                .enumeration(
+                    Alias::new("image"),
+                    [Alias::new("Small"), Alias::new("Large")],
+                )
+                .extra("CHARACTER SET ascii"),
+        )
+        .col(ColumnDef::new(Glyph::Aspect).custom(Alias::new("MEDIUMINT")))
+        .index(
+            Index::create()
+                .unique()
+                .name("IDX-Glyph-Aspect")
+                .col(Glyph::Aspect),
+        )
+        .engine("InnoDB")
+        .comment("Glyph TABLE")
+        .build_collect_into(MysqlQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.to_string(),
+        [
+            "create table if not exists `glyph` (",
+            "`id` int not null auto_increment primary key comment 'Primary KEY Of Glyph',",
+            "`image` enum('Small', 'Large') CHARACTER SET ascii,",
+            "`aspect` MEDIUMINT,",
+            "unique key `IDX-Glyph-Aspect` (`aspect`)",
+            ") comment 'Glyph TABLE' engine=InnoDB",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_lowercase_identifiers() {
+    let mut writer = LowercaseIdenWriter::new(String::new());
+    Table::create()
+        .table(Alias::new("Glyph"))
+        .col(
+            ColumnDef::new(Alias::new("FontId"))
+                .integer()
+                .not_null()
+                .comment("Font OF Glyph"),
+        )
+        .index(
+            Index::create()
+                .name("IDX-Glyph-FontId")
+                .col(Alias::new("FontId")),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK-Glyph-Font")
+                .from_col(Alias::new("FontId"))
+                .to(Alias::new("Font"), Alias::new("Id")),
+        )
+        .build_collect_into(MysqlQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.to_string(),
+        [
+            "CREATE TABLE `glyph` (",
+            "`fontid` int NOT NULL COMMENT 'Font OF Glyph',",
+            "KEY `idx-glyph-fontid` (`fontid`),",
+            "CONSTRAINT `fk-glyph-font` FOREIGN KEY (`fontid`) REFERENCES `font` (`id`)",
+            ")",
+        ]
+        .join(" ")
+    );
+}
@@ -0,0 +1,59 @@
+use sea_query::{
+    tests_cfg::{Char, Font},
+    Alias, Expr, MysqlQueryBuilder, PostgresQueryBuilder, Query, SqliteQueryBuilder,
+};
+
+#[test]
+fn test_omit_table_alias_as() {
+    let query = Query::select()
+        .expr_as(
+            Expr::col((Alias::new("c"), Char::Character)),
+            Alias::new("ch"),
+        )
+        .from_as(Char::Table, Alias::new("c"))
+        .left_join(
+            Font::Table,
+            Expr::col((Alias::new("c"), Char::FontId)).equals((Font::Table, Font::Id)),
+        )
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(MysqlQueryBuilder),
+        "SELECT `c`.`character` AS `ch` FROM `character` `c` LEFT JOIN `font` ON `c`.`font_id` = `font`.`id`"
+    );
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        r#"SELECT "c"."character" AS "ch" FROM "character" "c" LEFT JOIN "font" ON "c"."font_id" = "font"."id""#
+    );
+    assert_eq!(
+        query.to_string(SqliteQueryBuilder),
+        r#"SELECT "c"."character" AS "ch" FROM "character" "c" LEFT JOIN "font" ON "c"."font_id" = "font"."id""#
+    );
+}
+
+#[test]
+fn test_omit_table_alias_as_sub_query() {
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from_subquery(
+            Query::select()
+                .column(Char::Id)
+                .from(Char::Table)
+                .to_owned(),
+            Alias::new("sub"),
+        )
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(MysqlQueryBuilder),
+        "SELECT `id` FROM (SELECT `id` FROM `character`) `sub`"
+    );
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM (SELECT "id" FROM "character") "sub""#
+    );
+    assert_eq!(
+        query.to_string(SqliteQueryBuilder),
+        r#"SELECT "id" FROM (SELECT "id" FROM "character") "sub""#
+    );
+}
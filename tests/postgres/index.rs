@@ -98,6 +98,78 @@ fn create_7() {
     );
 }
 
+#[test]
+fn create_8() {
+    use sea_query::extension::postgres::PgExpr;
+
+    assert_eq!(
+        Index::create()
+            .name("idx-font-variant-weight")
+            .table(Font::Table)
+            .col_expr(Expr::col(Font::Variant).cast_json_field("weight"))
+            .col(Font::Name)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-font-variant-weight" ON "font" (("variant" ->> 'weight'), "name")"#
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name-language")
+            .table(Font::Table)
+            .col_expr(Func::lower(Expr::col(Font::Name)))
+            .col_expr(Func::coalesce([
+                Expr::col(Font::Language).into(),
+                "en".into()
+            ]))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-font-name-language" ON "font" ((LOWER("name")), (COALESCE("language", 'en')))"#
+    );
+}
+
+#[test]
+fn create_10() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .nulls_not_distinct()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .include([Glyph::Image, Glyph::Tokens])
+            .and_where(Expr::col(Glyph::Aspect).gt(0))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") INCLUDE ("image", "tokens") NULLS NOT DISTINCT WHERE "aspect" > 0"#
+    );
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name-en")
+            .table(Font::Table)
+            .col(Font::Name)
+            .and_where(Func::lower(Expr::col(Font::Language)).eq("en"))
+            .and_where(Func::char_length(Expr::col(Font::Name)).gt(3))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-font-name-en" ON "font" ("name") WHERE LOWER("language") = 'en' AND CHAR_LENGTH("name") > 3"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "NULLS NOT DISTINCT is only supported by unique indexes")]
+fn create_nulls_not_distinct_without_unique() {
+    let _ = Index::create()
+        .nulls_not_distinct()
+        .name("idx-glyph-image")
+        .table(Glyph::Table)
+        .col(Glyph::Image)
+        .to_string(PostgresQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -1,6 +1,8 @@
 use super::*;
 use pretty_assertions::assert_eq;
-use sea_query::extension::postgres::PgBinOper;
+use sea_query::extension::postgres::{
+    PgBinOper, PgExpr, PostgresSelectStatementExt, TableSampleMethod,
+};
 
 #[test]
 fn select_1() {
@@ -901,6 +903,88 @@ fn select_54() {
     );
 }
 
+#[test]
+fn select_distinct_on_latest_per_group() {
+    assert_eq!(
+        Query::select()
+            .distinct_on([(Char::Table, Char::FontId), (Char::Table, Char::SizeW)])
+            .columns([Char::FontId, Char::SizeW, Char::Character])
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeH).gt(4))
+            .order_by((Char::Table, Char::FontId), Order::Asc)
+            .order_by((Char::Table, Char::SizeW), Order::Asc)
+            .order_by(Char::CreatedAt, Order::Desc)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT DISTINCT ON ("character"."font_id", "character"."size_w") "font_id", "size_w", "character""#,
+                r#"FROM "character""#,
+                r#"WHERE "size_h" > $1"#,
+                r#"ORDER BY "character"."font_id" ASC, "character"."size_w" ASC, "created_at" DESC"#,
+            ]
+            .join(" "),
+            Values(vec![4.into()])
+        )
+    );
+}
+
+#[test]
+fn select_distinct_on_try_build() {
+    let latest_per_font = Query::select()
+        .distinct_on([Char::FontId])
+        .columns([Char::FontId, Char::Character, Char::CreatedAt])
+        .from(Char::Table)
+        .and_where(Expr::col(Char::CreatedAt).is_not_null())
+        .order_by_with_nulls(Char::FontId, Order::Asc, NullOrdering::First)
+        .order_by_with_nulls(Char::CreatedAt, Order::Desc, NullOrdering::Last)
+        .limit(10)
+        .to_owned();
+
+    assert_eq!(
+        latest_per_font.try_build(PostgresQueryBuilder),
+        Ok((
+            [
+                r#"SELECT DISTINCT ON ("font_id") "font_id", "character", "created_at""#,
+                r#"FROM "character""#,
+                r#"WHERE "created_at" IS NOT NULL"#,
+                r#"ORDER BY "font_id" ASC NULLS FIRST, "created_at" DESC NULLS LAST"#,
+                r#"LIMIT $1"#,
+            ]
+            .join(" "),
+            Values(vec![10u64.into()])
+        ))
+    );
+
+    assert_eq!(
+        Query::select()
+            .distinct_on([Char::FontId, Char::SizeW])
+            .column(Char::Character)
+            .from(Char::Table)
+            .order_by(Char::SizeW, Order::Asc)
+            .order_by(Char::FontId, Order::Desc)
+            .order_by(Char::CreatedAt, Order::Desc)
+            .try_build(PostgresQueryBuilder)
+            .map(|(sql, _)| sql),
+        Ok([
+            r#"SELECT DISTINCT ON ("font_id", "size_w") "character""#,
+            r#"FROM "character""#,
+            r#"ORDER BY "size_w" ASC, "font_id" DESC, "created_at" DESC"#,
+        ]
+        .join(" "))
+    );
+
+    assert_eq!(
+        Query::select()
+            .distinct_on([Char::FontId, Char::SizeW])
+            .column(Char::Character)
+            .from(Char::Table)
+            .order_by(Char::FontId, Order::Asc)
+            .order_by(Char::CreatedAt, Order::Desc)
+            .try_build(PostgresQueryBuilder),
+        Err(sea_query::error::Error::DistinctOnOrderMismatch)
+    );
+}
+
 #[test]
 fn select_55() {
     let statement = Query::select()
@@ -1093,230 +1177,1046 @@ fn select_62() {
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_2() {
+fn select_group_by_rollup() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Image, Glyph::Aspect])
-            .values_panic([
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
-            ])
-            .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#
+        Query::select()
+            .columns([Char::FontId, Char::SizeW])
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeH).gt(2))
+            .group_by_rollup([Char::FontId, Char::SizeW])
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" WHERE "size_h" > $1 GROUP BY ROLLUP ("font_id", "size_w")"#.to_owned(),
+            Values(vec![2.into()])
+        )
     );
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_3() {
+fn select_group_by_grouping_sets() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Image, Glyph::Aspect])
-            .values_panic([
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
-            ])
-            .values_panic([Value::String(None).into(), 2.1345.into()])
+        Query::select()
+            .columns([Char::Character, Char::FontId, Char::SizeW])
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .group_by_col(Char::Character)
+            .group_by_grouping_sets([vec![Char::FontId, Char::SizeW], vec![Char::FontId], vec![]])
+            .and_having(Expr::col(Char::Id).count().gt(1))
             .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415), (NULL, 2.1345)"#
+        [
+            r#"SELECT "character", "font_id", "size_w", COUNT("id") FROM "character""#,
+            r#"GROUP BY "character", GROUPING SETS (("font_id", "size_w"), ("font_id"), ())"#,
+            r#"HAVING COUNT("id") > 1"#,
+        ]
+        .join(" ")
     );
 }
 
 #[test]
-#[cfg(feature = "with-chrono")]
-fn insert_4() {
+fn select_explain() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Image])
-            .values_panic([chrono::NaiveDateTime::from_timestamp_opt(0, 0)
-                .unwrap()
-                .into()])
-            .to_string(PostgresQueryBuilder),
-        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00')"
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeW).gt(10))
+            .to_owned()
+            .explain()
+            .build(PostgresQueryBuilder),
+        (
+            r#"EXPLAIN (FORMAT JSON) SELECT "id" FROM "character" WHERE "size_w" > $1"#.to_owned(),
+            Values(vec![10.into()])
+        )
     );
 }
 
 #[test]
-#[cfg(feature = "with-time")]
-fn insert_9() {
-    use time::macros::{date, time};
+fn select_explain_analyze() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Image])
-            .values_panic([date!(1970 - 01 - 01).with_time(time!(00:00:00)).into()])
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .limit(5)
+            .to_owned()
+            .explain_analyze()
             .to_string(PostgresQueryBuilder),
-        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00.000000')"
+        r#"EXPLAIN (ANALYZE, FORMAT JSON) SELECT "id" FROM "character" LIMIT 5"#
     );
 }
 
 #[test]
-#[cfg(feature = "with-uuid")]
-fn insert_5() {
+fn select_hash_mod_bucket() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Image])
-            .values_panic([uuid::Uuid::nil().into()])
-            .to_string(PostgresQueryBuilder),
-        "INSERT INTO \"glyph\" (\"image\") VALUES ('00000000-0000-0000-0000-000000000000')"
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Func::hash_mod(Expr::col(Char::Id), 100).lt(5))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "character" WHERE (CAST(CAST('x' || SUBSTR(MD5(CAST("id" AS TEXT)), 1, 8) AS BIT(32)) AS INTEGER) & 2147483647) % $1 < $2"#.to_owned(),
+            Values(vec![100u32.into(), 5.into()])
+        )
     );
 }
 
 #[test]
-fn insert_from_select() {
+fn select_aggregate_filter() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .or_default_values()
-            .columns([Glyph::Aspect, Glyph::Image])
-            .select_from(
-                Query::select()
-                    .column(Glyph::Aspect)
-                    .column(Glyph::Image)
-                    .from(Glyph::Table)
-                    .conditions(
-                        true,
-                        |x| {
-                            x.and_where(Expr::col(Glyph::Image).like("%"));
-                        },
-                        |x| {
-                            x.and_where(Expr::col(Glyph::Id).eq(6));
-                        },
-                    )
-                    .to_owned()
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::sum(Expr::col(Char::SizeW).mul(2)).filter(Expr::col(Char::SizeH).gt(3)))
+            .expr(
+                Func::count(Expr::col(Asterisk)).filter(
+                    Cond::any()
+                        .add(Expr::col(Char::SizeW).lt(1))
+                        .add(Expr::col(Char::SizeH).lt(1))
+                )
             )
-            .unwrap()
-            .to_owned()
-            .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect", "image" FROM "glyph" WHERE "image" LIKE '%'"#
+            .from(Char::Table)
+            .group_by_col(Char::FontId)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "font_id","#,
+                r#"SUM("size_w" * $1) FILTER (WHERE "size_h" > $2),"#,
+                r#"COUNT(*) FILTER (WHERE "size_w" < $3 OR "size_h" < $4)"#,
+                r#"FROM "character" GROUP BY "font_id""#,
+            ]
+            .join(" "),
+            Values(vec![2.into(), 3.into(), 1.into(), 1.into()])
+        )
     );
 }
 
 #[test]
-fn insert_6() -> error::Result<()> {
-    let select = SelectStatement::new()
-        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
-        .from(Glyph::Table)
-        .to_owned();
-    let cte = CommonTableExpression::new()
-        .query(select)
-        .column(Glyph::Id)
-        .column(Glyph::Image)
-        .column(Glyph::Aspect)
-        .table_name(Alias::new("cte"))
-        .to_owned();
-    let with_clause = WithClause::new().cte(cte).to_owned();
-    let select = SelectStatement::new()
-        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
-        .from(Alias::new("cte"))
-        .to_owned();
-    let mut insert = Query::insert();
-    insert
-        .into_table(Glyph::Table)
-        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
-        .select_from(select)?;
-    let sql = insert.with(with_clause).to_string(PostgresQueryBuilder);
+fn select_format_date() {
     assert_eq!(
-        sql.as_str(),
-        [
-            r#"WITH "cte" ("id", "image", "aspect") AS (SELECT "id", "image", "aspect" FROM "glyph")"#,
-            r#"INSERT INTO "glyph" ("id", "image", "aspect") SELECT "id", "image", "aspect" FROM "cte""#,
-        ].join(" ")
+        Query::select()
+            .expr(Func::format_date(
+                Expr::col(Char::CreatedAt),
+                "%d/%m/%Y at %H:%M:%S (100%%)"
+            ))
+            .from(Char::Table)
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT TO_CHAR("created_at", $1) FROM "character""#.to_owned(),
+            Values(vec![r#"DD/MM/YYYY" at "HH24:MI:SS (100%)"#.into()])
+        )
     );
-    Ok(())
 }
 
 #[test]
-fn insert_7() {
+fn select_join_values_list() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .or_default_values()
-            .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" VALUES (DEFAULT)"#
+        Query::select()
+            .columns([(Char::Table, Char::Character)])
+            .column((Alias::new("t"), Alias::new("label")))
+            .from(Char::Table)
+            .inner_join(
+                Query::values_list(
+                    [(1i32, "small"), (2, "large")],
+                    Alias::new("t"),
+                    [Alias::new("id"), Alias::new("label")],
+                ),
+                Expr::col((Char::Table, Char::Id)).equals((Alias::new("t"), Alias::new("id"))),
+            )
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "character"."character", "t"."label" FROM "character""#,
+                r#"INNER JOIN (VALUES ($1, $2), ($3, $4)) AS "t"("id", "label")"#,
+                r#"ON "character"."id" = "t"."id""#,
+            ]
+            .join(" "),
+            Values(vec![
+                1i32.into(),
+                "small".into(),
+                2i32.into(),
+                "large".into()
+            ])
+        )
     );
 }
 
 #[test]
-fn insert_8() {
-    assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .or_default_values()
-            .returning_col(Glyph::Id)
-            .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" VALUES (DEFAULT) RETURNING "id""#
+#[should_panic(expected = "Query::values_list requires at least one row")]
+fn select_join_empty_values_list() {
+    Query::values_list(
+        Vec::<(i32, &str)>::new(),
+        Alias::new("t"),
+        [Alias::new("id"), Alias::new("label")],
     );
 }
 
 #[test]
-#[cfg(feature = "postgres-array")]
-fn insert_10() {
+fn select_append_raw() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Aspect, Glyph::Tokens])
-            .values_panic([
-                3.1415.into(),
-                vec![
-                    "Token1".to_string(),
-                    "Token2".to_string(),
-                    "Token3".to_string()
-                ]
-                .into()
-            ])
-            .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("aspect", "tokens") VALUES (3.1415, ARRAY ['Token1','Token2','Token3'])"#
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(5))
+            .and_where(Expr::col(Char::SizeW).gt(2))
+            .order_by(Char::SizeW, Order::Desc)
+            .append_raw("OFFSET $1 ROWS FETCH FIRST $2 ROWS WITH TIES", [20, 10])
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "character" FROM "character""#,
+                r#"WHERE "font_id" = $1 AND "size_w" > $2 ORDER BY "size_w" DESC"#,
+                r#"OFFSET $3 ROWS FETCH FIRST $4 ROWS WITH TIES"#,
+            ]
+            .join(" "),
+            Values(vec![5.into(), 2.into(), 20.into(), 10.into()])
+        )
     );
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_on_conflict_1() {
+fn select_lock_skip_locked() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Aspect, Glyph::Image])
-            .values_panic([
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
-            ])
-            .on_conflict(
-                OnConflict::column(Glyph::Id)
-                    .update_column(Glyph::Aspect)
-                    .to_owned()
+        Query::select()
+            .column((Glyph::Table, Glyph::Id))
+            .from(Glyph::Table)
+            .inner_join(
+                Font::Table,
+                Expr::col((Glyph::Table, Glyph::Id)).equals((Font::Table, Font::Id)),
+            )
+            .and_where(Expr::col((Glyph::Table, Glyph::Aspect)).lt(1))
+            .limit(1)
+            .lock_with_tables_behavior(
+                LockType::Update,
+                [Glyph::Table.into_table_ref(), Font::Table.into_table_ref()],
+                LockBehavior::SkipLocked,
             )
             .to_string(PostgresQueryBuilder),
         [
-            r#"INSERT INTO "glyph" ("aspect", "image")"#,
-            r#"VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#,
-            r#"ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect""#,
+            r#"SELECT "glyph"."id" FROM "glyph""#,
+            r#"INNER JOIN "font" ON "glyph"."id" = "font"."id""#,
+            r#"WHERE "glyph"."aspect" < 1 LIMIT 1"#,
+            r#"FOR UPDATE OF "glyph", "font" SKIP LOCKED"#,
         ]
         .join(" ")
     );
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_on_conflict_2() {
+fn select_except_snapshot_diff() {
+    let snapshot = |table: &str| {
+        Query::select()
+            .columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .from(Alias::new(table))
+            .to_owned()
+    };
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns([Glyph::Aspect, Glyph::Image])
-            .values_panic([
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
-            ])
-            .on_conflict(
-                OnConflict::columns([Glyph::Id, Glyph::Aspect])
-                    .update_columns([Glyph::Aspect, Glyph::Image])
-                    .to_owned()
-            )
+        snapshot("glyph_new")
+            .union(UnionType::Except, snapshot("glyph_old"))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "id", "aspect", "image" FROM "glyph_new""#,
+            r#"EXCEPT (SELECT "id", "aspect", "image" FROM "glyph_old")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        snapshot("glyph_new")
+            .union(UnionType::ExceptAll, snapshot("glyph_old"))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "id", "aspect", "image" FROM "glyph_new""#,
+            r#"EXCEPT ALL (SELECT "id", "aspect", "image" FROM "glyph_old")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_intersect_null_rows() {
+    // Set operations treat NULLs as equal, so this query returns the row `(1, NULL)`
+    assert_eq!(
+        Query::select()
+            .exprs([Expr::val(1), Expr::val(Option::<i32>::None)])
+            .union(
+                UnionType::IntersectAll,
+                Query::select()
+                    .exprs([Expr::val(1), Expr::val(Option::<i32>::None)])
+                    .to_owned()
+            )
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT $1, $2 INTERSECT ALL (SELECT $3, $4)"#.to_owned(),
+            Values(vec![
+                1.into(),
+                Option::<i32>::None.into(),
+                1.into(),
+                Option::<i32>::None.into()
+            ])
+        )
+    );
+}
+
+#[test]
+fn with_recursive_search_cycle() {
+    let mut base = Query::select()
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .from(Alias::new("node"))
+        .and_where(Expr::col(Alias::new("id")).eq(1))
+        .to_owned();
+    let step = Query::select()
+        .columns([
+            (Alias::new("n"), Alias::new("id")),
+            (Alias::new("n"), Alias::new("parent_id")),
+        ])
+        .from_as(Alias::new("node"), Alias::new("n"))
+        .inner_join(
+            Alias::new("tree"),
+            Expr::col((Alias::new("n"), Alias::new("parent_id")))
+                .equals((Alias::new("tree"), Alias::new("id"))),
+        )
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .query(base.union(UnionType::All, step).to_owned())
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .table_name(Alias::new("tree"))
+        .search_breadth_first([Alias::new("id")], Alias::new("ord"))
+        .cycle(
+            [Alias::new("id"), Alias::new("parent_id")],
+            Alias::new("is_cycle"),
+            Alias::new("path"),
+        )
+        .to_owned();
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .and_where(Expr::col(Alias::new("is_cycle")).not())
+        .order_by(Alias::new("ord"), Order::Asc)
+        .to_owned()
+        .with(WithClause::new().recursive(true).cte(cte).to_owned());
+
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        [
+            r#"WITH RECURSIVE "tree" ("id", "parent_id") AS"#,
+            r#"(SELECT "id", "parent_id" FROM "node" WHERE "id" = 1"#,
+            r#"UNION ALL (SELECT "n"."id", "n"."parent_id" FROM "node" AS "n""#,
+            r#"INNER JOIN "tree" ON "n"."parent_id" = "tree"."id"))"#,
+            r#"SEARCH BREADTH FIRST BY "id" SET "ord""#,
+            r#"CYCLE "id", "parent_id" SET "is_cycle" USING "path""#,
+            r#"SELECT "id" FROM "tree" WHERE NOT "is_cycle" ORDER BY "ord" ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "SEARCH and CYCLE can be set on the WithClause or on its common table expressions, not both"
+)]
+fn with_recursive_search_on_clause_and_cte() {
+    let cte = CommonTableExpression::new()
+        .query(
+            Query::select()
+                .column(Alias::new("id"))
+                .from(Alias::new("node"))
+                .to_owned(),
+        )
+        .table_name(Alias::new("tree"))
+        .search_breadth_first([Alias::new("id")], Alias::new("ord"))
+        .to_owned();
+    Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .to_owned()
+        .with(
+            WithClause::new()
+                .recursive(true)
+                .cycle(Cycle::new_from_expr_set_using(
+                    Expr::col(Alias::new("id")),
+                    Alias::new("is_cycle"),
+                    Alias::new("path"),
+                ))
+                .cte(cte)
+                .to_owned(),
+        )
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn select_unnest_array_column() {
+    assert_eq!(
+        Query::select()
+            .column((Alias::new("p"), Alias::new("id")))
+            .column(Alias::new("tag"))
+            .from_as(Alias::new("post"), Alias::new("p"))
+            .from_function(
+                PgFunc::unnest(Expr::col((Alias::new("p"), Alias::new("tags")))),
+                Alias::new("tag")
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "p"."id", "tag" FROM "post" AS "p", UNNEST("p"."tags") AS "tag""#
+    );
+    // Keep the rows with an empty array
+    assert_eq!(
+        Query::select()
+            .column((Alias::new("p"), Alias::new("id")))
+            .column(Alias::new("tag"))
+            .from_as(Alias::new("post"), Alias::new("p"))
+            .left_join(
+                TableRef::FunctionCall(
+                    PgFunc::unnest(Expr::col((Alias::new("p"), Alias::new("tags")))),
+                    Alias::new("tag").into_iden()
+                ),
+                Expr::cust("TRUE")
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "p"."id", "tag" FROM "post" AS "p""#,
+            r#"LEFT JOIN UNNEST("p"."tags") AS "tag" ON TRUE"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_from_sampled() {
+    assert_eq!(
+        Query::select()
+            .column((Alias::new("c"), Char::Character))
+            .from_sampled(
+                Char::Table.into_table_ref().alias(Alias::new("c")),
+                TableSampleMethod::System,
+                25.0,
+                None
+            )
+            .and_where(Expr::col((Alias::new("c"), Char::SizeW)).gt(10))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "c"."character" FROM "character" AS "c" TABLESAMPLE SYSTEM (25) WHERE "c"."size_w" > 10"#
+    );
+}
+
+#[test]
+fn select_ilike() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Name).ilike("sans%"))
+            .and_where(Expr::col(Font::Variant).not_ilike(LikeExpr::new("%|_mono").escape('|')))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "name" FROM "font" WHERE "name" ILIKE $1 AND "variant" NOT ILIKE $2 ESCAPE '|'"#
+                .to_owned(),
+            Values(vec!["sans%".into(), "%|_mono".into()])
+        )
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn select_pg_expr_ilike() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .and_where(PgExpr::ilike(Expr::col(Font::Name), "sans%"))
+            .and_where(PgExpr::not_ilike(Expr::col(Font::Variant), "%mono"))
+            .and_where(Expr::col(Font::Language).binary(PgBinOper::ILike, "en%"))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "name" FROM "font" WHERE "name" ILIKE 'sans%'"#,
+            r#"AND "variant" NOT ILIKE '%mono' AND "language" ILIKE 'en%'"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_page_with_total_count() {
+    assert_eq!(
+        Query::select()
+            .columns([Char::Id, Char::Character])
+            .expr_window_as(
+                Func::count_star(),
+                WindowStatement::new(),
+                Alias::new("total")
+            )
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(5))
+            .order_by(Char::Id, Order::Asc)
+            .limit(10)
+            .offset(20)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "id", "character", COUNT(*) OVER () AS "total""#,
+                r#"FROM "character""#,
+                r#"WHERE "font_id" = $1"#,
+                r#"ORDER BY "id" ASC LIMIT $2 OFFSET $3"#,
+            ]
+            .join(" "),
+            Values(vec![5.into(), 10u64.into(), 20u64.into()])
+        )
+    );
+}
+
+#[test]
+fn select_pg_cast() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Char::FontId).pg_cast(ColumnType::Text))
+            .from(Char::Table)
+            .and_where(
+                Expr::col(Char::CreatedAt).gt(Expr::val("2024-01-01").pg_cast(ColumnType::Date))
+            )
+            .and_where(
+                Expr::col(Char::SizeW)
+                    .mul(Expr::col(Char::SizeH))
+                    .pg_cast(ColumnType::BigInteger)
+                    .gt(100)
+            )
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "font_id"::text FROM "character""#,
+                r#"WHERE "created_at" > $1::date"#,
+                r#"AND ("size_w" * "size_h")::bigint > $2"#,
+            ]
+            .join(" "),
+            Values(vec!["2024-01-01".into(), 100.into()])
+        )
+    );
+}
+
+#[test]
+fn select_string_agg() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Language)
+            .expr(
+                Func::string_agg(Expr::col(Font::Name), ", ")
+                    .distinct()
+                    .order_by(Font::Name, Order::Asc),
+            )
+            .from(Font::Table)
+            .group_by_col(Font::Language)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "language", STRING_AGG(DISTINCT "name", ', ' ORDER BY "name" ASC) FROM "font" GROUP BY "language""#
+    );
+}
+
+#[test]
+fn select_distinct_before_arguments() {
+    assert_eq!(
+        Query::select()
+            .expr(
+                Func::cust(Alias::new("ARRAY_AGG"))
+                    .distinct()
+                    .arg(Expr::col(Font::Name))
+            )
+            .from(Font::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT ARRAY_AGG(DISTINCT "name") FROM "font""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "MATCH ... AGAINST is only supported by Mysql")]
+fn select_match_against() {
+    use sea_query::extension::mysql::{MatchAgainstMode, MySqlFunc};
+
+    let _ = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(
+            MySqlFunc::match_against([Font::Name], "serif", MatchAgainstMode::Boolean).into(),
+        )
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+#[cfg(feature = "postgres-array")]
+fn select_val_as() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::Id).eq(PgFunc::any(Expr::val_as(
+                vec![1, 2, 3],
+                ColumnType::Array(RcOrArc::new(ColumnType::Integer))
+            ))))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "character" WHERE "id" = ANY($1::integer[])"#.to_owned(),
+            Values(vec![vec![1, 2, 3].into()])
+        )
+    );
+}
+
+#[test]
+fn select_overlaps() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::overlaps(
+                ("2020-01-01", "2020-02-01"),
+                (Expr::col(Char::CreatedAt), Expr::val("2020-03-01")),
+            ))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "character" WHERE ($1, $2) OVERLAPS ("created_at", $3)"#.to_owned(),
+            Values(vec![
+                "2020-01-01".into(),
+                "2020-02-01".into(),
+                "2020-03-01".into()
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_cust_with_exprs() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::cust_with_exprs(
+                "$2 < COALESCE($1, $3)",
+                [
+                    Expr::col(Char::FontSize).into(),
+                    Expr::val(10).into(),
+                    Expr::val(12).into()
+                ],
+            ))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "character" WHERE $1 < COALESCE("font_size", $2)"#.to_owned(),
+            Values(vec![10.into(), 12.into()])
+        )
+    );
+}
+
+#[test]
+fn select_group_by_case() {
+    let bucket = Expr::case(Expr::col(Char::SizeW).lt(18), "minor").finally("adult");
+    assert_eq!(
+        Query::select()
+            .expr_as(bucket.clone(), Alias::new("bucket"))
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .group_by_expr(bucket)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT (CASE WHEN ("size_w" < $1) THEN $2 ELSE $3 END) AS "bucket", COUNT("id")"#,
+                r#"FROM "character" GROUP BY 1"#,
+            ]
+            .join(" "),
+            Values(vec![18.into(), "minor".into(), "adult".into()])
+        )
+    );
+}
+
+#[test]
+fn select_add_group_by_selected_expr() {
+    let bucket = Expr::case(Expr::col(Char::SizeW).lt(18), "minor").finally("adult");
+    assert_eq!(
+        Query::select()
+            .expr_as(bucket.clone(), Alias::new("bucket"))
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .add_group_by([bucket.into()])
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT (CASE WHEN ("size_w" < 18) THEN 'minor' ELSE 'adult' END) AS "bucket", COUNT("id")"#,
+            r#"FROM "character" GROUP BY (CASE WHEN ("size_w" < 18) THEN 'minor' ELSE 'adult' END)"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_group_by_case_alias_shadowing_column() {
+    let bucket = Expr::case(Expr::col(Char::SizeW).lt(18), "minor").finally("adult");
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_as(bucket.clone(), Char::SizeW)
+            .from(Char::Table)
+            .group_by_col(Char::Id)
+            .group_by_expr(bucket)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "id", (CASE WHEN ("size_w" < $1) THEN $2 ELSE $3 END) AS "size_w""#,
+                r#"FROM "character" GROUP BY "id", 2"#,
+            ]
+            .join(" "),
+            Values(vec![18.into(), "minor".into(), "adult".into()])
+        )
+    );
+}
+
+#[test]
+fn select_window_frame() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                WindowStatement::new()
+                    .order_by(Char::Id, Order::Asc)
+                    .frame_between(FrameType::Rows, Frame::Preceding(2), Frame::CurrentRow)
+                    .take(),
+                Alias::new("moving_avg"),
+            )
+            .from(Char::Table)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "id", AVG("size_w") OVER ( ORDER BY "id" ASC ROWS BETWEEN 2 PRECEDING AND CURRENT ROW )"#,
+                r#"AS "moving_avg" FROM "character""#,
+            ]
+            .join(" "),
+            Values(vec![])
+        )
+    );
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                WindowStatement::new()
+                    .order_by(Char::Id, Order::Asc)
+                    .frame_between(FrameType::Groups, Frame::Preceding(2), Frame::CurrentRow)
+                    .take(),
+                Alias::new("moving_avg"),
+            )
+            .from(Char::Table)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "id", AVG("size_w") OVER ( ORDER BY "id" ASC GROUPS BETWEEN 2 PRECEDING AND CURRENT ROW )"#,
+            r#"AS "moving_avg" FROM "character""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_named_window() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_name_as(
+                Func::sum(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("total")
+            )
+            .expr_window_name_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("average")
+            )
+            .from(Char::Table)
+            .and_having(Expr::col(Char::Id).count().gt(1))
+            .group_by_col(Char::Id)
+            .window(
+                Alias::new("w"),
+                WindowStatement::partition_by(Char::FontId)
+                    .order_by(Char::Id, Order::Asc)
+                    .to_owned(),
+            )
+            .order_by(Char::Id, Order::Desc)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "id", SUM("size_w") OVER "w" AS "total", AVG("size_w") OVER "w" AS "average""#,
+            r#"FROM "character" GROUP BY "id" HAVING COUNT("id") > 1"#,
+            r#"WINDOW "w" AS (PARTITION BY "font_id" ORDER BY "id" ASC)"#,
+            r#"ORDER BY "id" DESC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_fetch_first() {
+    let query = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(Expr::col(Glyph::Aspect).gt(2))
+        .order_by(Glyph::Aspect, Order::Desc)
+        .limit(5)
+        .offset(20)
+        .fetch_first(10, true)
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" > 2 ORDER BY "aspect" DESC OFFSET 20 FETCH FIRST 10 ROWS WITH TIES"#
+    );
+    assert_eq!(
+        query.build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" WHERE "aspect" > $1 ORDER BY "aspect" DESC OFFSET $2 FETCH FIRST $3 ROWS WITH TIES"#
+                .to_owned(),
+            Values(vec![2i32.into(), 20u64.into(), 10u64.into()])
+        )
+    );
+    assert_eq!(
+        query
+            .to_owned()
+            .fetch_first(3, false)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" > 2 ORDER BY "aspect" DESC OFFSET 20 FETCH FIRST 3 ROWS ONLY"#
+    );
+}
+
+#[test]
+fn select_greatest_ignore_nulls() {
+    let query = Query::select()
+        .expr(Func::greatest_ignore_nulls([
+            Expr::col(Char::SizeW).into(),
+            Expr::col(Char::SizeH).into(),
+            Expr::val(0).into(),
+        ]))
+        .expr(Func::least_ignore_nulls([Expr::col(Char::SizeW).into()]))
+        .from(Char::Table)
+        .to_owned();
+
+    assert_eq!(
+        query.build(PostgresQueryBuilder),
+        (
+            r#"SELECT GREATEST("size_w", "size_h", $1), LEAST("size_w") FROM "character""#
+                .to_owned(),
+            Values(vec![0i32.into()])
+        )
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_2() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image, Glyph::Aspect])
+            .values_panic([
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_3() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image, Glyph::Aspect])
+            .values_panic([
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .values_panic([Value::String(None).into(), 2.1345.into()])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415), (NULL, 2.1345)"#
+    );
+}
+
+#[test]
+#[cfg(feature = "with-chrono")]
+fn insert_4() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image])
+            .values_panic([chrono::NaiveDateTime::from_timestamp_opt(0, 0)
+                .unwrap()
+                .into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00')"
+    );
+}
+
+#[test]
+#[cfg(feature = "with-time")]
+fn insert_9() {
+    use time::macros::{date, time};
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image])
+            .values_panic([date!(1970 - 01 - 01).with_time(time!(00:00:00)).into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00.000000')"
+    );
+}
+
+#[test]
+#[cfg(feature = "with-uuid")]
+fn insert_5() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image])
+            .values_panic([uuid::Uuid::nil().into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\") VALUES ('00000000-0000-0000-0000-000000000000')"
+    );
+}
+
+#[test]
+fn insert_from_select() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .or_default_values()
+            .columns([Glyph::Aspect, Glyph::Image])
+            .select_from(
+                Query::select()
+                    .column(Glyph::Aspect)
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .conditions(
+                        true,
+                        |x| {
+                            x.and_where(Expr::col(Glyph::Image).like("%"));
+                        },
+                        |x| {
+                            x.and_where(Expr::col(Glyph::Id).eq(6));
+                        },
+                    )
+                    .to_owned()
+            )
+            .unwrap()
+            .to_owned()
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect", "image" FROM "glyph" WHERE "image" LIKE '%'"#
+    );
+}
+
+#[test]
+fn insert_6() -> error::Result<()> {
+    let select = SelectStatement::new()
+        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
+        .from(Glyph::Table)
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .query(select)
+        .column(Glyph::Id)
+        .column(Glyph::Image)
+        .column(Glyph::Aspect)
+        .table_name(Alias::new("cte"))
+        .to_owned();
+    let with_clause = WithClause::new().cte(cte).to_owned();
+    let select = SelectStatement::new()
+        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
+        .from(Alias::new("cte"))
+        .to_owned();
+    let mut insert = Query::insert();
+    insert
+        .into_table(Glyph::Table)
+        .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
+        .select_from(select)?;
+    let sql = insert.with(with_clause).to_string(PostgresQueryBuilder);
+    assert_eq!(
+        sql.as_str(),
+        [
+            r#"WITH "cte" ("id", "image", "aspect") AS (SELECT "id", "image", "aspect" FROM "glyph")"#,
+            r#"INSERT INTO "glyph" ("id", "image", "aspect") SELECT "id", "image", "aspect" FROM "cte""#,
+        ].join(" ")
+    );
+    Ok(())
+}
+
+#[test]
+fn insert_7() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .or_default_values()
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" VALUES (DEFAULT)"#
+    );
+}
+
+#[test]
+fn insert_8() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .or_default_values()
+            .returning_col(Glyph::Id)
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" VALUES (DEFAULT) RETURNING "id""#
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres-array")]
+fn insert_10() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Tokens])
+            .values_panic([
+                3.1415.into(),
+                vec![
+                    "Token1".to_string(),
+                    "Token2".to_string(),
+                    "Token3".to_string()
+                ]
+                .into()
+            ])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("aspect", "tokens") VALUES (3.1415, ARRAY ['Token1','Token2','Token3'])"#
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_on_conflict_1() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .values_panic([
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_column(Glyph::Aspect)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("aspect", "image")"#,
+            r#"VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#,
+            r#"ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_on_conflict_2() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .values_panic([
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .on_conflict(
+                OnConflict::columns([Glyph::Id, Glyph::Aspect])
+                    .update_columns([Glyph::Aspect, Glyph::Image])
+                    .to_owned()
+            )
             .to_string(PostgresQueryBuilder),
         [
             r#"INSERT INTO "glyph" ("aspect", "image")"#,
@@ -1597,6 +2497,177 @@ fn insert_returning_specific_columns() {
     );
 }
 
+#[test]
+fn insert_returning_exprs_as() {
+    let query = Query::insert()
+        .into_table(Glyph::Table)
+        .columns([Glyph::Image, Glyph::Aspect])
+        .values_panic(["A".into(), 2.into()])
+        .returning_col(Glyph::Id)
+        .returning_expr_as(Func::cust(Alias::new("now")), Alias::new("ts"))
+        .returning_expr_as(Expr::col(Glyph::Aspect).mul(10), Alias::new("scaled"))
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('A', 2)"#,
+            r#"RETURNING "id", now() AS "ts", "aspect" * 10 AS "scaled""#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query.build(PostgresQueryBuilder),
+        (
+            [
+                r#"INSERT INTO "glyph" ("image", "aspect") VALUES ($1, $2)"#,
+                r#"RETURNING "id", now() AS "ts", "aspect" * $3 AS "scaled""#,
+            ]
+            .join(" "),
+            Values(vec!["A".into(), 2i32.into(), 10i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_random_sample() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::expr(Func::random()).lt(0.05))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" WHERE RANDOM() < $1"#.to_owned(),
+            Values(vec![0.05.into()])
+        )
+    );
+}
+
+#[test]
+fn select_random_sample_tablesample() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from_sampled(Glyph::Table, TableSampleMethod::System, 1.0, None)
+            .random_sample(10)
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" TABLESAMPLE SYSTEM ($1) ORDER BY RANDOM() ASC LIMIT $2"#
+                .to_owned(),
+            Values(vec![1.0.into(), 10u64.into()])
+        )
+    );
+}
+
+#[test]
+fn insert_on_conflict_partial_index() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Image, Glyph::Aspect])
+            .values_panic(["A".into(), 1.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Image)
+                    .target_and_where(Expr::col(Glyph::Aspect).gt(0))
+                    .update_column(Glyph::Aspect)
+                    .action_and_where(Expr::col((Glyph::Table, Glyph::Aspect)).lt(10))
+                    .to_owned()
+            )
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"INSERT INTO "glyph" ("image", "aspect") VALUES ($1, $2)"#,
+                r#"ON CONFLICT ("image") WHERE "aspect" > $3"#,
+                r#"DO UPDATE SET "aspect" = "excluded"."aspect" WHERE "glyph"."aspect" < $4"#,
+            ]
+            .join(" "),
+            Values(vec!["A".into(), 1.into(), 0.into(), 10.into()])
+        )
+    );
+}
+
+#[test]
+fn insert_on_conflict_update_where_newer() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
+            .values_panic([1.into(), "A".into(), 2.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_column(Glyph::Image)
+                    .action_and_where(
+                        Expr::col((Glyph::Table, Glyph::Aspect))
+                            .lt(Expr::col((Alias::new("excluded"), Glyph::Aspect)))
+                    )
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("id", "image", "aspect") VALUES (1, 'A', 2)"#,
+            r#"ON CONFLICT ("id") DO UPDATE SET "image" = "excluded"."image""#,
+            r#"WHERE "glyph"."aspect" < "excluded"."aspect""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn insert_on_conflict_default() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .values_panic(["abcd".into(), 3.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_column(Glyph::Aspect)
+                    .value(Glyph::Image, Keyword::Default)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("aspect", "image") VALUES ('abcd', 3)"#,
+            r#"ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect", "image" = DEFAULT"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn insert_build_chunked() {
+    let mut query = Query::insert();
+    query
+        .into_table(Glyph::Table)
+        .columns([Glyph::Aspect, Glyph::Image])
+        .on_conflict(
+            OnConflict::column(Glyph::Id)
+                .value(Glyph::Image, Expr::val("conflict"))
+                .to_owned(),
+        )
+        .returning_col(Glyph::Id);
+    for i in 0..1000 {
+        query.values_panic([i.into(), format!("{i}").into()]);
+    }
+
+    let chunks = query.build_chunked(PostgresQueryBuilder, 300);
+
+    // 1 parameter in ON CONFLICT leaves room for 149 rows of 2 parameters
+    assert_eq!(chunks.len(), 7);
+    for (i, (sql, values)) in chunks.iter().enumerate() {
+        let rows = if i < 6 { 149 } else { 1000 - 6 * 149 };
+        assert_eq!(values.0.len(), rows * 2 + 1);
+        assert_eq!(values.0[0], ((i * 149) as i32).into());
+        assert_eq!(values.0[rows * 2], "conflict".into());
+        assert!(sql.starts_with(r#"INSERT INTO "glyph" ("aspect", "image") VALUES ($1, $2), "#));
+        assert!(sql.ends_with(&format!(
+            r#"ON CONFLICT ("id") DO UPDATE SET "image" = ${} RETURNING "id""#,
+            rows * 2 + 1
+        )));
+    }
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -1649,6 +2720,40 @@ fn update_4() {
     );
 }
 
+#[test]
+fn update_case_value() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(
+                Glyph::Image,
+                Expr::case(Expr::col(Glyph::Aspect).gt(2), "large")
+                    .case(Expr::col(Glyph::Aspect).lt(1), "small")
+                    .finally(Expr::col(Glyph::Image))
+            )
+            .value(Glyph::Aspect, Expr::col(Glyph::Aspect).add(1))
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"UPDATE "glyph" SET"#,
+                r#""image" = (CASE WHEN ("aspect" > $1) THEN $2 WHEN ("aspect" < $3) THEN $4 ELSE "image" END),"#,
+                r#""aspect" = "aspect" + $5"#,
+                r#"WHERE "id" = $6"#,
+            ]
+            .join(" "),
+            Values(vec![
+                2i32.into(),
+                "large".into(),
+                1i32.into(),
+                "small".into(),
+                1i32.into(),
+                1i32.into()
+            ])
+        )
+    );
+}
+
 #[test]
 fn update_returning_all_columns() {
     assert_eq!(
@@ -1683,6 +2788,60 @@ fn update_returning_specified_columns() {
     );
 }
 
+#[test]
+fn update_from_sub_query() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .from(TableRef::SubQuery(
+                Query::select()
+                    .column(Char::Id)
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::FontId).eq(5))
+                    .to_owned(),
+                Alias::new("sub").into_iden(),
+            ))
+            .value(Glyph::Aspect, 2)
+            .and_where(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("sub"), Glyph::Id)))
+            .and_where(Expr::col((Glyph::Table, Glyph::Image)).like("A%"))
+            .build(PostgresQueryBuilder),
+        (
+            r#"UPDATE "glyph" SET "aspect" = $1 FROM (SELECT "id" FROM "character" WHERE "font_id" = $2) AS "sub" WHERE "glyph"."id" = "sub"."id" AND "glyph"."image" LIKE $3"#.to_owned(),
+            Values(vec![2.into(), 5.into(), "A%".into()])
+        )
+    );
+}
+
+#[test]
+fn update_value_tuple() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 1)
+            .value_tuple(
+                [Glyph::Image, Glyph::Tokens],
+                Expr::subquery(
+                    Query::select()
+                        .columns([Font::Name, Font::Variant])
+                        .from(Font::Table)
+                        .and_where(Expr::col(Font::Id).eq(2))
+                        .to_owned(),
+                ),
+            )
+            .and_where(Expr::col(Glyph::Id).eq(3))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"UPDATE "glyph" SET "aspect" = $1, ("image", "tokens") ="#,
+                r#"(SELECT "name", "variant" FROM "font" WHERE "id" = $2)"#,
+                r#"WHERE "id" = $3"#,
+            ]
+            .join(" "),
+            Values(vec![1.into(), 2.into(), 3.into()])
+        )
+    );
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -1694,6 +2853,31 @@ fn delete_1() {
     );
 }
 
+#[test]
+fn delete_using() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Char::Table)
+            .using(Font::Table)
+            .cond_where(all![
+                Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)),
+                Expr::col((Font::Table, Font::Language)).eq("fr"),
+                Expr::col((Char::Table, Char::SizeW)).gt(10),
+            ])
+            .returning_col((Char::Table, Char::Id))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"DELETE FROM "character" USING "font""#,
+                r#"WHERE "character"."font_id" = "font"."id" AND "font"."language" = $1 AND "character"."size_w" > $2"#,
+                r#"RETURNING "character"."id""#,
+            ]
+            .join(" "),
+            Values(vec!["fr".into(), 10.into()])
+        )
+    );
+}
+
 #[test]
 fn escape_1() {
     let test = r#" "abc" "#;
@@ -1782,6 +2966,19 @@ fn delete_returning_specific_exprs() {
     );
 }
 
+#[test]
+fn delete_returning_all_and_expr() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .returning_all()
+            .returning_expr(Expr::col(Glyph::Aspect).is_null())
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING *, "aspect" IS NULL"#
+    );
+}
+
 #[test]
 fn select_pgtrgm_similarity() {
     assert_eq!(
@@ -1920,18 +3117,76 @@ fn sub_query_with_fn() {
         .to_owned();
 
     assert_eq!(
-        select.to_string(PostgresQueryBuilder),
-        r#"SELECT ARRAY((SELECT * FROM "character"))"#
+        select.to_string(PostgresQueryBuilder),
+        r#"SELECT ARRAY((SELECT * FROM "character"))"#
+    );
+}
+
+#[test]
+fn md5_fn() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::md5(Expr::val("test")))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT MD5('test')"#
+    );
+}
+
+#[test]
+fn select_array_string_round_trip() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                PgFunc::array_to_string(Expr::col(Glyph::Tokens), ","),
+                Alias::new("csv")
+            )
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Tokens).eq(PgFunc::string_to_array(
+                PgFunc::array_to_string(Expr::col(Glyph::Tokens), ","),
+                ","
+            )))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT ARRAY_TO_STRING("tokens", $1) AS "csv" FROM "glyph""#,
+                r#"WHERE "tokens" = STRING_TO_ARRAY(ARRAY_TO_STRING("tokens", $2), $3)"#,
+            ]
+            .join(" "),
+            Values(vec![",".into(), ",".into(), ",".into()])
+        )
     );
 }
 
 #[test]
-fn md5_fn() {
+fn update_array_functions() {
     assert_eq!(
-        Query::select()
-            .expr(Func::md5(Expr::val("test")))
-            .to_string(PostgresQueryBuilder),
-        r#"SELECT MD5('test')"#
+        Query::update()
+            .table(Glyph::Table)
+            .value(
+                Glyph::Tokens,
+                PgFunc::array_append(PgFunc::array_remove(Expr::col(Glyph::Tokens), "a"), "b"),
+            )
+            .and_where(PgFunc::array_position(Expr::col(Glyph::Tokens), "a").is_not_null())
+            .and_where(Expr::expr(PgFunc::array_length(Expr::col(Glyph::Tokens), 1)).gt(0))
+            .and_where(Expr::expr(PgFunc::cardinality(Expr::col(Glyph::Tokens))).lt(10))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"UPDATE "glyph" SET "tokens" = ARRAY_APPEND(ARRAY_REMOVE("tokens", $1), $2)"#,
+                r#"WHERE ARRAY_POSITION("tokens", $3) IS NOT NULL"#,
+                r#"AND ARRAY_LENGTH("tokens", $4) > $5"#,
+                r#"AND CARDINALITY("tokens") < $6"#,
+            ]
+            .join(" "),
+            Values(vec![
+                "a".into(),
+                "b".into(),
+                "a".into(),
+                1i32.into(),
+                0i32.into(),
+                10i32.into()
+            ])
+        )
     );
 }
 
@@ -2047,6 +3302,23 @@ fn regex_case_insensitive_bin_oper() {
     );
 }
 
+#[test]
+fn similar_to() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::Character).similar_to("%(b|d)%"))
+            .and_where(Expr::col(Char::Character).not_similar_to("a%"))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "character" FROM "character" WHERE "character" SIMILAR TO $1 AND "character" NOT SIMILAR TO $2"#
+                .to_owned(),
+            Values(vec!["%(b|d)%".into(), "a%".into()])
+        )
+    );
+}
+
 #[test]
 fn test_issue_674_nested_logical() {
     let t = SimpleExpr::Value(true.into());
@@ -2135,3 +3407,451 @@ fn test_pgvector_select() {
         r#"SELECT "character" FROM "character" WHERE "character" = '[1,2]'"#
     );
 }
+
+#[test]
+fn select_extract_epoch_duration() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                Func::timestamp_diff(
+                    Expr::col(Alias::new("started_at")),
+                    Expr::col(Alias::new("ended_at"))
+                ),
+                Alias::new("duration")
+            )
+            .from(Alias::new("session"))
+            .and_where(Expr::expr(Func::extract(DatePart::Hour, Expr::col(Char::CreatedAt))).gt(12))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT EXTRACT(EPOCH FROM "ended_at" - "started_at") AS "duration" FROM "session" WHERE EXTRACT(HOUR FROM "created_at") > $1"#
+                .to_owned(),
+            Values(vec![12i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_top_n_per_group_lateral() {
+    let top_char: DynIden = SeaRc::new(Alias::new("top_char"));
+    assert_eq!(
+        Query::select()
+            .column((Font::Table, Font::Name))
+            .column((top_char.clone(), Char::Character))
+            .from(Font::Table)
+            .cross_join_lateral(
+                Query::select()
+                    .column(Char::Character)
+                    .from(Char::Table)
+                    .and_where(
+                        Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id))
+                    )
+                    .and_where(Expr::col((Char::Table, Char::SizeW)).gt(10))
+                    .order_by(Char::SizeW, Order::Desc)
+                    .limit(3)
+                    .take(),
+                top_char.clone(),
+            )
+            .and_where(Expr::col((Font::Table, Font::Language)).eq("en"))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "font"."name", "top_char"."character" FROM "font""#,
+                r#"CROSS JOIN LATERAL (SELECT "character" FROM "character""#,
+                r#"WHERE "character"."font_id" = "font"."id" AND "character"."size_w" > $1"#,
+                r#"ORDER BY "size_w" DESC LIMIT $2) AS "top_char""#,
+                r#"WHERE "font"."language" = $3"#,
+            ]
+            .join(" "),
+            Values(vec![10i32.into(), 3u64.into(), "en".into()])
+        )
+    );
+}
+
+#[test]
+fn select_having_inline_alias() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Aspect)
+            .expr_as(
+                Expr::col(Glyph::Image).count().add(1),
+                Alias::new("image_count")
+            )
+            .from(Glyph::Table)
+            .group_by_col(Glyph::Aspect)
+            .cond_having(
+                Cond::any()
+                    .add(Expr::col(Alias::new("image_count")).gt(2))
+                    .add(Expr::col(Glyph::Aspect).lt(8))
+            )
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "aspect", COUNT("image") + $1 AS "image_count" FROM "glyph""#,
+                r#"GROUP BY "aspect" HAVING COUNT("image") + $2 > $3 OR "aspect" < $4"#,
+            ]
+            .join(" "),
+            Values(vec![1i32.into(), 1i32.into(), 2i32.into(), 8i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_first_row_per_group() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Id, Glyph::Image])
+            .from_subquery(
+                Query::select()
+                    .columns([Glyph::Id, Glyph::Image])
+                    .expr_window_as(
+                        Func::row_number(),
+                        WindowStatement::partition_by(Glyph::Image)
+                            .order_by(Glyph::Aspect, Order::Desc)
+                            .order_by(Glyph::Id, Order::Asc)
+                            .to_owned(),
+                        Alias::new("rn")
+                    )
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned(),
+                Alias::new("ranked")
+            )
+            .and_where(Expr::col(Alias::new("rn")).eq(1))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "id", "image" FROM"#,
+                r#"(SELECT "id", "image", ROW_NUMBER() OVER ( PARTITION BY "image" ORDER BY "aspect" DESC, "id" ASC ) AS "rn" FROM "glyph" WHERE "aspect" > $1) AS "ranked""#,
+                r#"WHERE "rn" = $2"#,
+            ]
+            .join(" "),
+            Values(vec![2i32.into(), 1i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_cast_bound_null_as_type() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(
+                Expr::col(Char::Character)
+                    .eq(Expr::val(Value::String(None)).cast_as_type(ColumnType::Text))
+                    .or(Expr::val(Value::String(None))
+                        .cast_as_type(ColumnType::Text)
+                        .is_null())
+            )
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "character" WHERE "character" = CAST($1 AS text) OR CAST($2 AS text) IS NULL"#
+                .to_owned(),
+            Values(vec![Value::String(None), Value::String(None)])
+        )
+    );
+}
+
+#[test]
+fn select_from_only() {
+    assert_eq!(
+        Query::select()
+            .column((Char::Table, Char::Character))
+            .column((Font::Table, Font::Name))
+            .from(Font::Table)
+            .from_only(Char::Table)
+            .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT "character"."character", "font"."name""#,
+            r#"FROM "font", ONLY "character""#,
+            r#"WHERE "character"."font_id" = "font"."id""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[cfg(feature = "with-json")]
+fn select_jsonb_path_query_vars() {
+    let query = Query::select()
+        .column(Glyph::Id)
+        .expr_as(
+            PgFunc::jsonb_path_query_array(Expr::col(Glyph::Image), "$.tokens[*] ? (@ > $min)")
+                .arg(json!({ "min": 5 })),
+            Alias::new("tokens"),
+        )
+        .from(Glyph::Table)
+        .and_where(
+            PgFunc::jsonb_path_exists(Expr::col(Glyph::Image), "$.tokens[*] ? (@ > 5)").into(),
+        )
+        .to_owned();
+
+    assert_eq!(
+        query.build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "id", JSONB_PATH_QUERY_ARRAY("image", $1::jsonpath, $2) AS "tokens""#,
+                r#"FROM "glyph" WHERE JSONB_PATH_EXISTS("image", $3::jsonpath)"#,
+            ]
+            .join(" "),
+            Values(vec![
+                "$.tokens[*] ? (@ > $min)".into(),
+                json!({ "min": 5 }).into(),
+                "$.tokens[*] ? (@ > 5)".into(),
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_json_agg_build_object() {
+    assert_eq!(
+        Query::select()
+            .column((Font::Table, Font::Name))
+            .expr_as(
+                PgFunc::json_agg(PgFunc::jsonb_build_object(vec![
+                    (Expr::val("id"), Expr::col((Char::Table, Char::Id))),
+                    (
+                        Expr::val("size"),
+                        Expr::expr(Expr::col((Char::Table, Char::SizeW)).add(1))
+                    ),
+                ])),
+                Alias::new("characters")
+            )
+            .from(Font::Table)
+            .inner_join(
+                Char::Table,
+                Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id))
+            )
+            .and_where(Expr::col((Font::Table, Font::Language)).eq("en"))
+            .group_by_col((Font::Table, Font::Name))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "font"."name","#,
+                r#"JSON_AGG(JSONB_BUILD_OBJECT('id', "character"."id", 'size', "character"."size_w" + $1)) AS "characters""#,
+                r#"FROM "font" INNER JOIN "character" ON "character"."font_id" = "font"."id""#,
+                r#"WHERE "font"."language" = $2"#,
+                r#"GROUP BY "font"."name""#,
+            ]
+            .join(" "),
+            Values(vec![1i32.into(), "en".into()])
+        )
+    );
+}
+
+#[test]
+fn select_width_bucket_histogram() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                PgFunc::width_bucket(Expr::col(Char::SizeW), 0.5, 100.5, 10),
+                Alias::new("bucket")
+            )
+            .expr_as(Func::count(Expr::col(Char::Id)), Alias::new("total"))
+            .from(Char::Table)
+            .group_by_col(Alias::new("bucket"))
+            .order_by(Alias::new("bucket"), Order::Asc)
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT WIDTH_BUCKET("size_w", $1, $2, $3) AS "bucket", COUNT("id") AS "total""#,
+                r#"FROM "character" GROUP BY "bucket" ORDER BY "bucket" ASC"#,
+            ]
+            .join(" "),
+            Values(vec![0.5f64.into(), 100.5f64.into(), 10i32.into()])
+        )
+    );
+}
+
+#[test]
+fn merge_using_sub_query() {
+    assert_eq!(
+        Query::merge()
+            .into_table(Glyph::Table)
+            .using(TableRef::SubQuery(
+                Query::select()
+                    .columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+                    .from(Alias::new("staging"))
+                    .and_where(Expr::col(Glyph::Aspect).gt(0))
+                    .to_owned(),
+                Alias::new("s").into_iden()
+            ))
+            .on(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("s"), Glyph::Id)))
+            .on(Expr::col((Glyph::Table, Glyph::Image)).ne("locked"))
+            .when_matched_then_update_columns([Glyph::Aspect])
+            .when_matched_then_update([(Glyph::Image, Expr::val("merged").into())])
+            .when_not_matched_then_insert_columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"MERGE INTO "glyph""#,
+                r#"USING (SELECT "id", "aspect", "image" FROM "staging" WHERE "aspect" > $1) AS "s""#,
+                r#"ON "glyph"."id" = "s"."id" AND "glyph"."image" <> $2"#,
+                r#"WHEN MATCHED THEN UPDATE SET "aspect" = "s"."aspect", "image" = $3"#,
+                r#"WHEN NOT MATCHED THEN INSERT ("id", "aspect", "image") VALUES ("s"."id", "s"."aspect", "s"."image")"#,
+            ]
+            .join(" "),
+            Values(vec![0i32.into(), "locked".into(), "merged".into()])
+        )
+    );
+}
+
+#[test]
+fn select_ts_headline_shared_query() {
+    let q = Alias::new("q");
+    let document = PgFunc::to_tsvector(Expr::col((Glyph::Table, Glyph::Image)), None);
+    assert_eq!(
+        Query::select()
+            .column((Glyph::Table, Glyph::Id))
+            .expr_as(
+                PgFunc::ts_rank(Expr::expr(document.clone()), Expr::col(q.clone())),
+                Alias::new("rank")
+            )
+            .expr_as(
+                PgFunc::ts_headline(
+                    None,
+                    Expr::col((Glyph::Table, Glyph::Image)),
+                    Expr::col(q.clone()),
+                    Some("StartSel=<b>, StopSel=</b>")
+                ),
+                Alias::new("headline")
+            )
+            .from(Glyph::Table)
+            .from_function(PgFunc::to_tsquery("fast & glyph", None), q.clone())
+            .and_where(Expr::expr(document).binary(PgBinOper::Matches, Expr::col(q)))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "glyph"."id","#,
+                r#"TS_RANK(TO_TSVECTOR("glyph"."image"), "q") AS "rank","#,
+                r#"TS_HEADLINE("glyph"."image", "q", $1) AS "headline""#,
+                r#"FROM "glyph", TO_TSQUERY($2) AS "q""#,
+                r#"WHERE TO_TSVECTOR("glyph"."image") @@ "q""#,
+            ]
+            .join(" "),
+            Values(vec![
+                "StartSel=<b>, StopSel=</b>".into(),
+                "fast & glyph".into()
+            ])
+        )
+    );
+}
+
+#[test]
+fn insert_from_select_with_values() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .select_from(
+                Query::select()
+                    .expr(Expr::col(Glyph::Aspect).mul(2))
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Image).like("0%"))
+                    .to_owned()
+            )
+            .unwrap()
+            .build(PostgresQueryBuilder),
+        (
+            r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect" * $1, "image" FROM "glyph" WHERE "image" LIKE $2"#.to_owned(),
+            Values(vec![2i32.into(), "0%".into()])
+        )
+    );
+}
+
+#[test]
+fn select_keyword_case_lower() {
+    let mut writer = KeywordCaseWriter::new(String::new(), KeywordCase::Lower);
+    Query::select()
+        .expr(Func::count(Expr::col(Char::Id)))
+        .expr(Func::cust(Alias::new("MyFunc")).arg("In 'Quotes'"))
+        .from(Char::Table)
+        .and_where(Expr::col(Char::Character).eq("Abc"))
+        .and_where(Expr::cust("NOT EXISTS (SELECT 'X')"))
+        .build_collect_into(PostgresQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.to_string(),
+        r#"select count("id"), MyFunc(E'In \'Quotes\'') from "character" where "character" = 'Abc' and (NOT EXISTS (SELECT 'X'))"#
+    );
+}
+
+#[test]
+fn select_having_alias_named_as_column() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Image)
+            .expr_as(Expr::col(Glyph::Aspect).max(), Glyph::Aspect)
+            .from(Glyph::Table)
+            .group_by_col(Glyph::Image)
+            .and_having(Expr::col(Glyph::Aspect).gt(2))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "image", MAX("aspect") AS "aspect" FROM "glyph""#,
+                r#"GROUP BY "image" HAVING "aspect" > $1"#,
+            ]
+            .join(" "),
+            Values(vec![2i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_having_non_aliased_column() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Aspect)
+            .expr_as(Expr::col(Glyph::Image).count(), Alias::new("image_count"))
+            .from(Glyph::Table)
+            .group_by_col(Glyph::Aspect)
+            .and_having(Expr::col(Glyph::Aspect).gt(2))
+            .build(PostgresQueryBuilder),
+        (
+            [
+                r#"SELECT "aspect", COUNT("image") AS "image_count" FROM "glyph""#,
+                r#"GROUP BY "aspect" HAVING "aspect" > $1"#,
+            ]
+            .join(" "),
+            Values(vec![2i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_lowercase_identifiers() {
+    #[derive(Iden)]
+    enum Font {
+        Table,
+        #[iden = "fontSize"]
+        FontSize,
+    }
+
+    let mut writer = LowercaseIdenWriter::new(String::new());
+    Query::select()
+        .column((Font::Table, Font::FontSize))
+        .expr_as(Expr::col(Alias::new("FontSize")), Alias::new("My\"Size"))
+        .from(Font::Table)
+        .and_where(Expr::col(Font::FontSize).eq("Large"))
+        .build_collect_into(PostgresQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.to_string(),
+        r#"SELECT "font"."fontsize", "fontsize" AS "my""size" FROM "font" WHERE "fontsize" = 'Large'"#
+    );
+}
+
+#[test]
+fn select_lowercase_identifiers_keyword_case() {
+    let mut writer =
+        KeywordCaseWriter::new(LowercaseIdenWriter::new(String::new()), KeywordCase::Lower);
+    Query::select()
+        .column(Alias::new("FontSize"))
+        .from(Alias::new("Glyph"))
+        .build_collect_into(PostgresQueryBuilder, &mut writer);
+
+    assert_eq!(writer.to_string(), r#"select "fontsize" from "glyph""#);
+}
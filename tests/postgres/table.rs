@@ -1,5 +1,6 @@
 use super::*;
 use pretty_assertions::assert_eq;
+use sea_query::extension::postgres::PgBinOper;
 
 #[test]
 fn create_1() {
@@ -335,6 +336,116 @@ fn create_15() {
     );
 }
 
+#[test]
+fn create_boolean_default() {
+    assert_eq!(
+        Table::create()
+            .table(Task::Table)
+            .col(ColumnDef::new(Task::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Task::IsDone)
+                    .boolean()
+                    .not_null()
+                    .default(false)
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "task" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""is_done" bool NOT NULL DEFAULT FALSE"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        Table::alter()
+            .table(Task::Table)
+            .add_column(
+                ColumnDef::new(Alias::new("is_public"))
+                    .boolean()
+                    .default(true)
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "task" ADD COLUMN "is_public" bool DEFAULT TRUE"#
+    );
+}
+
+#[test]
+fn create_timestamp_precision() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Char::CreatedAt)
+                    .timestamp_len(6)
+                    .not_null()
+                    .default(Expr::current_timestamp_with_precision(6))
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "character" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""created_at" timestamp(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6)"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_enum_column() {
+    use sea_query::extension::postgres::Type;
+
+    assert_eq!(
+        Type::create()
+            .as_enum(Alias::new("font_variant"))
+            .values([Alias::new("regular"), Alias::new("bold")])
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TYPE "font_variant" AS ENUM ('regular', 'bold')"#
+    );
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .enumeration(
+                        Alias::new("font_variant"),
+                        [Alias::new("regular"), Alias::new("bold")]
+                    )
+                    .not_null()
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""variant" "font_variant" NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_set_drop_default() {
+    assert_eq!(
+        Table::alter()
+            .table(Char::Table)
+            .set_default(Char::FontSize, 12)
+            .set_default(Char::CreatedAt, Expr::current_timestamp())
+            .drop_default(Char::Character)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "character""#,
+            r#"ALTER COLUMN "font_size" SET DEFAULT 12,"#,
+            r#"ALTER COLUMN "created_at" SET DEFAULT CURRENT_TIMESTAMP,"#,
+            r#"ALTER COLUMN "character" DROP DEFAULT"#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -571,6 +682,34 @@ fn create_with_check_constraint() {
     );
 }
 
+#[test]
+fn create_with_exclude_constraint() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("booking"))
+            .col(ColumnDef::new(Alias::new("room")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("during")).custom(Alias::new("tstzrange")))
+            .check(Expr::col(Alias::new("room")).gt(0))
+            .exclude_constraint(
+                IndexType::Custom(Alias::new("gist").into_iden()),
+                [
+                    (Alias::new("room"), BinOper::Equal),
+                    (Alias::new("during"), PgBinOper::Overlap.into()),
+                ]
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "booking" ("#,
+            r#""room" integer NOT NULL,"#,
+            r#""during" tstzrange,"#,
+            r#"CHECK ("room" > 0),"#,
+            r#"EXCLUDE USING gist ("room" WITH =, "during" WITH &&)"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn alter_with_check_constraint() {
     assert_eq!(
@@ -611,3 +750,83 @@ fn create_16() {
         .join(" ")
     );
 }
+
+#[test]
+#[cfg(feature = "postgres-interval")]
+fn create_17() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Aspect)
+                    .interval(Some(PgInterval::DayToSecond), Some(3))
+                    .not_null()
+                    .default(Interval {
+                        days: 7,
+                        ..Default::default()
+                    })
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""aspect" interval DAY TO SECOND(3) NOT NULL DEFAULT INTERVAL '0 months 7 days 0.000000 seconds'"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_keyword_case_lower() {
+    let mut writer = KeywordCaseWriter::new(String::new(), KeywordCase::Lower);
+    Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .integer()
+                .not_null()
+                .primary_key()
+                .extra("COLLATE \"C\""),
+        )
+        .col(
+            ColumnDef::new(Glyph::Image)
+                .array(ColumnType::Custom(Alias::new("CITEXT").into_iden())),
+        )
+        .col(ColumnDef::new(Glyph::Aspect).enumeration(Alias::new("Aspect"), [Alias::new("Wide")]))
+        .index(
+            Index::create()
+                .unique()
+                .name("IDX-Glyph-Aspect")
+                .col(Glyph::Aspect),
+        )
+        .build_collect_into(PostgresQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.to_string(),
+        [
+            r#"create table "glyph" ("#,
+            r#""id" integer not null primary key COLLATE "C","#,
+            r#""image" CITEXT[],"#,
+            r#""aspect" "Aspect","#,
+            r#"constraint "IDX-Glyph-Aspect" unique ("aspect")"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_extension_keyword_case_lower() {
+    let mut writer = KeywordCaseWriter::new(String::new(), KeywordCase::Lower);
+
+    assert_eq!(
+        extension::postgres::Extension::create()
+            .name("PostGIS")
+            .if_not_exists()
+            .schema("Public")
+            .version("3.4.0")
+            .cascade()
+            .build_collect(PostgresQueryBuilder, &mut writer),
+        "create extension if not exists PostGIS with schema Public version 3.4.0 cascade"
+    );
+}
@@ -57,6 +57,34 @@ fn create_4() {
     );
 }
 
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .name("idx-font-name-lower")
+            .table(Font::Table)
+            .col_expr(Func::lower(Expr::col(Font::Name)))
+            .and_where(Expr::col(Font::Language).ne("en"))
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE UNIQUE INDEX "idx-font-name-lower" ON "font" ((LOWER("name"))) WHERE "language" <> 'en'"#
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name-en")
+            .table(Font::Table)
+            .col(Font::Name)
+            .and_where(Func::lower(Expr::col(Font::Language)).eq("en"))
+            .and_where(Func::char_length(Expr::col(Font::Name)).gt(3))
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE INDEX "idx-font-name-en" ON "font" ("name") WHERE LOWER("language") = 'en' AND LENGTH("name") > 3"#
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -1053,6 +1053,394 @@ fn cast_json_field_bin_oper() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Sqlite doesn't support GROUPING SETS, ROLLUP and CUBE")]
+fn select_group_by_rollup() {
+    Query::select()
+        .columns([Char::FontId, Char::SizeW])
+        .expr(Expr::col(Char::Id).count())
+        .from(Char::Table)
+        .group_by_rollup([Char::FontId, Char::SizeW])
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_explain() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeW).gt(10))
+            .to_owned()
+            .explain()
+            .to_string(SqliteQueryBuilder),
+        r#"EXPLAIN QUERY PLAN SELECT "id" FROM "character" WHERE "size_w" > 10"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support EXPLAIN ANALYZE")]
+fn select_explain_analyze() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .to_owned()
+        .explain_analyze()
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't have a built-in hash function")]
+fn select_hash_mod_bucket() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Func::hash_mod(Expr::col(Char::Id), 100).lt(5))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_aggregate_filter() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::sum(Expr::col(Char::SizeW)).filter(Expr::col(Char::SizeH).gt(3)))
+            .from(Char::Table)
+            .group_by_col(Char::FontId)
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT "font_id", SUM("size_w") FILTER (WHERE "size_h" > 3) FROM "character" GROUP BY "font_id""#
+    );
+}
+
+#[test]
+fn select_format_date() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::format_date(
+                Expr::col(Char::CreatedAt),
+                "%d/%m/%Y at %H:%M:%S (100%%)"
+            ))
+            .from(Char::Table)
+            .build(SqliteQueryBuilder),
+        (
+            r#"SELECT STRFTIME(?, "created_at") FROM "character""#.to_owned(),
+            Values(vec!["%d/%m/%Y at %H:%M:%S (100%%)".into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unsupported date format specifier %B")]
+fn select_format_date_unsupported() {
+    Query::select()
+        .expr(Func::format_date(Expr::col(Char::CreatedAt), "%B %Y"))
+        .from(Char::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_join_values_list() {
+    assert_eq!(
+        Query::select()
+            .columns([(Char::Table, Char::Character)])
+            .column((Alias::new("t"), Alias::new("label")))
+            .from(Char::Table)
+            .inner_join(
+                Query::values_list(
+                    [(1i32, "small"), (2, "large")],
+                    Alias::new("t"),
+                    [Alias::new("id"), Alias::new("label")],
+                ),
+                Expr::col((Char::Table, Char::Id)).equals((Alias::new("t"), Alias::new("id"))),
+            )
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "character"."character", "t"."label" FROM "character""#,
+                r#"INNER JOIN (SELECT "column1" AS "id", "column2" AS "label" FROM (VALUES (?, ?), (?, ?))) AS "t""#,
+                r#"ON "character"."id" = "t"."id""#,
+            ]
+            .join(" "),
+            Values(vec![
+                1i32.into(),
+                "small".into(),
+                2i32.into(),
+                "large".into()
+            ])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support row locking with tables or behavior")]
+fn select_lock_skip_locked() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .limit(1)
+        .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_except_null_rows() {
+    // Set operations treat NULLs as equal, so this query returns no rows
+    assert_eq!(
+        Query::select()
+            .exprs([Expr::val(1), Expr::val(Option::<i32>::None)])
+            .union(
+                UnionType::Except,
+                Query::select()
+                    .exprs([Expr::val(1), Expr::val(Option::<i32>::None)])
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        "SELECT 1, NULL EXCEPT SELECT 1, NULL"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support EXCEPT ALL")]
+fn select_except_all() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Alias::new("glyph_new"))
+        .union(
+            UnionType::ExceptAll,
+            Query::select()
+                .column(Glyph::Id)
+                .from(Alias::new("glyph_old"))
+                .to_owned(),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_ilike() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Name)
+            .from(Font::Table)
+            .and_where(Expr::col(Font::Name).ilike("sans%"))
+            .and_where(Expr::col(Font::Variant).not_ilike(LikeExpr::new("%|_mono").escape('|')))
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "name" FROM "font" WHERE LOWER("name") LIKE LOWER(?)"#,
+                r#"AND LOWER("variant") NOT LIKE LOWER(?) ESCAPE '|'"#,
+            ]
+            .join(" "),
+            Values(vec!["sans%".into(), "%|_mono".into()])
+        )
+    );
+}
+
+#[test]
+fn select_page_with_total_count() {
+    assert_eq!(
+        Query::select()
+            .columns([Char::Id, Char::Character])
+            .expr_window_as(
+                Func::count_star(),
+                WindowStatement::new(),
+                Alias::new("total")
+            )
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).eq(5))
+            .order_by(Char::Id, Order::Asc)
+            .limit(10)
+            .offset(20)
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "id", "character", COUNT(*) OVER () AS "total""#,
+                r#"FROM "character""#,
+                r#"WHERE "font_id" = ?"#,
+                r#"ORDER BY "id" ASC LIMIT ? OFFSET ?"#,
+            ]
+            .join(" "),
+            Values(vec![5.into(), 10u64.into(), 20u64.into()])
+        )
+    );
+}
+
+#[test]
+fn select_string_agg() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Language)
+            .expr(Func::string_agg(Expr::col(Font::Name), ", ").order_by(Font::Name, Order::Desc))
+            .from(Font::Table)
+            .group_by_col(Font::Language)
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT "language", GROUP_CONCAT("name", ', ' ORDER BY "name" DESC) FROM "font" GROUP BY "language""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support DISTINCT in GROUP_CONCAT with a separator")]
+fn select_string_agg_distinct() {
+    Query::select()
+        .expr(Func::string_agg(Expr::col(Font::Name), ", ").distinct())
+        .from(Font::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support OVERLAPS")]
+fn select_overlaps() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::overlaps(
+            ("2020-01-01", "2020-02-01"),
+            ("2020-01-15", "2020-03-01"),
+        ))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support SIMILAR TO")]
+fn select_similar_to() {
+    Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::Character).similar_to("%(b|d)%"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_group_by_case() {
+    let bucket = Expr::case(Expr::col(Char::SizeW).lt(18), "minor").finally("adult");
+    assert_eq!(
+        Query::select()
+            .expr_as(bucket.clone(), Alias::new("bucket"))
+            .expr(Expr::col(Char::Id).count())
+            .from(Char::Table)
+            .group_by_expr(bucket)
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"SELECT (CASE WHEN ("size_w" < 18) THEN 'minor' ELSE 'adult' END) AS "bucket", COUNT("id")"#,
+            r#"FROM "character" GROUP BY (CASE WHEN ("size_w" < 18) THEN 'minor' ELSE 'adult' END)"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_window_frame() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                WindowStatement::new()
+                    .order_by(Char::Id, Order::Asc)
+                    .frame_between(FrameType::Rows, Frame::Preceding(2), Frame::CurrentRow)
+                    .take(),
+                Alias::new("moving_avg"),
+            )
+            .from(Char::Table)
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "id", AVG("size_w") OVER ( ORDER BY "id" ASC ROWS BETWEEN 2 PRECEDING AND CURRENT ROW )"#,
+                r#"AS "moving_avg" FROM "character""#,
+            ]
+            .join(" "),
+            Values(vec![])
+        )
+    );
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                WindowStatement::new()
+                    .order_by(Char::Id, Order::Asc)
+                    .frame_between(FrameType::Groups, Frame::Preceding(2), Frame::CurrentRow)
+                    .take(),
+                Alias::new("moving_avg"),
+            )
+            .from(Char::Table)
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"SELECT "id", AVG("size_w") OVER ( ORDER BY "id" ASC GROUPS BETWEEN 2 PRECEDING AND CURRENT ROW )"#,
+            r#"AS "moving_avg" FROM "character""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_named_window() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .expr_window_name_as(
+                Func::sum(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("total")
+            )
+            .expr_window_name_as(
+                Func::avg(Expr::col(Char::SizeW)),
+                Alias::new("w"),
+                Alias::new("average")
+            )
+            .from(Char::Table)
+            .and_having(Expr::col(Char::Id).count().gt(1))
+            .group_by_col(Char::Id)
+            .window(
+                Alias::new("w"),
+                WindowStatement::partition_by(Char::FontId)
+                    .order_by(Char::Id, Order::Asc)
+                    .to_owned(),
+            )
+            .order_by(Char::Id, Order::Desc)
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"SELECT "id", SUM("size_w") OVER "w" AS "total", AVG("size_w") OVER "w" AS "average""#,
+            r#"FROM "character" GROUP BY "id" HAVING COUNT("id") > 1"#,
+            r#"WINDOW "w" AS (PARTITION BY "font_id" ORDER BY "id" ASC)"#,
+            r#"ORDER BY "id" DESC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support FETCH FIRST, use LIMIT instead")]
+fn select_fetch_first() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .order_by(Glyph::Aspect, Order::Desc)
+        .fetch_first(10, true)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_greatest_ignore_nulls() {
+    let query = Query::select()
+        .expr(Func::greatest_ignore_nulls([
+            Expr::col(Char::SizeW).into(),
+            Expr::col(Char::SizeH).into(),
+            Expr::val(0).into(),
+        ]))
+        .expr(Func::least_ignore_nulls([Expr::col(Char::SizeW).into()]))
+        .from(Char::Table)
+        .to_owned();
+
+    assert_eq!(
+        query.build(SqliteQueryBuilder),
+        (
+            r#"SELECT MAX(COALESCE("size_w", "size_h", ?), COALESCE("size_h", "size_w", ?), COALESCE(?, "size_w", "size_h")), "size_w" FROM "character""#
+                .to_owned(),
+            Values(vec![0i32.into(), 0i32.into(), 0i32.into()])
+        )
+    );
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 fn insert_2() {
@@ -1517,6 +1905,47 @@ fn insert_on_conflict_do_nothing_on() {
     );
 }
 
+#[test]
+fn insert_on_conflict_update_where_newer() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Id, Glyph::Image, Glyph::Aspect])
+            .values_panic([1.into(), "A".into(), 2.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_column(Glyph::Image)
+                    .action_and_where(
+                        Expr::col((Glyph::Table, Glyph::Aspect))
+                            .lt(Expr::col((Alias::new("excluded"), Glyph::Aspect)))
+                    )
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("id", "image", "aspect") VALUES (1, 'A', 2)"#,
+            r#"ON CONFLICT ("id") DO UPDATE SET "image" = "excluded"."image""#,
+            r#"WHERE "glyph"."aspect" < "excluded"."aspect""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support DEFAULT as a value")]
+fn insert_on_conflict_default() {
+    Query::insert()
+        .into_table(Glyph::Table)
+        .columns([Glyph::Aspect, Glyph::Image])
+        .values_panic(["abcd".into(), 3.into()])
+        .on_conflict(
+            OnConflict::column(Glyph::Id)
+                .value(Glyph::Image, Keyword::Default)
+                .to_owned(),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 fn insert_returning_all_columns() {
@@ -1551,6 +1980,19 @@ fn insert_returning_specific_columns() {
     );
 }
 
+#[test]
+fn select_random_row() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .order_by_expr(Func::random().into(), Order::Asc)
+            .limit(1)
+            .to_string(SqliteQueryBuilder),
+        r#"SELECT "id" FROM "glyph" ORDER BY RANDOM() ASC LIMIT 1"#
+    );
+}
+
 #[test]
 fn update_1() {
     assert_eq!(
@@ -1637,6 +2079,60 @@ fn update_returning_specified_columns() {
     );
 }
 
+#[test]
+fn update_from_sub_query() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .from(TableRef::SubQuery(
+                Query::select()
+                    .column(Char::Id)
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::FontId).eq(5))
+                    .to_owned(),
+                Alias::new("sub").into_iden(),
+            ))
+            .value(Glyph::Aspect, 2)
+            .and_where(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("sub"), Glyph::Id)))
+            .and_where(Expr::col((Glyph::Table, Glyph::Image)).like("A%"))
+            .build(SqliteQueryBuilder),
+        (
+            r#"UPDATE "glyph" SET "aspect" = ? FROM (SELECT "id" FROM "character" WHERE "font_id" = ?) AS "sub" WHERE "glyph"."id" = "sub"."id" AND "glyph"."image" LIKE ?"#.to_owned(),
+            Values(vec![2.into(), 5.into(), "A%".into()])
+        )
+    );
+}
+
+#[test]
+fn update_value_tuple() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 1)
+            .value_tuple(
+                [Glyph::Image, Glyph::Tokens],
+                Expr::subquery(
+                    Query::select()
+                        .columns([Font::Name, Font::Variant])
+                        .from(Font::Table)
+                        .and_where(Expr::col(Font::Id).eq(2))
+                        .to_owned(),
+                ),
+            )
+            .and_where(Expr::col(Glyph::Id).eq(3))
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"UPDATE "glyph" SET "aspect" = ?, ("image", "tokens") ="#,
+                r#"(SELECT "name", "variant" FROM "font" WHERE "id" = ?)"#,
+                r#"WHERE "id" = ?"#,
+            ]
+            .join(" "),
+            Values(vec![1.into(), 2.into(), 3.into()])
+        )
+    );
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -1648,6 +2144,16 @@ fn delete_1() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Sqlite doesn't support DELETE ... USING")]
+fn delete_using() {
+    Query::delete()
+        .from_table(Char::Table)
+        .using(Font::Table)
+        .and_where(Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id)))
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 fn escape_1() {
     let test = r#" "abc" "#;
@@ -1762,13 +2268,62 @@ fn union_1() {
             )
             .to_string(SqliteQueryBuilder),
         [
-            r#"SELECT "character" FROM "character" UNION SELECT "character" FROM "character""#,
-            r#"LEFT JOIN "font" ON "character"."font_id" = "font"."id" ORDER BY "font"."id" ASC"#
+            r#"SELECT "character" FROM "character" UNION SELECT * FROM (SELECT "character" FROM "character""#,
+            r#"LEFT JOIN "font" ON "character"."font_id" = "font"."id" ORDER BY "font"."id" ASC)"#
         ]
         .join(" ")
     );
 }
 
+#[test]
+fn union_limit_per_branch() {
+    let query = Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::FontId).eq(1))
+        .union(
+            UnionType::Distinct,
+            Query::select()
+                .column(Char::Id)
+                .from(Char::Table)
+                .and_where(Expr::col(Char::FontId).eq(2))
+                .order_by(Char::CreatedAt, Order::Desc)
+                .limit(5)
+                .to_owned(),
+        )
+        .union(
+            UnionType::All,
+            Query::select()
+                .column(Char::Id)
+                .from(Char::Table)
+                .and_where(Expr::col(Char::FontId).eq(3))
+                .to_owned(),
+        )
+        .order_by(Char::Id, Order::Asc)
+        .limit(8)
+        .to_owned();
+
+    assert_eq!(
+        query.build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "id" FROM "character" WHERE "font_id" = ?"#,
+                r#"UNION SELECT * FROM (SELECT "id" FROM "character" WHERE "font_id" = ? ORDER BY "created_at" DESC LIMIT ?)"#,
+                r#"UNION ALL SELECT "id" FROM "character" WHERE "font_id" = ?"#,
+                r#"ORDER BY "id" ASC LIMIT ?"#,
+            ]
+            .join(" "),
+            Values(vec![
+                1i32.into(),
+                2i32.into(),
+                5u64.into(),
+                3i32.into(),
+                8u64.into()
+            ])
+        )
+    );
+}
+
 #[test]
 fn sub_query_with_fn() {
     #[derive(Iden)]
@@ -1836,3 +2391,168 @@ fn recursive_with_multiple_ctes() {
         r#"WITH RECURSIVE "sub1" ("a") AS (SELECT * FROM "character") , "sub2" ("b") AS (SELECT * FROM "character") SELECT * FROM "sub1" UNION ALL SELECT * FROM "sub2""#
     );
 }
+
+#[test]
+#[should_panic(
+    expected = "SEARCH and CYCLE clauses of a common table expression are only supported by Postgres"
+)]
+fn with_recursive_search_cycle() {
+    let cte = CommonTableExpression::new()
+        .query(
+            Query::select()
+                .columns([Alias::new("id"), Alias::new("parent_id")])
+                .from(Alias::new("node"))
+                .to_owned(),
+        )
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .table_name(Alias::new("tree"))
+        .cycle(
+            [Alias::new("id")],
+            Alias::new("is_cycle"),
+            Alias::new("path"),
+        )
+        .to_owned();
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .to_owned()
+        .with(WithClause::new().recursive(true).cte(cte).to_owned());
+
+    query.to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn select_extract_epoch_duration() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                Func::timestamp_diff(
+                    Expr::col(Alias::new("started_at")),
+                    Expr::col(Alias::new("ended_at"))
+                ),
+                Alias::new("duration")
+            )
+            .from(Alias::new("session"))
+            .and_where(Expr::expr(Func::extract(DatePart::Hour, Expr::col(Char::CreatedAt))).gt(12))
+            .build(SqliteQueryBuilder),
+        (
+            r#"SELECT (STRFTIME('%s', "ended_at") - STRFTIME('%s', "started_at")) AS "duration" FROM "session" WHERE CAST(STRFTIME('%H', "created_at") AS INTEGER) > ?"#
+                .to_owned(),
+            Values(vec![12i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_first_row_per_group() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Id, Glyph::Image])
+            .from_subquery(
+                Query::select()
+                    .columns([Glyph::Id, Glyph::Image])
+                    .expr_window_as(
+                        Func::row_number(),
+                        WindowStatement::partition_by(Glyph::Image)
+                            .order_by(Glyph::Aspect, Order::Desc)
+                            .order_by(Glyph::Id, Order::Asc)
+                            .to_owned(),
+                        Alias::new("rn")
+                    )
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned(),
+                Alias::new("ranked")
+            )
+            .and_where(Expr::col(Alias::new("rn")).eq(1))
+            .build(SqliteQueryBuilder),
+        (
+            [
+                r#"SELECT "id", "image" FROM"#,
+                r#"(SELECT "id", "image", ROW_NUMBER() OVER ( PARTITION BY "image" ORDER BY "aspect" DESC, "id" ASC ) AS "rn" FROM "glyph" WHERE "aspect" > ?) AS "ranked""#,
+                r#"WHERE "rn" = ?"#,
+            ]
+            .join(" "),
+            Values(vec![2i32.into(), 1i32.into()])
+        )
+    );
+}
+
+#[test]
+fn select_right_and_full_outer_join() {
+    assert_eq!(
+        Query::select()
+            .column((Char::Table, Char::Character))
+            .column((Font::Table, Font::Name))
+            .column((Glyph::Table, Glyph::Image))
+            .from(Char::Table)
+            .right_join(
+                Font::Table,
+                Expr::col((Char::Table, Char::FontId)).equals((Font::Table, Font::Id))
+            )
+            .full_outer_join(
+                Glyph::Table,
+                Expr::col((Char::Table, Char::Id)).equals((Glyph::Table, Glyph::Id))
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"SELECT "character"."character", "font"."name", "glyph"."image" FROM "character""#,
+            r#"RIGHT JOIN "font" ON "character"."font_id" = "font"."id""#,
+            r#"FULL OUTER JOIN "glyph" ON "character"."id" = "glyph"."id""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support MERGE statement")]
+fn merge_unsupported() {
+    Query::merge()
+        .into_table(Glyph::Table)
+        .using(Alias::new("staging"))
+        .on(Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("staging"), Glyph::Id)))
+        .when_matched_then_update_columns([Glyph::Image])
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn insert_from_select_with_values() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .select_from(
+                Query::select()
+                    .expr(Expr::col(Glyph::Aspect).mul(2))
+                    .column(Glyph::Image)
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Image).like("0%"))
+                    .to_owned()
+            )
+            .unwrap()
+            .build(SqliteQueryBuilder),
+        (
+            r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect" * ?, "image" FROM "glyph" WHERE "image" LIKE ?"#.to_owned(),
+            Values(vec![2i32.into(), "0%".into()])
+        )
+    );
+}
+
+#[test]
+fn select_keyword_case_lower() {
+    let mut writer = KeywordCaseWriter::new(SqlWriterValues::new("?", false), KeywordCase::Lower);
+    Query::select()
+        .expr(Func::extract(DatePart::Month, Expr::col(Char::CreatedAt)))
+        .from(Char::Table)
+        .and_where(Expr::col(Char::Character).is_in(["A", "B"]))
+        .build_collect_into(SqliteQueryBuilder, &mut writer);
+
+    assert_eq!(
+        writer.into_inner().into_parts(),
+        (
+            r#"select cast(strftime('%m', "created_at") as integer) from "character" where "character" in (?, ?)"#
+                .to_owned(),
+            Values(vec!["A".into(), "B".into()])
+        )
+    );
+}
@@ -329,6 +329,90 @@ fn create_with_unique_index_constraint() {
     );
 }
 
+#[test]
+fn create_boolean_default() {
+    assert_eq!(
+        Table::create()
+            .table(Task::Table)
+            .col(ColumnDef::new(Task::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Task::IsDone)
+                    .boolean()
+                    .not_null()
+                    .default(false)
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE "task" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""is_done" boolean NOT NULL DEFAULT 0"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        Table::alter()
+            .table(Task::Table)
+            .add_column(
+                ColumnDef::new(Alias::new("is_public"))
+                    .boolean()
+                    .default(true)
+            )
+            .to_string(SqliteQueryBuilder),
+        r#"ALTER TABLE "task" ADD COLUMN "is_public" boolean DEFAULT 1"#
+    );
+}
+
+#[test]
+fn create_timestamp_precision() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(
+                ColumnDef::new(Char::CreatedAt)
+                    .timestamp_len(6)
+                    .not_null()
+                    .default(Expr::current_timestamp_with_precision(6))
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE "character" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""created_at" timestamp_text NOT NULL DEFAULT CURRENT_TIMESTAMP"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_enum_column() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .enumeration(
+                        Alias::new("font_variant"),
+                        [Alias::new("regular"), Alias::new("bold")]
+                    )
+                    .check(Expr::col(Font::Variant).is_in(["regular", "bold"]))
+            )
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE TABLE "font" ( "variant" enum_text CHECK ("variant" IN ('regular', 'bold')) )"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite doesn't support altering column defaults")]
+fn alter_set_default() {
+    let _ = Table::alter()
+        .table(Char::Table)
+        .set_default(Char::FontSize, 12)
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(